@@ -15,6 +15,12 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+pub mod devnet_v0;
+pub use devnet_v0::*;
+
+pub mod testnet_v0;
+pub use testnet_v0::*;
+
 pub mod v0;
 pub use v0::*;
 
@@ -0,0 +1,503 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{
+    Blake2Xs,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+
+lazy_static! {
+    /// The group bases for the Aleo signature and encryption schemes.
+    pub static ref DEVNET_GENERATOR_G: Vec<Group<DevnetV0 >> = DevnetV0::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+
+    /// The encryption domain as a constant field element.
+    pub static ref DEVNET_ENCRYPTION_DOMAIN: Field<DevnetV0> = Field::<DevnetV0>::new_domain_separator("AleoSymmetricEncryption0");
+    /// The graph key domain as a constant field element.
+    pub static ref DEVNET_GRAPH_KEY_DOMAIN: Field<DevnetV0> = Field::<DevnetV0>::new_domain_separator("AleoGraphKey0");
+    /// The serial number domain as a constant field element.
+    pub static ref DEVNET_SERIAL_NUMBER_DOMAIN: Field<DevnetV0> = Field::<DevnetV0>::new_domain_separator("AleoSerialNumber0");
+
+    /// The BHP hash function, which can take an input of up to 256 bits.
+    pub static ref DEVNET_BHP_256: BHP256<DevnetV0> = BHP256::<DevnetV0>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    /// The BHP hash function, which can take an input of up to 512 bits.
+    pub static ref DEVNET_BHP_512: BHP512<DevnetV0> = BHP512::<DevnetV0>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    /// The BHP hash function, which can take an input of up to 768 bits.
+    pub static ref DEVNET_BHP_768: BHP768<DevnetV0> = BHP768::<DevnetV0>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    /// The BHP hash function, which can take an input of up to 1024 bits.
+    pub static ref DEVNET_BHP_1024: BHP1024<DevnetV0> = BHP1024::<DevnetV0>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+
+    /// The Pedersen hash function, which can take an input of up to 64 bits.
+    pub static ref DEVNET_PEDERSEN_64: Pedersen64<DevnetV0> = Pedersen64::<DevnetV0>::setup("AleoPedersen64");
+    /// The Pedersen hash function, which can take an input of up to 128 bits.
+    pub static ref DEVNET_PEDERSEN_128: Pedersen128<DevnetV0> = Pedersen128::<DevnetV0>::setup("AleoPedersen128");
+
+    /// The Poseidon hash function, using a rate of 2.
+    pub static ref DEVNET_POSEIDON_2: Poseidon2<DevnetV0> = Poseidon2::<DevnetV0>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    /// The Poseidon hash function, using a rate of 4.
+    pub static ref DEVNET_POSEIDON_4: Poseidon4<DevnetV0> = Poseidon4::<DevnetV0>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    /// The Poseidon hash function, using a rate of 8.
+    pub static ref DEVNET_POSEIDON_8: Poseidon8<DevnetV0> = Poseidon8::<DevnetV0>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+
+    /// The `credits.aleo` keys, which are synthesized on first use.
+    static ref DEVNET_CREDITS_KEYS: SynthesizedCreditsKeys<DevnetV0> = SynthesizedCreditsKeys::default();
+}
+
+/// A local development network, with fast blocks and cheap fees.
+///
+/// The `credits.aleo` circuits embed the network ID, so the devnet cannot use the mainnet `credits.aleo` keys.
+/// As no keys are bundled for the devnet, the key of each function is synthesized on first use,
+/// with the synthesizer set by the VM (see `Network::set_credits_key_synthesizer`).
+/// The inclusion circuit has no network-specific constants, so the devnet shares the mainnet inclusion keys.
+/// Its blocks are bound to its network ID, and its transactions are anchored to its own state roots,
+/// so neither can be replayed on mainnet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DevnetV0;
+
+impl DevnetV0 {
+    /// Initializes a new instance of group bases from a given input domain message.
+    fn new_bases(message: &str) -> Vec<Group<Self>> {
+        // Hash the given message to a point on the curve, to initialize the starting base.
+        let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
+
+        // Compute the bases up to the size of the scalar field (in bits).
+        let mut g = Group::<Self>::new(base);
+        let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
+        for _ in 0..Scalar::<Self>::size_in_bits() {
+            g_bases.push(g);
+            g = g.double();
+        }
+        g_bases
+    }
+}
+
+impl Environment for DevnetV0 {
+    type Affine = <Console as Environment>::Affine;
+    type BigInteger = <Console as Environment>::BigInteger;
+    type Field = <Console as Environment>::Field;
+    type PairingCurve = <Console as Environment>::PairingCurve;
+    type Projective = <Console as Environment>::Projective;
+    type Scalar = <Console as Environment>::Scalar;
+
+    /// The coefficient `A` of the twisted Edwards curve.
+    const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+    /// The coefficient `D` of the twisted Edwards curve.
+    const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+    /// The coefficient `A` of the Montgomery curve.
+    const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+    /// The coefficient `B` of the Montgomery curve.
+    const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+}
+
+impl Network for DevnetV0 {
+    /// The block hash type.
+    type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The ratification ID type.
+    type RatificationID = AleoID<Field<Self>, { hrp2!("ar") }>;
+    /// The state root type.
+    type StateRoot = AleoID<Field<Self>, { hrp2!("sr") }>;
+    /// The transaction ID type.
+    type TransactionID = AleoID<Field<Self>, { hrp2!(TRANSACTION_PREFIX) }>;
+    /// The transition ID type.
+    type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
+
+    /// The anchor time in seconds.
+    const ANCHOR_TIME: u16 = 5;
+    /// The expected time per block in seconds.
+    const BLOCK_TIME: u16 = 1;
+    /// The block height from which an execution may bundle multiple call trees.
+    const BUNDLED_EXECUTION_HEIGHT: u32 = 0;
+    /// The cost in microcredits per byte for the deployment transaction.
+    const DEPLOYMENT_FEE_MULTIPLIER: u64 = 10;
+    /// The network edition.
+    const EDITION: u16 = 0;
+    /// The genesis block coinbase target.
+    const GENESIS_COINBASE_TARGET: u64 = (1u64 << 10).saturating_sub(1);
+    /// The genesis block proof target.
+    const GENESIS_PROOF_TARGET: u64 = 1u64 << 3;
+    /// The network ID.
+    const ID: u16 = 2;
+    /// The function name for the inclusion circuit.
+    const INCLUSION_FUNCTION_NAME: &'static str = snarkvm_parameters::mainnet::NETWORK_INCLUSION_FUNCTION_NAME;
+    /// The network name.
+    const NAME: &'static str = "Aleo Devnet (v0)";
+    /// The number of blocks per epoch.
+    const NUM_BLOCKS_PER_EPOCH: u32 = 60 / Self::BLOCK_TIME as u32;
+    /// The short network name, as used in REST paths.
+    const SHORT_NAME: &'static str = "devnet";
+    /// The cost in microcredits per constraint for the deployment transaction.
+    const SYNTHESIS_FEE_MULTIPLIER: u64 = 1;
+
+    /// Returns the genesis block bytes.
+    ///
+    /// Note: No genesis block is bundled for this network, so one must be created with `VM::genesis_beacon`.
+    fn genesis_bytes() -> &'static [u8] {
+        &[]
+    }
+
+    /// Returns the proving key for the given function name in `credits.aleo`, synthesizing it on first use.
+    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
+        DEVNET_CREDITS_KEYS.proving_key(&function_name)
+    }
+
+    /// Returns the verifying key for the given function name in `credits.aleo`, synthesizing it on first use.
+    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
+        DEVNET_CREDITS_KEYS.verifying_key(&function_name)
+    }
+
+    /// Sets the synthesizer of the `credits.aleo` keys.
+    fn set_credits_key_synthesizer(synthesizer: CreditsKeySynthesizer<Self>) {
+        DEVNET_CREDITS_KEYS.set_synthesizer(synthesizer)
+    }
+
+    /// Returns the `proving key` for the inclusion circuit.
+    fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
+        MainnetV0::inclusion_proving_key()
+    }
+
+    /// Returns the `verifying key` for the inclusion circuit.
+    fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
+        MainnetV0::inclusion_verifying_key()
+    }
+
+    /// Returns the powers of `G`.
+    fn g_powers() -> &'static Vec<Group<Self>> {
+        &DEVNET_GENERATOR_G
+    }
+
+    /// Returns the scalar multiplication on the generator `G`.
+    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+        DEVNET_GENERATOR_G
+            .iter()
+            .zip_eq(&scalar.to_bits_le())
+            .filter_map(|(base, bit)| match bit {
+                true => Some(base),
+                false => None,
+            })
+            .sum()
+    }
+
+    /// Returns the Varuna universal prover.
+    fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+        MainnetV0::varuna_universal_prover()
+    }
+
+    /// Returns the Varuna universal verifier.
+    fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
+        MainnetV0::varuna_universal_verifier()
+    }
+
+    /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
+    fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
+        MainnetV0::varuna_fs_parameters()
+    }
+
+    /// Returns the encryption domain as a constant field element.
+    fn encryption_domain() -> Field<Self> {
+        *DEVNET_ENCRYPTION_DOMAIN
+    }
+
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self> {
+        *DEVNET_GRAPH_KEY_DOMAIN
+    }
+
+    /// Returns the serial number domain as a constant field element.
+    fn serial_number_domain() -> Field<Self> {
+        *DEVNET_SERIAL_NUMBER_DOMAIN
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+    fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_256.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+    fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_512.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+    fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_768.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+    fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_1024.commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_PEDERSEN_64.commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_PEDERSEN_128.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+    fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_BHP_256.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+    fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_BHP_512.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+    fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_BHP_768.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+    fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_BHP_1024.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_PEDERSEN_64.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_PEDERSEN_128.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_256.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_512.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_768.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_1024.hash(input)
+    }
+
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+        Keccak256::default().hash(input)
+    }
+
+    /// Returns the Keccak hash with a 384-bit output.
+    fn hash_keccak384(input: &[bool]) -> Result<Vec<bool>> {
+        Keccak384::default().hash(input)
+    }
+
+    /// Returns the Keccak hash with a 512-bit output.
+    fn hash_keccak512(input: &[bool]) -> Result<Vec<bool>> {
+        Keccak512::default().hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_PEDERSEN_64.hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_PEDERSEN_128.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2.
+    fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+        DEVNET_POSEIDON_2.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4.
+    fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+        DEVNET_POSEIDON_4.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8.
+    fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+        DEVNET_POSEIDON_8.hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha3_256::default().hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 384-bit output.
+    fn hash_sha3_384(input: &[bool]) -> Result<Vec<bool>> {
+        Sha3_384::default().hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 512-bit output.
+    fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>> {
+        Sha3_512::default().hash(input)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 2.
+    fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        DEVNET_POSEIDON_2.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 4.
+    fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        DEVNET_POSEIDON_4.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 8.
+    fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        DEVNET_POSEIDON_8.hash_many(input, num_outputs)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
+        DEVNET_BHP_256.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
+        DEVNET_BHP_512.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
+        DEVNET_BHP_768.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
+        DEVNET_BHP_1024.hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
+        DEVNET_PEDERSEN_64.hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
+        DEVNET_PEDERSEN_128.hash_uncompressed(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+        DEVNET_POSEIDON_2.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+        DEVNET_POSEIDON_4.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+        DEVNET_POSEIDON_8.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+    fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        DEVNET_POSEIDON_2.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+    fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        DEVNET_POSEIDON_4.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+    fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        DEVNET_POSEIDON_8.hash_to_scalar(input)
+    }
+
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
+    fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(&*DEVNET_BHP_1024, &*DEVNET_BHP_512, leaves)
+    }
+
+    /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
+    fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(&*DEVNET_POSEIDON_4, &*DEVNET_POSEIDON_2, leaves)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_bhp<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<bool>,
+    ) -> bool {
+        path.verify(&*DEVNET_BHP_1024, &*DEVNET_BHP_512, root, leaf)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_psd<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> bool {
+        path.verify(&*DEVNET_POSEIDON_4, &*DEVNET_POSEIDON_2, root, leaf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = DevnetV0;
+
+    #[test]
+    fn test_g_scalar_multiply() {
+        // Compute G^r.
+        let scalar = Scalar::rand(&mut TestRng::default());
+        let group = CurrentNetwork::g_scalar_multiply(&scalar);
+        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+    }
+
+    #[test]
+    fn test_hashes_match_mainnet() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the hash functions match mainnet, as the inclusion circuit and its keys are shared.
+        let input = (0..256).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        assert_eq!(*CurrentNetwork::hash_bhp256(&input).unwrap(), *MainnetV0::hash_bhp256(&input).unwrap());
+        let input = [Uniform::rand(rng)];
+        assert_eq!(
+            *CurrentNetwork::hash_psd2(&input.map(Field::new)).unwrap(),
+            *MainnetV0::hash_psd2(&input.map(Field::new)).unwrap()
+        );
+        assert_eq!(*CurrentNetwork::serial_number_domain(), *MainnetV0::serial_number_domain());
+        assert_eq!(*CurrentNetwork::g_powers()[0].to_x_coordinate(), *MainnetV0::g_powers()[0].to_x_coordinate());
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, VarunaProvingKey, VarunaVerifyingKey};
+
+use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
+use std::sync::{Arc, Mutex};
+
+/// The `credits.aleo` proving and verifying key of a function.
+pub type CreditsKeyPair<N> = (VarunaProvingKey<N>, VarunaVerifyingKey<N>);
+
+/// The synthesizer of the `credits.aleo` proving and verifying key for the given function name.
+pub type CreditsKeySynthesizer<N> = fn(&str) -> Result<CreditsKeyPair<N>>;
+
+/// The shared `credits.aleo` proving and verifying key of a function.
+type CreditsKeys<N> = (Arc<VarunaProvingKey<N>>, Arc<VarunaVerifyingKey<N>>);
+
+/// The `credits.aleo` keys of a network without bundled keys, each of which is synthesized on first use.
+///
+/// The keys only depend on the circuit of each function, so every node of the network synthesizes the same keys.
+pub struct SynthesizedCreditsKeys<N: Network> {
+    /// The synthesizer of the keys.
+    synthesizer: OnceCell<CreditsKeySynthesizer<N>>,
+    /// The keys of each requested function name, which are initialized once synthesized.
+    keys: Mutex<IndexMap<String, &'static OnceCell<CreditsKeys<N>>>>,
+}
+
+impl<N: Network> Default for SynthesizedCreditsKeys<N> {
+    /// Initializes the keys, without a synthesizer.
+    fn default() -> Self {
+        Self { synthesizer: OnceCell::new(), keys: Mutex::new(IndexMap::new()) }
+    }
+}
+
+impl<N: Network> SynthesizedCreditsKeys<N> {
+    /// Sets the synthesizer of the keys. As every synthesizer yields the same keys, only the first one is kept.
+    pub fn set_synthesizer(&self, synthesizer: CreditsKeySynthesizer<N>) {
+        // Note: The result is ignored, as a synthesizer is already set.
+        let _ = self.synthesizer.set(synthesizer);
+    }
+
+    /// Returns the proving key for the given function name, synthesizing the keys of the function on first use.
+    pub fn proving_key(&self, function_name: &str) -> Result<&'static Arc<VarunaProvingKey<N>>> {
+        Ok(&self.get(function_name)?.0)
+    }
+
+    /// Returns the verifying key for the given function name, synthesizing the keys of the function on first use.
+    pub fn verifying_key(&self, function_name: &str) -> Result<&'static Arc<VarunaVerifyingKey<N>>> {
+        Ok(&self.get(function_name)?.1)
+    }
+
+    /// Returns the keys for the given function name, synthesizing them on first use.
+    fn get(&self, function_name: &str) -> Result<&'static CreditsKeys<N>> {
+        // Retrieve the cell of the function, so that the keys of each function are synthesized at most once,
+        // without blocking the synthesis of the keys of other functions.
+        let cell: &'static OnceCell<CreditsKeys<N>> = *self
+            .keys
+            .lock()
+            .map_err(|_| anyhow!("The 'credits.aleo' keys of {} are poisoned", N::NAME))?
+            .entry(function_name.to_string())
+            .or_insert_with(|| &*Box::leak(Box::new(OnceCell::new())));
+        // Synthesize the keys, if they are not yet initialized.
+        cell.get_or_try_init(|| {
+            let Some(synthesizer) = self.synthesizer.get() else {
+                bail!("Cannot synthesize the key for credits.aleo/{function_name}, as no synthesizer is set")
+            };
+            let (proving_key, verifying_key) = synthesizer(function_name)?;
+            Ok((Arc::new(proving_key), Arc::new(verifying_key)))
+        })
+    }
+}
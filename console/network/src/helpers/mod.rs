@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod credits_keys;
+pub use credits_keys::*;
+
 mod id;
 pub use id::*;
 
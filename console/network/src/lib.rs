@@ -25,9 +25,15 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+mod devnet_v0;
+pub use devnet_v0::*;
+
 mod mainnet_v0;
 pub use mainnet_v0::*;
 
+mod testnet_v0;
+pub use testnet_v0::*;
+
 pub mod prelude {
     pub use crate::{environment::prelude::*, Network};
 }
@@ -81,6 +87,8 @@ pub trait Network:
     const ID: u16;
     /// The network name.
     const NAME: &'static str;
+    /// The short network name, as used in REST paths.
+    const SHORT_NAME: &'static str;
     /// The network edition.
     const EDITION: u16;
 
@@ -107,6 +115,14 @@ pub trait Network:
     /// The maximum number of microcredits that can be spent on a finalize block.
     const TRANSACTION_SPEND_LIMIT: u64 = 100_000_000;
 
+    /// The block height from which an execution may bundle multiple call trees (see `Authorization::bundle`).
+    /// Note: Bundled executions are not yet scheduled, unless the network overrides this height.
+    #[cfg(not(any(test, feature = "test")))]
+    const BUNDLED_EXECUTION_HEIGHT: u32 = u32::MAX;
+    /// The block height from which an execution may bundle multiple call trees (see `Authorization::bundle`).
+    #[cfg(any(test, feature = "test"))]
+    const BUNDLED_EXECUTION_HEIGHT: u32 = 5;
+
     /// The anchor height, defined as the expected number of blocks to reach the coinbase target.
     const ANCHOR_HEIGHT: u32 = Self::ANCHOR_TIME as u32 / Self::BLOCK_TIME as u32;
     /// The anchor time in seconds.
@@ -196,6 +212,10 @@ pub trait Network:
     /// Returns the verifying key for the given function name in `credits.aleo`.
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>>;
 
+    /// Sets the synthesizer of the `credits.aleo` keys, for a network without bundled keys.
+    /// Note: A network with bundled keys ignores the synthesizer.
+    fn set_credits_key_synthesizer(_synthesizer: CreditsKeySynthesizer<Self>) {}
+
     /// Returns the `proving key` for the inclusion circuit.
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>>;
 
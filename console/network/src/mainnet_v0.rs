@@ -139,6 +139,8 @@ impl Network for MainnetV0 {
     const INCLUSION_FUNCTION_NAME: &'static str = snarkvm_parameters::mainnet::NETWORK_INCLUSION_FUNCTION_NAME;
     /// The network name.
     const NAME: &'static str = "Aleo Mainnet (v0)";
+    /// The short network name, as used in REST paths.
+    const SHORT_NAME: &'static str = "mainnet";
 
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8] {
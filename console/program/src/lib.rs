@@ -39,6 +39,9 @@ pub use id::*;
 mod locator;
 pub use locator::*;
 
+mod mapping_operation_path;
+pub use mapping_operation_path::MappingOperationPath;
+
 mod owner;
 pub use owner::*;
 
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for MappingOperationPath<N> {
+    /// Reads the path from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid mapping operation path version"));
+        }
+
+        // Read the mapping entry.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        let mapping_name = Identifier::read_le(&mut reader)?;
+        let key = Plaintext::read_le(&mut reader)?;
+        let value = Value::read_le(&mut reader)?;
+
+        // Read the mapping operation path.
+        let variant = u8::read_le(&mut reader)?;
+        let finalize_id_path = FinalizeIDPath::read_le(&mut reader)?;
+        let finalize_id = Field::read_le(&mut reader)?;
+        let finalize_operations_path = FinalizeOperationsPath::read_le(&mut reader)?;
+
+        // Construct the mapping operation path.
+        Ok(Self::from(
+            program_id,
+            mapping_name,
+            key,
+            value,
+            variant,
+            finalize_id_path,
+            finalize_id,
+            finalize_operations_path,
+        ))
+    }
+}
+
+impl<N: Network> ToBytes for MappingOperationPath<N> {
+    /// Writes the path to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the mapping entry.
+        self.program_id.write_le(&mut writer)?;
+        self.mapping_name.write_le(&mut writer)?;
+        self.key.write_le(&mut writer)?;
+        self.value.write_le(&mut writer)?;
+
+        // Write the mapping operation path.
+        self.variant.write_le(&mut writer)?;
+        self.finalize_id_path.write_le(&mut writer)?;
+        self.finalize_id.write_le(&mut writer)?;
+        self.finalize_operations_path.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the mapping operation path.
+            let (expected, _) =
+                crate::mapping_operation_path::test_helpers::sample_operation_path::<CurrentNetwork>(&mut rng).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, MappingOperationPath::read_le(&expected_bytes[..]).unwrap());
+            assert!(MappingOperationPath::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+}
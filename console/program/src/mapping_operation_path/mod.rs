@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+mod serialize;
+mod verify;
+
+use crate::{FinalizeIDPath, FinalizeOperationsPath, Identifier, Plaintext, ProgramID, Value};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// The mapping operation path proves the inclusion of a finalize operation in a block, i.e. that the block at some
+/// height `h` inserted or updated a mapping entry with the given value, against the finalize root of block `h`.
+///
+/// It is a proof of an operation, not of the mapping state: it does not prove the value of the entry
+/// after block `h`, nor that the entry still exists, as later operations are not covered by the path.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MappingOperationPath<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The mapping name.
+    mapping_name: Identifier<N>,
+    /// The key.
+    key: Plaintext<N>,
+    /// The value.
+    value: Value<N>,
+    /// The variant of the finalize operation, which is 1 (InsertKeyValue) or 2 (UpdateKeyValue).
+    variant: u8,
+    /// The Merkle path for the finalize operation.
+    finalize_id_path: FinalizeIDPath<N>,
+    /// The finalize ID of the transaction or of the ratifications.
+    finalize_id: Field<N>,
+    /// The Merkle path for the finalize ID.
+    finalize_operations_path: FinalizeOperationsPath<N>,
+}
+
+impl<N: Network> MappingOperationPath<N> {
+    /// Initializes a new instance of `MappingOperationPath`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from(
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+        variant: u8,
+        finalize_id_path: FinalizeIDPath<N>,
+        finalize_id: Field<N>,
+        finalize_operations_path: FinalizeOperationsPath<N>,
+    ) -> Self {
+        // Return the mapping operation path.
+        Self { program_id, mapping_name, key, value, variant, finalize_id_path, finalize_id, finalize_operations_path }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the mapping name.
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping_name
+    }
+
+    /// Returns the key.
+    pub const fn key(&self) -> &Plaintext<N> {
+        &self.key
+    }
+
+    /// Returns the value.
+    pub const fn value(&self) -> &Value<N> {
+        &self.value
+    }
+
+    /// Returns the variant of the finalize operation.
+    pub const fn variant(&self) -> u8 {
+        self.variant
+    }
+
+    /// Returns the Merkle path for the finalize operation.
+    pub const fn finalize_id_path(&self) -> &FinalizeIDPath<N> {
+        &self.finalize_id_path
+    }
+
+    /// Returns the finalize ID.
+    pub const fn finalize_id(&self) -> &Field<N> {
+        &self.finalize_id
+    }
+
+    /// Returns the Merkle path for the finalize ID.
+    pub const fn finalize_operations_path(&self) -> &FinalizeOperationsPath<N> {
+        &self.finalize_operations_path
+    }
+}
+
+impl<N: Network> MappingOperationPath<N> {
+    /// Returns the little-endian bits of the finalize operation with the given variant,
+    /// which inserts or updates the given key-value in the given mapping.
+    pub fn to_finalize_operation_bits_le(
+        variant: u8,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        value: &Value<N>,
+    ) -> Result<Vec<bool>> {
+        // Compute the mapping ID.
+        let mut preimage = Vec::new();
+        program_id.write_bits_le(&mut preimage);
+        false.write_bits_le(&mut preimage); // Separator
+        mapping_name.write_bits_le(&mut preimage);
+        let mapping_id = N::hash_bhp1024(&preimage)?;
+        // Compute the key ID.
+        false.write_bits_le(&mut preimage); // Separator
+        key.write_bits_le(&mut preimage);
+        let key_id = N::hash_bhp1024(&preimage)?;
+        // Compute the value ID.
+        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+
+        // Construct the bits of the finalize operation.
+        let mut bits = Vec::new();
+        variant.write_bits_le(&mut bits);
+        mapping_id.write_bits_le(&mut bits);
+        key_id.write_bits_le(&mut bits);
+        value_id.write_bits_le(&mut bits);
+        Ok(bits)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::{FinalizeIDTree, FinalizeOperationsTree, Literal};
+    use snarkvm_console_network::prelude::TestRng;
+    use snarkvm_console_types::U64;
+
+    /// Randomly sample a mapping operation path, along with the finalize root it belongs to.
+    pub fn sample_operation_path<N: Network>(rng: &mut TestRng) -> Result<(MappingOperationPath<N>, Field<N>)> {
+        // Prepare the mapping entry.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str("account")?;
+        let key = Plaintext::from(Literal::Field(Field::rand(rng)));
+        let value = Value::from(Literal::U64(U64::rand(rng)));
+        let variant = rng.gen_range(1..3);
+
+        // Construct the finalize ID path.
+        let leaf =
+            MappingOperationPath::to_finalize_operation_bits_le(variant, &program_id, &mapping_name, &key, &value)?;
+        let leaf_index = rng.gen_range(0..4);
+        let mut leaves = (0..4).map(|_| Field::<N>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        leaves[leaf_index] = leaf.clone();
+        let finalize_id_tree: FinalizeIDTree<N> = N::merkle_tree_bhp(&leaves)?;
+        let finalize_id = *finalize_id_tree.root();
+        let finalize_id_path = finalize_id_tree.prove(leaf_index, &leaf)?;
+
+        // Construct the finalize operations path.
+        let finalize_ids = [Field::<N>::rand(rng), finalize_id, Field::<N>::rand(rng)];
+        let finalize_operations_tree: FinalizeOperationsTree<N> =
+            N::merkle_tree_bhp(&finalize_ids.iter().map(ToBits::to_bits_le).collect::<Vec<_>>())?;
+        let finalize_root = *finalize_operations_tree.root();
+        let finalize_operations_path = finalize_operations_tree.prove(1, &finalize_id.to_bits_le())?;
+
+        Ok((
+            MappingOperationPath::from(
+                program_id,
+                mapping_name,
+                key,
+                value,
+                variant,
+                finalize_id_path,
+                finalize_id,
+                finalize_operations_path,
+            ),
+            finalize_root,
+        ))
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> MappingOperationPath<N> {
+    /// Checks if the finalize operation is included in the block with the given finalize root.
+    ///
+    /// # Diagram
+    /// The `[[ ]]` notation is used to denote public inputs.
+    /// ```ignore
+    ///
+    ///  [[ finalize_root ]]
+    ///          |
+    ///  finalize_operations_path
+    ///         |
+    ///    finalize_id
+    ///        |
+    ///  finalize_id_path
+    ///       |
+    ///  finalize_operation := ( variant || mapping_id || key_id || value_id )
+    ///                                          |            |          |
+    ///                   Hash( program_id || mapping_name )  |          |
+    ///                                  Hash( program_id || mapping_name || key )
+    ///                                                                  |
+    ///                                                    Hash( key_id || Hash( value ) )
+    /// ```
+    pub fn verify(&self, finalize_root: Field<N>) -> Result<()> {
+        // Ensure the variant is 1 (InsertKeyValue) or 2 (UpdateKeyValue).
+        ensure!(
+            self.variant == 1 || self.variant == 2,
+            "Finalize operation variant must be 1 (InsertKeyValue) or 2 (UpdateKeyValue)"
+        );
+        // Compute the bits of the finalize operation.
+        let finalize_operation = Self::to_finalize_operation_bits_le(
+            self.variant,
+            &self.program_id,
+            &self.mapping_name,
+            &self.key,
+            &self.value,
+        )?;
+        // Ensure the finalize ID path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.finalize_id_path, &self.finalize_id, &finalize_operation),
+            "'{}/{}' key '{}' with value '{}' does not belong to '{}' (a finalize ID)",
+            self.program_id,
+            self.mapping_name,
+            self.key,
+            self.value,
+            self.finalize_id
+        );
+        // Ensure the finalize operations path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.finalize_operations_path, &finalize_root, &self.finalize_id.to_bits_le()),
+            "'{}' (a finalize ID) does not belong to '{finalize_root}' (a finalize root)",
+            self.finalize_id
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_network::{prelude::TestRng, MainnetV0};
+    use snarkvm_console_types::U64;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_verify() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the mapping operation path.
+            let (operation_path, finalize_root) =
+                crate::mapping_operation_path::test_helpers::sample_operation_path::<CurrentNetwork>(rng).unwrap();
+
+            // Ensure the mapping operation path is valid.
+            operation_path.verify(finalize_root).unwrap();
+            // Ensure the mapping operation path is *not* valid for a random finalize root.
+            operation_path.verify(Field::rand(rng)).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn test_verify_modified_entry() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the mapping operation path.
+            let (operation_path, finalize_root) =
+                crate::mapping_operation_path::test_helpers::sample_operation_path::<CurrentNetwork>(rng).unwrap();
+
+            // Initializes the mapping operation path with the given entry and variant.
+            let modify = |key: Plaintext<CurrentNetwork>, value: Value<CurrentNetwork>, variant: u8| {
+                MappingOperationPath::from(
+                    *operation_path.program_id(),
+                    *operation_path.mapping_name(),
+                    key,
+                    value,
+                    variant,
+                    operation_path.finalize_id_path().clone(),
+                    *operation_path.finalize_id(),
+                    operation_path.finalize_operations_path().clone(),
+                )
+            };
+
+            // Ensure the mapping operation path is *not* valid for a different key.
+            let key = Plaintext::from(Literal::Field(Field::rand(rng)));
+            modify(key, operation_path.value().clone(), operation_path.variant()).verify(finalize_root).unwrap_err();
+            // Ensure the mapping operation path is *not* valid for a different value.
+            let value = Value::from(Literal::U64(U64::rand(rng)));
+            modify(operation_path.key().clone(), value, operation_path.variant()).verify(finalize_root).unwrap_err();
+            // Ensure the mapping operation path is *not* valid for a different variant.
+            let variant = 3 - operation_path.variant();
+            let (key, value) = (operation_path.key().clone(), operation_path.value().clone());
+            modify(key, value, variant).verify(finalize_root).unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Request<N> {
+    /// Assembles the request from the given signing payload and the payload signature returned by the signer.
+    /// This method does not require the private key, and ensures the assembled request is valid.
+    pub fn from_signing_payload(payload: &SigningPayload<N>, signature: &PayloadSignature<N>) -> Result<Self> {
+        // Retrieve the program ID and function name.
+        let program_id = *payload.program_id();
+        let function_name = *payload.function_name();
+
+        // Derive the signer from the compute key.
+        let signer = Address::try_from(signature.signature().compute_key())?;
+        // Retrieve the transition view key `tvk`.
+        let tvk = *signature.tvk();
+        // Retrieve the tag secret key `sk_tag`.
+        let sk_tag = *signature.sk_tag();
+        // Compute the transition commitment `tcm` as `Hash(tvk)`.
+        let tcm = N::hash_psd2(&[tvk])?;
+        // Compute the signer commitment `scm` as `Hash(signer || root_tvk)`.
+        let root_tvk = payload.root_tvk().unwrap_or(tvk);
+        let scm = N::hash_psd2(&[signer.deref().to_x_coordinate(), root_tvk])?;
+
+        // Ensure the payload is for the current network.
+        ensure!(
+            **payload.network_id() == N::ID,
+            "Invalid network ID in the signing payload. Expected {}, found {}",
+            N::ID,
+            **payload.network_id()
+        );
+        // Retrieve the network ID.
+        let network_id = U16::new(N::ID);
+        // Compute the function ID.
+        let function_id = compute_function_id(&network_id, &program_id, &function_name)?;
+
+        // Initialize an iterator over the record gammas.
+        let mut gammas = signature.gammas().iter();
+
+        // Compute the input IDs.
+        let input_ids = payload
+            .inputs()
+            .iter()
+            .zip_eq(payload.input_types())
+            .enumerate()
+            .map(|(index, (input, input_type))| {
+                Self::compute_input_id(
+                    &signer,
+                    &program_id,
+                    function_id,
+                    sk_tag,
+                    tvk,
+                    tcm,
+                    index,
+                    input,
+                    input_type,
+                    // Retrieve the `gamma` for this record.
+                    |_| gammas.next().copied().ok_or_else(|| anyhow!("Missing 'gamma' for record input #{index}")),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure all of the gammas were used.
+        ensure!(gammas.next().is_none(), "Found more 'gamma' values than record inputs");
+
+        // Construct the request.
+        let request = Self {
+            signer,
+            network_id,
+            program_id,
+            function_name,
+            input_ids,
+            inputs: payload.inputs().to_vec(),
+            signature: *signature.signature(),
+            sk_tag,
+            tvk,
+            tcm,
+            scm,
+        };
+        // Ensure the request is valid.
+        ensure!(
+            request.verify(payload.input_types(), payload.is_root()),
+            "The signature is invalid for the signing payload of '{program_id}/{function_name}'"
+        );
+        // Return the request.
+        Ok(request)
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Request<N> {
+    /// Returns the input ID for the given input, where:
+    ///     constant and public inputs are hashed (using `tcm`) to a field element,
+    ///     private inputs are encrypted (using `tvk`) and hashed to a field element,
+    ///     record inputs are computed to their serial number, using `gamma` := sk_sig * HashToGroup(commitment),
+    ///     external record inputs are hashed (using `tvk`) to a field element.
+    ///
+    /// As `sk_sig` is not always available to the caller, `gamma` is resolved from the record commitment
+    /// by the given `to_gamma` closure, which is only invoked for record inputs.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn compute_input_id(
+        signer: &Address<N>,
+        program_id: &ProgramID<N>,
+        function_id: Field<N>,
+        sk_tag: Field<N>,
+        tvk: Field<N>,
+        tcm: Field<N>,
+        index: usize,
+        input: &Value<N>,
+        input_type: &ValueType<N>,
+        to_gamma: impl FnOnce(Field<N>) -> Result<Group<N>>,
+    ) -> Result<InputID<N>> {
+        // Construct the (console) input index as a field element.
+        let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+
+        match input_type {
+            // A constant or public input is hashed (using `tcm`) to a field element.
+            ValueType::Constant(..) | ValueType::Public(..) => {
+                // Ensure the input is a plaintext.
+                ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
+
+                // Construct the preimage as `(function ID || input || tcm || index)`.
+                let mut preimage = Vec::new();
+                preimage.push(function_id);
+                preimage.extend(input.to_fields()?);
+                preimage.push(tcm);
+                preimage.push(index);
+                // Hash the input to a field element.
+                let input_hash = N::hash_psd8(&preimage)?;
+
+                // Return the input ID.
+                match input_type {
+                    ValueType::Constant(..) => Ok(InputID::Constant(input_hash)),
+                    _ => Ok(InputID::Public(input_hash)),
+                }
+            }
+            // A private input is encrypted (using `tvk`) and hashed to a field element.
+            ValueType::Private(..) => {
+                // Compute the input view key as `Hash(function ID || tvk || index)`.
+                let input_view_key = N::hash_psd4(&[function_id, tvk, index])?;
+                // Compute the ciphertext.
+                let ciphertext = match input {
+                    Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(input_view_key)?,
+                    // Ensure the input is a plaintext.
+                    Value::Record(..) => bail!("Expected a plaintext input, found a record input"),
+                    Value::Future(..) => bail!("Expected a plaintext input, found a future input"),
+                };
+                // Hash the ciphertext to a field element.
+                let input_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
+
+                // Return the input ID.
+                Ok(InputID::Private(input_hash))
+            }
+            // A record input is computed to its serial number.
+            ValueType::Record(record_name) => {
+                // Retrieve the record.
+                let record = match input {
+                    Value::Record(record) => record,
+                    // Ensure the input is a record.
+                    Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
+                    Value::Future(..) => bail!("Expected a record input, found a future input"),
+                };
+                // Ensure the record belongs to the signer.
+                ensure!(**record.owner() == *signer, "Input record for '{program_id}' must belong to the signer");
+
+                // Compute the record commitment.
+                let commitment = record.to_commitment(program_id, record_name)?;
+                // Resolve `gamma` for the record commitment.
+                let gamma = to_gamma(commitment)?;
+
+                // Compute the `serial_number` from `gamma`.
+                let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
+                // Compute the tag.
+                let tag = Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?;
+
+                // Return the input ID.
+                Ok(InputID::Record(commitment, gamma, serial_number, tag))
+            }
+            // An external record input is hashed (using `tvk`) to a field element.
+            ValueType::ExternalRecord(..) => {
+                // Ensure the input is a record.
+                ensure!(matches!(input, Value::Record(..)), "Expected a record input");
+
+                // Construct the preimage as `(function ID || input || tvk || index)`.
+                let mut preimage = Vec::new();
+                preimage.push(function_id);
+                preimage.extend(input.to_fields()?);
+                preimage.push(tvk);
+                preimage.push(index);
+                // Hash the input to a field element.
+                let input_hash = N::hash_psd8(&preimage)?;
+
+                // Return the input ID.
+                Ok(InputID::ExternalRecord(input_hash))
+            }
+            // A future is not a valid input.
+            ValueType::Future(..) => bail!("A future is not a valid input"),
+        }
+    }
+}
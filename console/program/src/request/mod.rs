@@ -15,7 +15,15 @@
 mod input_id;
 pub use input_id::InputID;
 
+mod payload_signature;
+pub use payload_signature::PayloadSignature;
+
+mod signing_payload;
+pub use signing_payload::SigningPayload;
+
+mod assemble;
 mod bytes;
+mod input_ids;
 mod serialize;
 mod sign;
 mod string;
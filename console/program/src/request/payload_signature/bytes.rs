@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for PayloadSignature<N> {
+    /// Reads the payload signature from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid payload signature version"));
+        }
+
+        // Read the signature.
+        let signature = FromBytes::read_le(&mut reader)?;
+        // Read the tag secret key.
+        let sk_tag = FromBytes::read_le(&mut reader)?;
+        // Read the transition view key.
+        let tvk = FromBytes::read_le(&mut reader)?;
+
+        // Read the number of gammas.
+        let gammas_len = u16::read_le(&mut reader)?;
+        // Read the gammas.
+        let gammas = (0..gammas_len).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Return the payload signature.
+        Self::new(signature, sk_tag, tvk, gammas).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for PayloadSignature<N> {
+    /// Writes the payload signature to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the signature.
+        self.signature.write_le(&mut writer)?;
+        // Write the tag secret key.
+        self.sk_tag.write_le(&mut writer)?;
+        // Write the transition view key.
+        self.tvk.write_le(&mut writer)?;
+
+        // Write the number of gammas.
+        u16::try_from(self.gammas.len())
+            .or_halt_with::<N>("Payload signature gammas length exceeds u16")
+            .write_le(&mut writer)?;
+        // Write the gammas.
+        for gamma in &self.gammas {
+            gamma.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for expected in test_helpers::sample_payload_signatures(&mut rng).into_iter() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, PayloadSignature::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{InputID, Request};
+use snarkvm_console_account::Signature;
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+/// The payload signature is returned by an external signer for a `SigningPayload`.
+/// It contains the signature, along with the secret components of the request that
+/// can only be derived from the private key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PayloadSignature<N: Network> {
+    /// The signature for the transition.
+    signature: Signature<N>,
+    /// The tag secret key.
+    sk_tag: Field<N>,
+    /// The transition view key.
+    tvk: Field<N>,
+    /// The `gamma` value for each record input, in input order.
+    gammas: Vec<Group<N>>,
+}
+
+impl<N: Network> PayloadSignature<N> {
+    /// Initializes a new payload signature.
+    pub fn new(signature: Signature<N>, sk_tag: Field<N>, tvk: Field<N>, gammas: Vec<Group<N>>) -> Result<Self> {
+        // Ensure the number of gammas is within bounds.
+        ensure!(gammas.len() <= N::MAX_INPUTS, "The payload signature exceeds the maximum number of inputs");
+        // Return the payload signature.
+        Ok(Self { signature, sk_tag, tvk, gammas })
+    }
+
+    /// Returns the signature for the transition.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the tag secret key `sk_tag`.
+    pub const fn sk_tag(&self) -> &Field<N> {
+        &self.sk_tag
+    }
+
+    /// Returns the transition view key `tvk`.
+    pub const fn tvk(&self) -> &Field<N> {
+        &self.tvk
+    }
+
+    /// Returns the `gamma` value for each record input.
+    pub fn gammas(&self) -> &[Group<N>] {
+        &self.gammas
+    }
+}
+
+impl<N: Network> TryFrom<&Request<N>> for PayloadSignature<N> {
+    type Error = Error;
+
+    /// Extracts the payload signature from the given request.
+    fn try_from(request: &Request<N>) -> Result<Self> {
+        // Retrieve the `gamma` value for each record input.
+        let gammas = request
+            .input_ids()
+            .iter()
+            .filter_map(|input_id| match input_id {
+                InputID::Record(_, gamma, ..) => Some(*gamma),
+                _ => None,
+            })
+            .collect();
+        // Return the payload signature.
+        Self::new(*request.signature(), *request.sk_tag(), *request.tvk(), gammas)
+    }
+}
+
+#[cfg(test)]
+pub(super) mod test_helpers {
+    use super::*;
+    use crate::request::signing_payload::test_helpers::sample_signing_payloads;
+
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    pub(crate) fn sample_payload_signatures(rng: &mut TestRng) -> Vec<PayloadSignature<CurrentNetwork>> {
+        sample_signing_payloads(rng)
+            .into_iter()
+            .map(|(private_key, payload)| payload.sign(&private_key, rng).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for PayloadSignature<N> {
+    /// Serializes the payload signature into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut signature = serializer.serialize_struct("PayloadSignature", 4)?;
+                signature.serialize_field("signature", &self.signature)?;
+                signature.serialize_field("sk_tag", &self.sk_tag)?;
+                signature.serialize_field("tvk", &self.tvk)?;
+                signature.serialize_field("gammas", &self.gammas)?;
+                signature.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PayloadSignature<N> {
+    /// Deserializes the payload signature from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the payload signature from a string into a value.
+                let mut signature = serde_json::Value::deserialize(deserializer)?;
+                // Recover the payload signature.
+                Self::new(
+                    // Retrieve the signature.
+                    DeserializeExt::take_from_value::<D>(&mut signature, "signature")?,
+                    // Retrieve the `sk_tag`.
+                    DeserializeExt::take_from_value::<D>(&mut signature, "sk_tag")?,
+                    // Retrieve the `tvk`.
+                    DeserializeExt::take_from_value::<D>(&mut signature, "tvk")?,
+                    // Retrieve the gammas.
+                    DeserializeExt::take_from_value::<D>(&mut signature, "gammas")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "payload signature"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let mut rng = TestRng::default();
+
+        for expected in test_helpers::sample_payload_signatures(&mut rng).into_iter() {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+
+            // Deserialize
+            assert_eq!(expected, PayloadSignature::from_str(expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        let mut rng = TestRng::default();
+
+        for expected in test_helpers::sample_payload_signatures(&mut rng).into_iter() {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, PayloadSignature::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
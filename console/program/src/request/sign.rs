@@ -95,131 +95,43 @@ impl<N: Network> Request<N> {
             // Store the prepared input.
             prepared_inputs.push(input.clone());
 
-            match input_type {
-                // A constant input is hashed (using `tcm`) to a field element.
-                ValueType::Constant(..) => {
-                    // Ensure the input is a plaintext.
-                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
-
-                    // Construct the (console) input index as a field element.
-                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                    // Construct the preimage as `(function ID || input || tcm || index)`.
-                    let mut preimage = Vec::new();
-                    preimage.push(function_id);
-                    preimage.extend(input.to_fields()?);
-                    preimage.push(tcm);
-                    preimage.push(index);
-                    // Hash the input to a field element.
-                    let input_hash = N::hash_psd8(&preimage)?;
-
-                    // Add the input hash to the preimage.
-                    message.push(input_hash);
-                    // Add the input ID to the inputs.
-                    input_ids.push(InputID::Constant(input_hash));
-                }
-                // A public input is hashed (using `tcm`) to a field element.
-                ValueType::Public(..) => {
-                    // Ensure the input is a plaintext.
-                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
-
-                    // Construct the (console) input index as a field element.
-                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                    // Construct the preimage as `(function ID || input || tcm || index)`.
-                    let mut preimage = Vec::new();
-                    preimage.push(function_id);
-                    preimage.extend(input.to_fields()?);
-                    preimage.push(tcm);
-                    preimage.push(index);
-                    // Hash the input to a field element.
-                    let input_hash = N::hash_psd8(&preimage)?;
-
-                    // Add the input hash to the preimage.
-                    message.push(input_hash);
-                    // Add the input ID to the inputs.
-                    input_ids.push(InputID::Public(input_hash));
-                }
-                // A private input is encrypted (using `tvk`) and hashed to a field element.
-                ValueType::Private(..) => {
-                    // Ensure the input is a plaintext.
-                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
-
-                    // Construct the (console) input index as a field element.
-                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                    // Compute the input view key as `Hash(function ID || tvk || index)`.
-                    let input_view_key = N::hash_psd4(&[function_id, tvk, index])?;
-                    // Compute the ciphertext.
-                    let ciphertext = match &input {
-                        Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(input_view_key)?,
-                        // Ensure the input is a plaintext.
-                        Value::Record(..) => bail!("Expected a plaintext input, found a record input"),
-                        Value::Future(..) => bail!("Expected a plaintext input, found a future input"),
-                    };
-                    // Hash the ciphertext to a field element.
-                    let input_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
-
-                    // Add the input hash to the preimage.
-                    message.push(input_hash);
-                    // Add the input hash to the inputs.
-                    input_ids.push(InputID::Private(input_hash));
-                }
-                // A record input is computed to its serial number.
-                ValueType::Record(record_name) => {
-                    // Retrieve the record.
-                    let record = match &input {
-                        Value::Record(record) => record,
-                        // Ensure the input is a record.
-                        Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
-                        Value::Future(..) => bail!("Expected a record input, found a future input"),
-                    };
-                    // Ensure the record belongs to the signer.
-                    ensure!(**record.owner() == signer, "Input record for '{program_id}' must belong to the signer");
-
-                    // Compute the record commitment.
-                    let commitment = record.to_commitment(&program_id, record_name)?;
-
+            // Initialize a slot for the generator `H` of a record input.
+            let mut generator = None;
+            // Compute the input ID.
+            let input_id = Self::compute_input_id(
+                &signer,
+                &program_id,
+                function_id,
+                sk_tag,
+                tvk,
+                tcm,
+                index,
+                &input,
+                input_type,
+                |commitment| {
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+                    generator = Some(h);
+                    // Compute `gamma` as `sk_sig * H`.
+                    Ok(h * sk_sig)
+                },
+            )?;
+
+            match input_id {
+                InputID::Record(_, gamma, _, tag) => {
+                    // Retrieve the generator `H`.
+                    let h = generator.ok_or_else(|| anyhow!("Missing the generator for record input #{index}"))?;
                     // Compute `h_r` as `r * H`.
                     let h_r = h * r;
-                    // Compute `gamma` as `sk_sig * H`.
-                    let gamma = h * sk_sig;
-
-                    // Compute the `serial_number` from `gamma`.
-                    let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
-                    // Compute the tag.
-                    let tag = Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?;
-
                     // Add (`H`, `r * H`, `gamma`, `tag`) to the preimage.
                     message.extend([h, h_r, gamma].iter().map(|point| point.to_x_coordinate()));
                     message.push(tag);
-
-                    // Add the input ID.
-                    input_ids.push(InputID::Record(commitment, gamma, serial_number, tag));
-                }
-                // An external record input is hashed (using `tvk`) to a field element.
-                ValueType::ExternalRecord(..) => {
-                    // Ensure the input is a record.
-                    ensure!(matches!(input, Value::Record(..)), "Expected a record input");
-
-                    // Construct the (console) input index as a field element.
-                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
-                    // Construct the preimage as `(function ID || input || tvk || index)`.
-                    let mut preimage = Vec::new();
-                    preimage.push(function_id);
-                    preimage.extend(input.to_fields()?);
-                    preimage.push(tvk);
-                    preimage.push(index);
-                    // Hash the input to a field element.
-                    let input_hash = N::hash_psd8(&preimage)?;
-
-                    // Add the input hash to the preimage.
-                    message.push(input_hash);
-                    // Add the input hash to the inputs.
-                    input_ids.push(InputID::ExternalRecord(input_hash));
                 }
-                // A future is not a valid input.
-                ValueType::Future(..) => bail!("A future is not a valid input"),
+                // Add the input hash to the preimage.
+                _ => message.push(*input_id.id()),
             }
+            // Add the input ID to the inputs.
+            input_ids.push(input_id);
         }
 
         // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
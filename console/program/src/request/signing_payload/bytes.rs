@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for SigningPayload<N> {
+    /// Reads the signing payload from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid signing payload version"));
+        }
+
+        // Read the network ID.
+        let network_id = U16::<N>::read_le(&mut reader)?;
+        // Ensure the network ID is correct.
+        if *network_id != N::ID {
+            return Err(error(format!("Invalid network ID. Expected {}, found {}", N::ID, *network_id)));
+        }
+        // Read the program ID.
+        let program_id = FromBytes::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = FromBytes::read_le(&mut reader)?;
+
+        // Read the number of inputs.
+        let inputs_len = u16::read_le(&mut reader)?;
+        // Read the inputs.
+        let inputs = (0..inputs_len).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the input types.
+        let input_types = (0..inputs_len).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the root transition view key.
+        let root_tvk = match bool::read_le(&mut reader)? {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+        // Read the `is_root` flag.
+        let is_root = bool::read_le(&mut reader)?;
+
+        // Return the signing payload.
+        Self::new(program_id, function_name, inputs, input_types, root_tvk, is_root).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for SigningPayload<N> {
+    /// Writes the signing payload to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the network ID.
+        self.network_id.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the function name.
+        self.function_name.write_le(&mut writer)?;
+
+        // Ensure the inputs and input types are the same length.
+        if self.inputs.len() != self.input_types.len() {
+            return Err(error("Invalid signing payload: mismatching number of inputs and input types"));
+        }
+
+        // Write the number of inputs.
+        u16::try_from(self.inputs.len())
+            .or_halt_with::<N>("Signing payload inputs length exceeds u16")
+            .write_le(&mut writer)?;
+        // Write the inputs.
+        for input in &self.inputs {
+            input.write_le(&mut writer)?;
+        }
+        // Write the input types.
+        for input_type in &self.input_types {
+            input_type.write_le(&mut writer)?;
+        }
+
+        // Write the root transition view key.
+        match &self.root_tvk {
+            Some(root_tvk) => {
+                true.write_le(&mut writer)?;
+                root_tvk.write_le(&mut writer)?;
+            }
+            None => false.write_le(&mut writer)?,
+        }
+        // Write the `is_root` flag.
+        self.is_root.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for (_, expected) in test_helpers::sample_signing_payloads(&mut rng).into_iter() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, SigningPayload::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Identifier, ProgramID, Request, Value, ValueType};
+use snarkvm_console_account::PrivateKey;
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+use super::PayloadSignature;
+
+/// The signing payload is the minimal, canonical description of a request that an external signer
+/// (e.g. an air-gapped device) needs in order to authorize a call, without holding any proving material.
+///
+/// The signer returns a `PayloadSignature`, which is combined with this payload via
+/// `Request::from_signing_payload` to assemble the full request.
+///
+/// Note: A signing payload only covers the root request. Functions that call into other functions
+/// require a signed request per call, and cannot be authorized from a single signing payload.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SigningPayload<N: Network> {
+    /// The network ID.
+    network_id: U16<N>,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The function input types.
+    input_types: Vec<ValueType<N>>,
+    /// The root transition view key, if the request is not the root request.
+    root_tvk: Option<Field<N>>,
+    /// A boolean indicating if this is the root request.
+    is_root: bool,
+}
+
+impl<N: Network> SigningPayload<N> {
+    /// Initializes a new signing payload.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        input_types: Vec<ValueType<N>>,
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+    ) -> Result<Self> {
+        // Ensure the number of inputs matches the number of input types.
+        ensure!(
+            inputs.len() == input_types.len(),
+            "'{program_id}/{function_name}' expects {} inputs, but {} were provided.",
+            input_types.len(),
+            inputs.len()
+        );
+        // Ensure the number of inputs is within bounds.
+        ensure!(
+            inputs.len() <= N::MAX_INPUTS,
+            "'{program_id}/{function_name}' exceeds the maximum number of inputs ({})",
+            N::MAX_INPUTS
+        );
+        // Ensure a future is not provided as an input.
+        ensure!(
+            !input_types.iter().any(|input_type| matches!(input_type, ValueType::Future(..))),
+            "A future is not a valid input"
+        );
+        // Return the signing payload.
+        Ok(Self { network_id: U16::new(N::ID), program_id, function_name, inputs, input_types, root_tvk, is_root })
+    }
+
+    /// Returns the network ID.
+    pub const fn network_id(&self) -> &U16<N> {
+        &self.network_id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the function inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+
+    /// Returns the function input types.
+    pub fn input_types(&self) -> &[ValueType<N>] {
+        &self.input_types
+    }
+
+    /// Returns the root transition view key, if one was provided.
+    pub const fn root_tvk(&self) -> Option<Field<N>> {
+        self.root_tvk
+    }
+
+    /// Returns `true` if this is the root request.
+    pub const fn is_root(&self) -> bool {
+        self.is_root
+    }
+
+    /// Signs the payload with the given private key, returning the signature and secret components
+    /// required to assemble the request. This is the only step that requires the private key.
+    pub fn sign<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<PayloadSignature<N>> {
+        // Compute the request.
+        let request = Request::sign(
+            private_key,
+            self.program_id,
+            self.function_name,
+            self.inputs.iter().cloned(),
+            &self.input_types,
+            self.root_tvk,
+            self.is_root,
+            rng,
+        )?;
+        // Extract the signature and secret components.
+        PayloadSignature::try_from(&request)
+    }
+}
+
+#[cfg(test)]
+pub(super) mod test_helpers {
+    use super::*;
+    use snarkvm_console_account::Address;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    pub(crate) fn sample_signing_payloads(
+        rng: &mut TestRng,
+    ) -> Vec<(PrivateKey<CurrentNetwork>, SigningPayload<CurrentNetwork>)> {
+        (0..ITERATIONS)
+            .map(|i| {
+                // Sample a random private key and address.
+                let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+                let address = Address::try_from(&private_key).unwrap();
+
+                // Construct a program ID and function name.
+                let program_id = ProgramID::from_str("token.aleo").unwrap();
+                let function_name = Identifier::from_str("transfer").unwrap();
+
+                // Prepare a record belonging to the address.
+                let record_string =
+                    format!("{{ owner: {address}.private, token_amount: {i}u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}");
+
+                // Construct the inputs.
+                let inputs = vec![
+                    Value::from_str(&format!("{{ token_amount: {i}u128 }}")).unwrap(),
+                    Value::from_str(&format!("{{ token_amount: {i}u128 }}")).unwrap(),
+                    Value::from_str(&format!("{{ token_amount: {i}u128 }}")).unwrap(),
+                    Value::from_str(&record_string).unwrap(),
+                    Value::from_str(&record_string).unwrap(),
+                ];
+                // Construct the input types.
+                let input_types = vec![
+                    ValueType::from_str("amount.constant").unwrap(),
+                    ValueType::from_str("amount.public").unwrap(),
+                    ValueType::from_str("amount.private").unwrap(),
+                    ValueType::from_str("token.record").unwrap(),
+                    ValueType::from_str("token.aleo/token.record").unwrap(),
+                ];
+
+                // Alternate between root and child requests.
+                let (root_tvk, is_root) = match i % 2 == 0 {
+                    true => (None, true),
+                    false => (Some(Field::rand(rng)), false),
+                };
+
+                // Construct the signing payload.
+                let payload =
+                    SigningPayload::new(program_id, function_name, inputs, input_types, root_tvk, is_root).unwrap();
+                (private_key, payload)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sign_and_assemble() {
+        let rng = &mut TestRng::default();
+
+        for (private_key, payload) in sample_signing_payloads(rng) {
+            // Sign the payload, using a fixed seed.
+            let seed: u64 = rng.gen();
+            let signature = payload.sign(&private_key, &mut TestRng::fixed(seed)).unwrap();
+
+            // Assemble the request from the payload and the signature.
+            let candidate = Request::from_signing_payload(&payload, &signature).unwrap();
+            assert!(candidate.verify(payload.input_types(), payload.is_root()));
+
+            // Ensure the assembled request matches the request signed in-process.
+            let expected = Request::sign(
+                &private_key,
+                *payload.program_id(),
+                *payload.function_name(),
+                payload.inputs().iter().cloned(),
+                payload.input_types(),
+                payload.root_tvk(),
+                payload.is_root(),
+                &mut TestRng::fixed(seed),
+            )
+            .unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_assemble_fails_on_mismatched_payload() {
+        let rng = &mut TestRng::default();
+
+        let payloads = sample_signing_payloads(rng);
+        let (private_key, payload) = &payloads[0];
+        let (_, other_payload) = &payloads[2];
+
+        // Sign the first payload.
+        let signature = payload.sign(private_key, rng).unwrap();
+        // Ensure the signature does not assemble into a request for a different payload.
+        assert!(Request::from_signing_payload(other_payload, &signature).is_err());
+    }
+
+    #[test]
+    fn test_assemble_fails_on_mismatched_network_id() {
+        let rng = &mut TestRng::default();
+
+        let payloads = sample_signing_payloads(rng);
+        let (private_key, payload) = &payloads[0];
+
+        // Sign the payload.
+        let signature = payload.sign(private_key, rng).unwrap();
+        // Ensure the signature does not assemble into a request for a payload on a different network.
+        let other_payload =
+            SigningPayload { network_id: U16::new(CurrentNetwork::ID.wrapping_add(1)), ..payload.clone() };
+        assert!(Request::from_signing_payload(&other_payload, &signature).is_err());
+    }
+
+    #[test]
+    fn test_new_fails_on_mismatched_inputs() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let inputs = vec![Value::from_str("1u64").unwrap()];
+        let input_types = vec![];
+        assert!(SigningPayload::new(program_id, function_name, inputs, input_types, None, true).is_err());
+    }
+}
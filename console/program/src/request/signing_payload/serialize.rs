@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for SigningPayload<N> {
+    /// Serializes the signing payload into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut payload = serializer.serialize_struct("SigningPayload", 7)?;
+                payload.serialize_field("network", &self.network_id)?;
+                payload.serialize_field("program", &self.program_id)?;
+                payload.serialize_field("function", &self.function_name)?;
+                payload.serialize_field("inputs", &self.inputs)?;
+                payload.serialize_field("input_types", &self.input_types)?;
+                payload.serialize_field("root_tvk", &self.root_tvk)?;
+                payload.serialize_field("is_root", &self.is_root)?;
+                payload.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SigningPayload<N> {
+    /// Deserializes the signing payload from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the signing payload from a string into a value.
+                let mut payload = serde_json::Value::deserialize(deserializer)?;
+
+                // Retrieve the network ID.
+                let network_id: U16<N> = DeserializeExt::take_from_value::<D>(&mut payload, "network")?;
+                // Ensure the network ID is correct.
+                if *network_id != N::ID {
+                    return Err(de::Error::custom(format!(
+                        "Invalid network ID. Expected {}, found {}",
+                        N::ID,
+                        *network_id
+                    )));
+                }
+
+                // Recover the signing payload.
+                Self::new(
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut payload, "program")?,
+                    // Retrieve the function name.
+                    DeserializeExt::take_from_value::<D>(&mut payload, "function")?,
+                    // Retrieve the inputs.
+                    DeserializeExt::take_from_value::<D>(&mut payload, "inputs")?,
+                    // Retrieve the input types.
+                    DeserializeExt::take_from_value::<D>(&mut payload, "input_types")?,
+                    // Retrieve the root transition view key.
+                    DeserializeExt::take_from_value::<D>(&mut payload, "root_tvk")?,
+                    // Retrieve the `is_root` flag.
+                    DeserializeExt::take_from_value::<D>(&mut payload, "is_root")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "signing payload"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let mut rng = TestRng::default();
+
+        for (_, expected) in test_helpers::sample_signing_payloads(&mut rng).into_iter() {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+
+            // Deserialize
+            assert_eq!(expected, SigningPayload::from_str(expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        let mut rng = TestRng::default();
+
+        for (_, expected) in test_helpers::sample_signing_payloads(&mut rng).into_iter() {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, SigningPayload::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
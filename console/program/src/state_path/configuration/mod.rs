@@ -46,6 +46,16 @@ pub type HeaderTree<N> = BHPMerkleTree<N, HEADER_DEPTH>;
 /// The Merkle path for the block header.
 pub type HeaderPath<N> = MerklePath<N, HEADER_DEPTH>;
 
+/// The Merkle tree for the finalize operations in a transaction.
+pub type FinalizeIDTree<N> = BHPMerkleTree<N, FINALIZE_ID_DEPTH>;
+/// The Merkle path for a finalize operation in a transaction.
+pub type FinalizeIDPath<N> = MerklePath<N, FINALIZE_ID_DEPTH>;
+
+/// The Merkle tree for the finalize IDs in a block.
+pub type FinalizeOperationsTree<N> = BHPMerkleTree<N, FINALIZE_OPERATIONS_DEPTH>;
+/// The Merkle path for a finalize ID in a block.
+pub type FinalizeOperationsPath<N> = MerklePath<N, FINALIZE_OPERATIONS_DEPTH>;
+
 /// The Merkle tree for ratifications in a block.
 pub type RatificationsTree<N> = BHPMerkleTree<N, RATIFICATIONS_DEPTH>;
 /// The Merkle path for a ratification in a block.
@@ -0,0 +1,541 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, StatePath, Value},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// The configuration of a `RestClient`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestClientConfig {
+    /// The timeout of each request.
+    pub timeout: Duration,
+    /// The maximum number of retries of a request, after the first attempt on every endpoint fails.
+    pub max_retries: u32,
+    /// The delay before the first retry, which doubles on every subsequent retry.
+    pub initial_backoff: Duration,
+    /// The maximum delay before a retry.
+    pub max_backoff: Duration,
+    /// The maximum number of cached programs, beyond which the least recently used programs are evicted.
+    pub cache_capacity: usize,
+}
+
+impl Default for RestClientConfig {
+    /// Returns the default configuration.
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+            cache_capacity: 1024,
+        }
+    }
+}
+
+impl RestClientConfig {
+    /// Returns the delay before the given retry (starting at 1).
+    fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// A bounded cache, which evicts the least recently used entry when it is full.
+struct Cache<K, V> {
+    /// The cached entries, from the least to the most recently used.
+    entries: IndexMap<K, V>,
+    /// The maximum number of entries.
+    capacity: usize,
+}
+
+impl<K: std::hash::Hash + Eq, V: Clone> Cache<K, V> {
+    /// Initializes a new cache with the given capacity.
+    fn new(capacity: usize) -> Self {
+        Self { entries: Default::default(), capacity }
+    }
+
+    /// Returns the cached value for the given key, if any, and marks it as the most recently used.
+    fn get(&mut self, key: &K) -> Option<V> {
+        let index = self.entries.get_index_of(key)?;
+        self.entries.move_index(index, self.entries.len() - 1);
+        self.entries.last().map(|(_, value)| value.clone())
+    }
+
+    /// Caches the given value for the given key, as the most recently used,
+    /// evicting the least recently used entry if the cache is full.
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            self.entries.shift_remove_index(0);
+        }
+        let (index, _) = self.entries.insert_full(key, value);
+        self.entries.move_index(index, self.entries.len() - 1);
+    }
+}
+
+/// The outcome of a failed request.
+enum RequestError {
+    /// The resource does not exist on the endpoint, which may lag behind the other endpoints.
+    NotFound,
+    /// The request failed, and may be retried.
+    Failed(String),
+}
+
+/// A REST client for the `Query` endpoints, with a pool of endpoints, retries with exponential backoff,
+/// and a cache of immutable responses.
+///
+/// Each request starts at the endpoint that last succeeded, and fails over to the next endpoint on error,
+/// including when the resource is not found, as an endpoint may lag behind the others. Once every endpoint has failed,
+/// the request is retried after a backoff, up to the configured number of retries. If every endpoint reports that
+/// the resource is not found, the request fails without a retry.
+///
+/// Programs are cached by program ID, as deployed programs are immutable. The latest state root, state paths,
+/// and mapping values are never cached, as each state path is computed against the latest state root,
+/// and the state paths of an execution must share the same state root.
+#[derive(Clone)]
+pub struct RestClient<N: Network> {
+    /// The base URLs of the endpoints.
+    endpoints: Arc<Vec<String>>,
+    /// The configuration.
+    config: RestClientConfig,
+    /// The index of the endpoint that last succeeded.
+    preferred: Arc<AtomicUsize>,
+    /// The blocking HTTP agent.
+    agent: ureq::Agent,
+    /// The async HTTP client.
+    #[cfg(feature = "async")]
+    client: reqwest::Client,
+    /// The cached programs.
+    programs: Arc<Mutex<Cache<ProgramID<N>, Program<N>>>>,
+}
+
+impl<N: Network> RestClient<N> {
+    /// Initializes a new client for the given endpoints, with the given configuration.
+    pub fn new<S: Into<String>>(endpoints: impl IntoIterator<Item = S>, config: RestClientConfig) -> Result<Self> {
+        // Remove any trailing slashes from the endpoints.
+        let endpoints =
+            endpoints.into_iter().map(|url| url.into().trim_end_matches('/').to_string()).collect::<Vec<_>>();
+        ensure!(!endpoints.is_empty(), "The REST client requires at least one endpoint");
+
+        Ok(Self {
+            endpoints: Arc::new(endpoints),
+            agent: ureq::AgentBuilder::new().timeout(config.timeout).build(),
+            #[cfg(feature = "async")]
+            client: reqwest::Client::builder().timeout(config.timeout).build()?,
+            programs: Arc::new(Mutex::new(Cache::new(config.cache_capacity))),
+            preferred: Default::default(),
+            config,
+        })
+    }
+
+    /// Returns the base URLs of the endpoints.
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
+    }
+
+    /// Returns the configuration.
+    pub const fn config(&self) -> &RestClientConfig {
+        &self.config
+    }
+
+    /// Returns the path of the mapping value for the given program ID, mapping name, and key.
+    fn mapping_path(program_id: &ProgramID<N>, mapping_name: &Identifier<N>, key: &Plaintext<N>) -> String {
+        format!("program/{program_id}/mapping/{mapping_name}/{}", percent_encode(&key.to_string()))
+    }
+
+    /// Returns the order in which to try the endpoints, starting at the preferred endpoint.
+    fn endpoint_order(&self) -> impl Iterator<Item = usize> {
+        let preferred = self.preferred.load(Ordering::Relaxed);
+        let num_endpoints = self.endpoints.len();
+        (0..num_endpoints).map(move |offset| (preferred + offset) % num_endpoints)
+    }
+
+    /// Performs a GET request for the given path (relative to the network prefix) on the endpoints,
+    /// and returns the deserialized response.
+    fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let prefix = N::SHORT_NAME;
+        let mut errors = Vec::new();
+        for retry in 0..=self.config.max_retries {
+            // Back off before retrying.
+            if retry > 0 {
+                std::thread::sleep(self.config.backoff(retry));
+            }
+            let mut num_not_found = 0;
+            for index in self.endpoint_order() {
+                let url = format!("{}/{prefix}/{path}", self.endpoints[index]);
+                let result = match self.agent.get(&url).call() {
+                    Ok(response) => response.into_json().map_err(|e| RequestError::Failed(e.to_string())),
+                    Err(ureq::Error::Status(404, _)) => Err(RequestError::NotFound),
+                    Err(e) => Err(RequestError::Failed(e.to_string())),
+                };
+                match result {
+                    Ok(value) => {
+                        self.preferred.store(index, Ordering::Relaxed);
+                        return Ok(value);
+                    }
+                    Err(RequestError::NotFound) => {
+                        num_not_found += 1;
+                        errors.push(format!("{url} - not found"));
+                    }
+                    Err(RequestError::Failed(error)) => errors.push(format!("{url} - {error}")),
+                }
+            }
+            // Do not retry, if every endpoint reports that the resource is not found.
+            if num_not_found == self.endpoints.len() {
+                bail!("Failed to fetch '{path}' - not found on any endpoint");
+            }
+        }
+        bail!("Failed to fetch '{path}' after {} attempts: {}", errors.len(), errors.join("; "))
+    }
+
+    /// Performs a GET request for the given path (relative to the network prefix) on the endpoints,
+    /// and returns the deserialized response.
+    #[cfg(feature = "async")]
+    async fn get_async<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let prefix = N::SHORT_NAME;
+        let mut errors = Vec::new();
+        for retry in 0..=self.config.max_retries {
+            // Back off before retrying.
+            if retry > 0 {
+                tokio::time::sleep(self.config.backoff(retry)).await;
+            }
+            let mut num_not_found = 0;
+            for index in self.endpoint_order() {
+                let url = format!("{}/{prefix}/{path}", self.endpoints[index]);
+                let result = match self.client.get(&url).send().await {
+                    Ok(response) if response.status() == 200 => {
+                        response.json().await.map_err(|e| RequestError::Failed(e.to_string()))
+                    }
+                    Ok(response) if response.status() == 404 => Err(RequestError::NotFound),
+                    Ok(response) => Err(RequestError::Failed(format!("status {}", response.status()))),
+                    Err(e) => Err(RequestError::Failed(e.to_string())),
+                };
+                match result {
+                    Ok(value) => {
+                        self.preferred.store(index, Ordering::Relaxed);
+                        return Ok(value);
+                    }
+                    Err(RequestError::NotFound) => {
+                        num_not_found += 1;
+                        errors.push(format!("{url} - not found"));
+                    }
+                    Err(RequestError::Failed(error)) => errors.push(format!("{url} - {error}")),
+                }
+            }
+            // Do not retry, if every endpoint reports that the resource is not found.
+            if num_not_found == self.endpoints.len() {
+                bail!("Failed to fetch '{path}' - not found on any endpoint");
+            }
+        }
+        bail!("Failed to fetch '{path}' after {} attempts: {}", errors.len(), errors.join("; "))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for RestClient<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        self.get("latest/stateRoot")
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.get_async("latest/stateRoot").await
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get(&format!("statePath/{commitment}"))
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_async(&format!("statePath/{commitment}")).await
+    }
+
+    /// Returns the program for the given program ID.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        if let Some(program) = self.programs.lock().get(program_id) {
+            return Ok(program);
+        }
+        let program: Program<N> = self.get(&format!("program/{program_id}"))?;
+        self.programs.lock().insert(*program_id, program.clone());
+        Ok(program)
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        if let Some(program) = self.programs.lock().get(program_id) {
+            return Ok(program);
+        }
+        let program: Program<N> = self.get_async(&format!("program/{program_id}")).await?;
+        self.programs.lock().insert(*program_id, program.clone());
+        Ok(program)
+    }
+
+    /// Returns the value for the given key in the given mapping, if it exists.
+    fn get_mapping_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.get(&Self::mapping_path(program_id, mapping_name, key))
+    }
+
+    /// Returns the value for the given key in the given mapping, if it exists.
+    #[cfg(feature = "async")]
+    async fn get_mapping_value_async(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.get_async(&Self::mapping_path(program_id, mapping_name, key)).await
+    }
+}
+
+/// Encodes the characters of the given path segment that are not unreserved in a URL.
+pub(crate) fn percent_encode(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::state_path::test_helpers::sample_global_state_path};
+
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Starts a server that answers each request with the next of the given responses (as a status code and body),
+    /// and returns its URL, along with a handle that yields the requested paths.
+    fn sample_server(responses: Vec<(u16, String)>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut paths = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                // Read the request line, and the remaining head.
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                paths.push(line.split_whitespace().nth(1).unwrap().to_string());
+                while line != "\r\n" {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                }
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            paths
+        });
+        (url, handle)
+    }
+
+    /// Returns a configuration with short backoffs.
+    fn sample_config() -> RestClientConfig {
+        RestClientConfig {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(10),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_retry_and_cache() {
+        let state_root = <CurrentNetwork as Network>::StateRoot::default();
+        let program = Program::<CurrentNetwork>::credits().unwrap();
+
+        // Answer with a transient failure, then the state root, then the program.
+        let (url, handle) = sample_server(vec![
+            (500, "\"error\"".to_string()),
+            (200, serde_json::to_string(&state_root).unwrap()),
+            (200, serde_json::to_string(&program).unwrap()),
+        ]);
+        let client = RestClient::<CurrentNetwork>::new([url], sample_config()).unwrap();
+
+        // Ensure the failed request is retried.
+        assert_eq!(client.current_state_root().unwrap(), state_root);
+        // Ensure the program is fetched once, and then cached.
+        assert_eq!(client.get_program(program.id()).unwrap(), program);
+        assert_eq!(client.get_program(program.id()).unwrap(), program);
+
+        let paths = handle.join().unwrap();
+        assert_eq!(paths, ["/mainnet/latest/stateRoot", "/mainnet/latest/stateRoot", "/mainnet/program/credits.aleo"]);
+    }
+
+    #[test]
+    fn test_state_paths_follow_the_latest_state_root() {
+        let rng = &mut TestRng::default();
+        let commitment = Field::rand(rng);
+
+        // Sample the state paths of the commitment, before and after the chain advances.
+        let state_path = sample_global_state_path::<CurrentNetwork>(Some(commitment), rng).unwrap();
+        let next_state_path = sample_global_state_path::<CurrentNetwork>(Some(commitment), rng).unwrap();
+        assert_ne!(state_path.global_state_root(), next_state_path.global_state_root());
+
+        // Answer each execution with the latest state root, and the state path against it.
+        let (url, handle) = sample_server(vec![
+            (200, serde_json::to_string(&state_path.global_state_root()).unwrap()),
+            (200, serde_json::to_string(&state_path).unwrap()),
+            (200, serde_json::to_string(&next_state_path.global_state_root()).unwrap()),
+            (200, serde_json::to_string(&next_state_path).unwrap()),
+        ]);
+        let client = RestClient::<CurrentNetwork>::new([url], sample_config()).unwrap();
+
+        // Ensure the state path of the first execution is against the latest state root.
+        assert_eq!(client.current_state_root().unwrap(), state_path.global_state_root());
+        assert_eq!(client.get_state_path_for_commitment(&commitment).unwrap(), state_path);
+        // Ensure the state path of the second execution, after the chain advanced, is against the new state root,
+        // rather than a stale state path for the same commitment.
+        assert_eq!(client.current_state_root().unwrap(), next_state_path.global_state_root());
+        assert_eq!(client.get_state_path_for_commitment(&commitment).unwrap(), next_state_path);
+
+        assert_eq!(handle.join().unwrap(), [
+            "/mainnet/latest/stateRoot".to_string(),
+            format!("/mainnet/statePath/{commitment}"),
+            "/mainnet/latest/stateRoot".to_string(),
+            format!("/mainnet/statePath/{commitment}"),
+        ]);
+    }
+
+    #[test]
+    fn test_failover_and_not_found() {
+        let state_root = <CurrentNetwork as Network>::StateRoot::default();
+
+        let program = Program::<CurrentNetwork>::credits().unwrap();
+
+        // Start an endpoint that fails at first, and an endpoint that answers, but lacks the program.
+        let (failing_url, failing_handle) = sample_server(vec![
+            (503, "\"error\"".to_string()),
+            (200, serde_json::to_string(&program).unwrap()),
+            (404, "\"error\"".to_string()),
+        ]);
+        let (url, handle) = sample_server(vec![
+            (200, serde_json::to_string(&state_root).unwrap()),
+            (200, "null".to_string()),
+            (404, "\"error\"".to_string()),
+            (404, "\"error\"".to_string()),
+        ]);
+        let client = RestClient::<CurrentNetwork>::new([failing_url, url], sample_config()).unwrap();
+
+        // Ensure the request fails over to the next endpoint, which is then preferred.
+        assert_eq!(client.current_state_root().unwrap(), state_root);
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("{ a: 1u8 }").unwrap();
+        assert_eq!(client.get_mapping_value(&program_id, &mapping_name, &key).unwrap(), None);
+        // Ensure a resource that is not found fails over to the next endpoint.
+        assert_eq!(client.get_program(&program_id).unwrap(), program);
+        // Ensure a resource that no endpoint has is not retried.
+        assert!(client.get_program(&ProgramID::from_str("missing.aleo").unwrap()).is_err());
+
+        assert_eq!(failing_handle.join().unwrap(), [
+            "/mainnet/latest/stateRoot",
+            "/mainnet/program/credits.aleo",
+            "/mainnet/program/missing.aleo"
+        ]);
+        assert_eq!(handle.join().unwrap(), [
+            "/mainnet/latest/stateRoot",
+            "/mainnet/program/credits.aleo/mapping/account/%7B%0A%20%20a%3A%201u8%0A%7D",
+            "/mainnet/program/credits.aleo",
+            "/mainnet/program/missing.aleo"
+        ]);
+    }
+
+    #[test]
+    fn test_exhausted_retries() {
+        // Start an endpoint that always fails.
+        let config = RestClientConfig { max_retries: 2, ..sample_config() };
+        let (url, handle) = sample_server(vec![(500, "\"error\"".to_string()); 3]);
+        let client = RestClient::<CurrentNetwork>::new([url], config).unwrap();
+
+        // Ensure the request fails after the retries are exhausted.
+        assert!(client.current_state_root().is_err());
+        assert_eq!(handle.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_cache() {
+        let mut cache = Cache::new(2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+
+        // Ensure the least recently used entry is evicted.
+        assert_eq!(cache.get(&1), Some("a"));
+        cache.insert(3, "c");
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some("a"));
+        assert_eq!(cache.get(&3), Some("c"));
+
+        // Ensure an updated entry becomes the most recently used.
+        cache.insert(1, "d");
+        cache.insert(4, "e");
+        assert_eq!(cache.get(&3), None);
+        assert_eq!(cache.get(&1), Some("d"));
+
+        // Ensure a cache without capacity holds no entries.
+        let mut cache = Cache::new(0);
+        cache.insert(1, "a");
+        assert_eq!(cache.get(&1), None);
+    }
+
+    #[test]
+    fn test_backoff() {
+        let config = RestClientConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(350),
+            ..Default::default()
+        };
+        assert_eq!(config.backoff(1), Duration::from_millis(100));
+        assert_eq!(config.backoff(2), Duration::from_millis(200));
+        assert_eq!(config.backoff(3), Duration::from_millis(350));
+        assert_eq!(config.backoff(100), Duration::from_millis(350));
+    }
+}
@@ -18,6 +18,11 @@
 #[cfg_attr(feature = "async", macro_use)]
 extern crate async_trait;
 
+#[cfg(feature = "query")]
+mod client;
+#[cfg(feature = "query")]
+pub use client::*;
+
 #[cfg(feature = "query")]
 mod query;
 #[cfg(feature = "query")]
@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::QueryTrait;
+use crate::{QueryTrait, RestClient};
 use console::{
     network::prelude::*,
-    program::{ProgramID, StatePath},
+    program::{Identifier, Plaintext, ProgramID, StatePath, Value},
     types::Field,
 };
 use ledger_store::{BlockStorage, BlockStore};
@@ -27,6 +27,8 @@ pub enum Query<N: Network, B: BlockStorage<N>> {
     VM(BlockStore<N, B>),
     /// The base URL of the node.
     REST(String),
+    /// The REST client, with retries and caching.
+    Client(RestClient<N>),
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
@@ -41,6 +43,12 @@ impl<N: Network, B: BlockStorage<N>> From<&BlockStore<N, B>> for Query<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> From<RestClient<N>> for Query<N, B> {
+    fn from(client: RestClient<N>) -> Self {
+        Self::Client(client)
+    }
+}
+
 impl<N: Network, B: BlockStorage<N>> From<String> for Query<N, B> {
     fn from(url: String) -> Self {
         Self::REST(url)
@@ -65,12 +73,11 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     fn current_state_root(&self) -> Result<N::StateRoot> {
         match self {
             Self::VM(block_store) => Ok(block_store.current_state_root()),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request(&format!("{url}/mainnet/latest/stateRoot"))?.into_json()?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                Ok(Self::get_request(&format!("{url}/{network}/latest/stateRoot"))?.into_json()?)
+            }
+            Self::Client(client) => client.current_state_root(),
         }
     }
 
@@ -79,12 +86,11 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     async fn current_state_root_async(&self) -> Result<N::StateRoot> {
         match self {
             Self::VM(block_store) => Ok(block_store.current_state_root()),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request_async(&format!("{url}/mainnet/latest/stateRoot")).await?.json().await?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                Ok(Self::get_request_async(&format!("{url}/{network}/latest/stateRoot")).await?.json().await?)
+            }
+            Self::Client(client) => client.current_state_root_async().await,
         }
     }
 
@@ -92,12 +98,11 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
             Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request(&format!("{url}/mainnet/statePath/{commitment}"))?.into_json()?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                Ok(Self::get_request(&format!("{url}/{network}/statePath/{commitment}"))?.into_json()?)
+            }
+            Self::Client(client) => client.get_state_path_for_commitment(commitment),
         }
     }
 
@@ -106,48 +111,88 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
             Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request_async(&format!("{url}/mainnet/statePath/{commitment}")).await?.json().await?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                Ok(Self::get_request_async(&format!("{url}/{network}/statePath/{commitment}")).await?.json().await?)
+            }
+            Self::Client(client) => client.get_state_path_for_commitment_async(commitment).await,
         }
     }
-}
 
-impl<N: Network, B: BlockStorage<N>> Query<N, B> {
     /// Returns the program for the given program ID.
-    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
         match self {
             Self::VM(block_store) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request(&format!("{url}/mainnet/program/{program_id}"))?.into_json()?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                Ok(Self::get_request(&format!("{url}/{network}/program/{program_id}"))?.into_json()?)
+            }
+            Self::Client(client) => client.get_program(program_id),
         }
     }
 
     /// Returns the program for the given program ID.
     #[cfg(feature = "async")]
-    pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
         match self {
             Self::VM(block_store) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
-            Self::REST(url) => match N::ID {
-                console::network::MainnetV0::ID => {
-                    Ok(Self::get_request_async(&format!("{url}/mainnet/program/{program_id}")).await?.json().await?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                Ok(Self::get_request_async(&format!("{url}/{network}/program/{program_id}")).await?.json().await?)
+            }
+            Self::Client(client) => client.get_program_async(program_id).await,
+        }
+    }
+
+    /// Returns the value for the given key in the given mapping, if it exists.
+    fn get_mapping_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        match self {
+            Self::VM(..) => bail!("Mapping values are not available from the block store"),
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                let key = crate::client::percent_encode(&key.to_string());
+                Ok(Self::get_request(&format!("{url}/{network}/program/{program_id}/mapping/{mapping_name}/{key}"))?
+                    .into_json()?)
+            }
+            Self::Client(client) => client.get_mapping_value(program_id, mapping_name, key),
+        }
+    }
+
+    /// Returns the value for the given key in the given mapping, if it exists.
+    #[cfg(feature = "async")]
+    async fn get_mapping_value_async(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        match self {
+            Self::VM(..) => bail!("Mapping values are not available from the block store"),
+            Self::REST(url) => {
+                let network = N::SHORT_NAME;
+                let key = crate::client::percent_encode(&key.to_string());
+                Ok(Self::get_request_async(&format!(
+                    "{url}/{network}/program/{program_id}/mapping/{mapping_name}/{key}"
+                ))
+                .await?
+                .json()
+                .await?)
+            }
+            Self::Client(client) => client.get_mapping_value_async(program_id, mapping_name, key).await,
         }
     }
+}
 
+impl<N: Network, B: BlockStorage<N>> Query<N, B> {
     /// Performs a GET request to the given URL.
     fn get_request(url: &str) -> Result<ureq::Response> {
         let response = ureq::get(url).call()?;
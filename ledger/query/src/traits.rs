@@ -12,7 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use console::{network::Network, prelude::Result, program::StatePath, types::Field};
+use console::{
+    network::Network,
+    prelude::{bail, Result},
+    program::{Identifier, Plaintext, ProgramID, StatePath, Value},
+    types::Field,
+};
+use synthesizer_program::Program;
 
 #[cfg_attr(feature = "async", async_trait(?Send))]
 pub trait QueryTrait<N: Network> {
@@ -29,4 +35,44 @@ pub trait QueryTrait<N: Network> {
     /// Returns a state path for the given `commitment`.
     #[cfg(feature = "async")]
     async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>>;
+
+    /// Returns the program for the given program ID.
+    ///
+    /// By default, the query does not support fetching programs.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        bail!("The query does not support fetching the program '{program_id}'")
+    }
+
+    /// Returns the program for the given program ID.
+    ///
+    /// By default, the query does not support fetching programs.
+    #[cfg(feature = "async")]
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        bail!("The query does not support fetching the program '{program_id}'")
+    }
+
+    /// Returns the value for the given key in the given mapping, if it exists.
+    ///
+    /// By default, the query does not support fetching mapping values.
+    fn get_mapping_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        _key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        bail!("The query does not support fetching the values of the mapping '{program_id}/{mapping_name}'")
+    }
+
+    /// Returns the value for the given key in the given mapping, if it exists.
+    ///
+    /// By default, the query does not support fetching mapping values.
+    #[cfg(feature = "async")]
+    async fn get_mapping_value_async(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        _key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        bail!("The query does not support fetching the values of the mapping '{program_id}/{mapping_name}'")
+    }
 }
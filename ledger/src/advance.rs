@@ -108,6 +108,40 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
         }
 
+        // Send the status changes of the subscribed transactions.
+        self.notify_transaction_statuses(block);
+        // Send the events of the block.
+        self.notify_block_events(block);
+
+        Ok(())
+    }
+
+    /// Reverts the ledger to its state after the block at the given height, removing all later blocks.
+    /// See `VM::rollback_to` for more details.
+    pub fn rollback_to(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Retrieve the hashes of the blocks to be removed.
+        let block_hashes = (height.saturating_add(1)..=current_block.height())
+            .map(|block_height| self.get_hash(block_height))
+            .collect::<Result<Vec<_>>>()?;
+        // Update the VM.
+        self.vm.rollback_to(height)?;
+        // Update the current block.
+        *current_block = self.get_block(height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Update the cached committee from storage.
+        *self.current_committee.write() = self.vm.finalize_store().committee_store().current_committee().ok();
+        // Update the current epoch challenge.
+        self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(height).ok());
+
+        // Send the status changes of the subscribed transactions that were removed.
+        self.notify_transaction_rollbacks(height);
+        // Send the rollback event.
+        self.notify_rollback_events(height, block_hashes);
+
         Ok(())
     }
 }
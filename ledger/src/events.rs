@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::DecodedFinalizeOperation;
+use std::sync::mpsc::{channel, Receiver};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Subscribes to the events of the ledger, and returns the receiver of the events.
+    ///
+    /// The events of each block are sent once the block is added to the ledger,
+    /// and a `Rollback` event is sent once blocks are removed from the ledger.
+    /// The subscription ends when the receiver is dropped.
+    pub fn subscribe_events(&self) -> Receiver<LedgerEvent<N>> {
+        let (sender, receiver) = channel();
+        self.event_subscriptions.lock().push(sender);
+        receiver
+    }
+
+    /// Returns the events of the block at the given height, as they were sent when the block was added.
+    pub fn get_block_events(&self, height: u32) -> Result<Vec<LedgerEvent<N>>> {
+        self.block_events(&self.get_block(height)?)
+    }
+
+    /// Returns the events of the given block, which must be in the ledger.
+    fn block_events(&self, block: &Block<N>) -> Result<Vec<LedgerEvent<N>>> {
+        let height = block.height();
+        let mut events = vec![LedgerEvent::NewBlock(Box::new(block.clone()))];
+
+        // Decode the finalize operations of the block, at once.
+        let operations: Vec<_> =
+            block.transactions().iter().flat_map(|confirmed| confirmed.finalize_operations().iter().copied()).collect();
+        let mut decoded_operations =
+            self.vm.finalize_store().decode_finalize_operations(height, &operations)?.into_iter();
+
+        // Add the events of the confirmed transactions.
+        for confirmed in block.transactions().iter() {
+            let transaction_id = confirmed.to_unconfirmed_transaction_id()?;
+            events.push(LedgerEvent::Transaction {
+                transaction_id,
+                status: TransactionStatus::from_confirmed(height, confirmed)?,
+            });
+            // Add the event of the deployment.
+            if let ConfirmedTransaction::AcceptedDeploy(_, transaction, _) = confirmed {
+                if let Some(deployment) = transaction.deployment() {
+                    let program_id = *deployment.program_id();
+                    events.push(LedgerEvent::Deployment { height, transaction_id, program_id });
+                }
+            }
+            // Add the events of the updated mappings.
+            let operations = decoded_operations.by_ref().take(confirmed.finalize_operations().len());
+            events.extend(mapping_update_events(height, Some(transaction_id), operations));
+        }
+        // Add the events of the mappings updated by the ratifications.
+        self.vm.finalize_store().ensure_ratified_operations_available(height)?;
+        let Some(operations) = self.vm.finalize_store().get_ratified_operations(height)? else {
+            bail!("Missing the ratified finalize operations of block {height}")
+        };
+        let operations = self.vm.finalize_store().decode_finalize_operations(height, &operations)?;
+        events.extend(mapping_update_events(height, None, operations));
+        // Add the events of the aborted transactions.
+        for transaction_id in block.aborted_transaction_ids() {
+            events.push(LedgerEvent::Transaction {
+                transaction_id: *transaction_id,
+                status: TransactionStatus::Aborted { height },
+            });
+        }
+
+        // Add the event of the committee change, if the members or their stakes changed.
+        if let Some(committee) = self.get_committee(height)? {
+            let previous_committee = match committee.starting_round() {
+                0 => None,
+                starting_round => self.get_committee_for_round(starting_round - 1)?,
+            };
+            if previous_committee.map_or(true, |previous| previous.members() != committee.members()) {
+                events.push(LedgerEvent::CommitteeChange { height, committee });
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Sends the given events to the subscribers, and removes the subscribers whose receiver has been dropped.
+    fn send_events(&self, events: Vec<LedgerEvent<N>>) {
+        self.event_subscriptions.lock().retain(|sender| events.iter().all(|event| sender.send(event.clone()).is_ok()));
+    }
+
+    /// Sends the events of the given block to the subscribers.
+    pub(crate) fn notify_block_events(&self, block: &Block<N>) {
+        // If there are no subscribers, skip computing the events.
+        if self.event_subscriptions.lock().is_empty() {
+            return;
+        }
+        match self.block_events(block) {
+            Ok(events) => self.send_events(events),
+            Err(error) => warn!("Failed to compute the events of block {}: {error}", block.height()),
+        }
+    }
+
+    /// Sends the rollback event for the given height and removed block hashes to the subscribers.
+    pub(crate) fn notify_rollback_events(&self, height: u32, block_hashes: Vec<N::BlockHash>) {
+        self.send_events(vec![LedgerEvent::Rollback { height, block_hashes }]);
+    }
+}
+
+/// Returns the `MappingUpdate` events of the given decoded finalize operations, grouping the operations by mapping.
+fn mapping_update_events<N: Network>(
+    height: u32,
+    transaction_id: Option<N::TransactionID>,
+    operations: impl IntoIterator<Item = DecodedFinalizeOperation<N>>,
+) -> Vec<LedgerEvent<N>> {
+    let mut mappings: IndexMap<_, Vec<_>> = IndexMap::new();
+    for decoded in operations {
+        mappings.entry((decoded.program_id, decoded.mapping_name)).or_default().push(decoded);
+    }
+    mappings
+        .into_iter()
+        .map(|((program_id, mapping_name), operations)| LedgerEvent::MappingUpdate {
+            height,
+            transaction_id,
+            program_id,
+            mapping_name,
+            operations,
+        })
+        .collect()
+}
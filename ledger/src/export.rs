@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{fs, path::Path};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Exports the blocks in the given range of heights into the given (new) directory, and returns the manifest.
+    ///
+    /// The blocks, transactions, transitions, and finalize operations are each written to an NDJSON table,
+    /// with one flat row per line and a fixed set of columns (see `EXPORT_VERSION`), so that the export
+    /// can be loaded directly into columnar analytics tools. The blocks are streamed from storage, one at a time.
+    pub fn export_blocks<P: AsRef<Path>>(&self, heights: Range<u32>, directory: P) -> Result<ExportManifest> {
+        let timer = timer!("Ledger::export_blocks");
+
+        // Ensure the range of heights is valid.
+        ensure!(!heights.is_empty(), "The range of heights to export ({heights:?}) is empty");
+        ensure!(
+            heights.end <= self.latest_height().saturating_add(1),
+            "The range of heights to export ({heights:?}) exceeds the latest height ({})",
+            self.latest_height()
+        );
+
+        let directory = directory.as_ref();
+        // Ensure the directory does not exist.
+        ensure!(!directory.exists(), "The export directory '{}' already exists", directory.display());
+        fs::create_dir_all(directory)?;
+
+        // Write the tables.
+        let mut exporter = BlockExporter::create(directory)?;
+        for block in self.blocks(heights.clone()) {
+            let block = block?;
+            // Decode the finalize operations of the transactions, followed by the ratified finalize operations.
+            self.vm.finalize_store().ensure_ratified_operations_available(block.height())?;
+            let Some(ratified_operations) = self.vm.finalize_store().get_ratified_operations(block.height())? else {
+                bail!("Missing the ratified finalize operations of block {}", block.height())
+            };
+            let operations: Vec<_> =
+                block.transactions().finalize_operations().copied().chain(ratified_operations).collect();
+            let decoded_operations =
+                self.vm.finalize_store().decode_finalize_operations(block.height(), &operations)?;
+            exporter.write_block(&block, &decoded_operations)?;
+        }
+        let manifest = exporter.finish(N::ID, heights.start, heights.end)?;
+        lap!(timer, "Write the tables");
+
+        // Write the manifest.
+        manifest.write(directory)?;
+        info!("Exported blocks {} to {} into '{}'", heights.start, heights.end - 1, directory.display());
+
+        finish!(timer);
+        Ok(manifest)
+    }
+}
@@ -35,6 +35,21 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
     }
 
+    /// Returns the mapping operation path for the given `program ID`, `mapping name`, and `key`, at block `height`.
+    /// The path proves the inclusion of the operation that wrote the value of the key as of the block, in the block.
+    pub fn get_mapping_operation_path(
+        &self,
+        height: u32,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingOperationPath<N>> {
+        // Retrieve the block transactions.
+        let transactions = self.get_transactions(height)?;
+        // Compute the mapping operation path.
+        self.vm.finalize_store().get_mapping_operation_path(height, &transactions, program_id, mapping_name, key)
+    }
+
     /// Returns the epoch challenge for the given block height.
     pub fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>> {
         // Compute the epoch number from the current block height.
@@ -241,6 +256,29 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>> {
         self.vm.block_store().get_batch_certificate(certificate_id)
     }
+
+    /// Returns the `(transition ID, block height)` pairs of the transitions that reference the given address
+    /// in a public input, public output, or future, in order of block height.
+    #[cfg(feature = "address-index")]
+    pub fn get_transitions_for_address(&self, address: &Address<N>) -> Result<Vec<(N::TransitionID, u32)>> {
+        self.vm.block_store().address_store().get_transitions(address)
+    }
+
+    /// Returns the `(transaction ID, block height)` pairs of the transactions that wrote to
+    /// the `credits.aleo/account` mapping of the given address, in order of block height.
+    /// Note: Only the transactions that reference the address in a public input, public output, or future
+    /// are returned, and the writes of the ratifications (e.g. the block rewards) are not returned.
+    #[cfg(feature = "address-index")]
+    pub fn get_account_transactions_for_address(&self, address: &Address<N>) -> Result<Vec<(N::TransactionID, u32)>> {
+        self.vm.block_store().address_store().get_account_transactions(address)
+    }
+
+    /// Returns the `(program ID, block height)` pairs of the programs deployed by the given owner,
+    /// in order of block height.
+    #[cfg(feature = "address-index")]
+    pub fn get_programs_for_owner(&self, owner: &Address<N>) -> Result<Vec<(ProgramID<N>, u32)>> {
+        self.vm.block_store().address_store().get_programs(owner)
+    }
 }
 
 #[cfg(test)]
@@ -248,6 +286,8 @@ mod tests {
     use super::*;
     use crate::test_helpers::CurrentLedger;
     use console::network::MainnetV0;
+    #[cfg(feature = "address-index")]
+    use console::program::Argument;
 
     type CurrentNetwork = MainnetV0;
 
@@ -263,4 +303,36 @@ mod tests {
         // Ensure the genesis block matches.
         assert_eq!(genesis, candidate);
     }
+
+    #[cfg(feature = "address-index")]
+    #[test]
+    fn test_get_address_index() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+
+        // Ensure each transition is indexed for the addresses in its futures,
+        // and that the account writes of the genesis block are indexed for those addresses.
+        let mut account_transactions = Vec::new();
+        for transition in genesis.transitions() {
+            for output in transition.outputs() {
+                if let ledger_block::Output::Future(_, Some(future)) = output {
+                    for argument in future.arguments() {
+                        if let Argument::Plaintext(Plaintext::Literal(Literal::Address(address), _)) = argument {
+                            let transitions = ledger.get_transitions_for_address(address).unwrap();
+                            assert!(transitions.contains(&(*transition.id(), 0)));
+                            account_transactions.extend(ledger.get_account_transactions_for_address(address).unwrap());
+                        }
+                    }
+                }
+            }
+        }
+        assert!(!account_transactions.is_empty(), "This test must be run with at least one account write.");
+        for (transaction_id, height) in account_transactions {
+            assert_eq!(height, 0);
+            assert!(genesis.transaction_ids().any(|id| *id == transaction_id));
+        }
+    }
 }
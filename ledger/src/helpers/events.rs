@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::TransactionStatus;
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use ledger_block::Block;
+use ledger_committee::Committee;
+use ledger_store::DecodedFinalizeOperation;
+
+/// An event emitted by the ledger, as blocks are added or removed.
+///
+/// For each added block, the events are emitted in the following order:
+/// the `NewBlock` event, then for each transaction in the block, its `Transaction` event,
+/// followed by its `Deployment` and `MappingUpdate` events, then the `MappingUpdate` events of the ratifications,
+/// the `Transaction` events of the aborted transactions, and finally the `CommitteeChange` event, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LedgerEvent<N: Network> {
+    /// The block was added to the ledger.
+    NewBlock(Box<Block<N>>),
+    /// The transaction was accepted, rejected, or aborted in the block.
+    Transaction { transaction_id: N::TransactionID, status: TransactionStatus<N> },
+    /// The program was deployed by the transaction, in the block at the given height.
+    Deployment { height: u32, transaction_id: N::TransactionID, program_id: ProgramID<N> },
+    /// The mapping was updated by the finalize operations of the transaction, in the block at the given height.
+    /// The transaction ID is `None` if the mapping was updated by the ratifications of the block.
+    MappingUpdate {
+        height: u32,
+        transaction_id: Option<N::TransactionID>,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        operations: Vec<DecodedFinalizeOperation<N>>,
+    },
+    /// The committee changed in the block at the given height.
+    CommitteeChange { height: u32, committee: Committee<N> },
+    /// The blocks above the given height were removed from the ledger, with the given hashes in ascending order.
+    Rollback { height: u32, block_hashes: Vec<N::BlockHash> },
+}
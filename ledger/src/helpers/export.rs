@@ -0,0 +1,408 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::{Block, ConfirmedTransaction, Transition};
+use ledger_store::DecodedFinalizeOperation;
+use synthesizer::program::FinalizeOperation;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader, BufWriter, Write},
+    marker::PhantomData,
+    path::Path,
+};
+
+/// The version of the export format, which is bumped whenever a column is added, removed, or changed.
+pub const EXPORT_VERSION: u8 = 1;
+
+/// The file name of the export manifest, within an export directory.
+pub const EXPORT_MANIFEST_FILE: &str = "manifest.json";
+/// The file name of the blocks table, within an export directory.
+pub const EXPORT_BLOCKS_FILE: &str = "blocks.ndjson";
+/// The file name of the transactions table, within an export directory.
+pub const EXPORT_TRANSACTIONS_FILE: &str = "transactions.ndjson";
+/// The file name of the transitions table, within an export directory.
+pub const EXPORT_TRANSITIONS_FILE: &str = "transitions.ndjson";
+/// The file name of the finalize operations table, within an export directory.
+pub const EXPORT_FINALIZE_OPERATIONS_FILE: &str = "finalize_operations.ndjson";
+
+/// A row of the blocks table.
+///
+/// Note: 128-bit integers are exported as decimal strings, as most JSON readers cannot represent them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockRow {
+    pub height: u32,
+    pub block_hash: String,
+    pub previous_hash: String,
+    pub previous_state_root: String,
+    pub transactions_root: String,
+    pub finalize_root: String,
+    pub ratifications_root: String,
+    pub solutions_root: String,
+    pub subdag_root: String,
+    pub network: u16,
+    pub round: u64,
+    pub cumulative_weight: String,
+    pub cumulative_proof_target: String,
+    pub coinbase_target: u64,
+    pub proof_target: u64,
+    pub last_coinbase_target: u64,
+    pub last_coinbase_timestamp: i64,
+    pub timestamp: i64,
+    pub authority: String,
+    pub num_ratifications: u64,
+    pub num_solutions: u64,
+    pub num_aborted_solutions: u64,
+    pub num_transactions: u64,
+    pub num_accepted_transactions: u64,
+    pub num_rejected_transactions: u64,
+    pub num_aborted_transactions: u64,
+}
+
+/// A row of the transactions table, for each confirmed transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionRow {
+    pub height: u32,
+    pub block_hash: String,
+    pub index: u32,
+    pub transaction_id: String,
+    pub unconfirmed_transaction_id: String,
+    pub status: String,
+    pub kind: String,
+    pub deployed_program_id: Option<String>,
+    pub fee: u64,
+    pub base_fee: u64,
+    pub priority_fee: u64,
+    pub num_transitions: u64,
+    pub num_finalize_operations: u64,
+}
+
+/// A row of the transitions table, for each transition of a confirmed transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionRow {
+    pub height: u32,
+    pub transaction_id: String,
+    pub index: u32,
+    pub transition_id: String,
+    pub program_id: String,
+    pub function_name: String,
+    pub num_inputs: u64,
+    pub num_outputs: u64,
+    pub tpk: String,
+    pub tcm: String,
+}
+
+/// A row of the finalize operations table, for each finalize operation of a confirmed transaction,
+/// and for each ratified finalize operation of a block (without a transaction ID).
+///
+/// Note: The value is only decoded for the last update of a key within a block (see `DecodedFinalizeOperation`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalizeOperationRow {
+    pub height: u32,
+    pub transaction_id: Option<String>,
+    pub index: u32,
+    pub operation: String,
+    pub program_id: String,
+    pub mapping: String,
+    pub key: Option<String>,
+    pub value: Option<String>,
+    pub mapping_id: String,
+    pub key_id: Option<String>,
+    pub value_id: Option<String>,
+}
+
+impl BlockRow {
+    /// Returns the row for the given block.
+    pub fn new<N: Network>(block: &Block<N>) -> Self {
+        Self {
+            height: block.height(),
+            block_hash: block.hash().to_string(),
+            previous_hash: block.previous_hash().to_string(),
+            previous_state_root: block.previous_state_root().to_string(),
+            transactions_root: block.transactions_root().to_string(),
+            finalize_root: block.finalize_root().to_string(),
+            ratifications_root: block.ratifications_root().to_string(),
+            solutions_root: block.solutions_root().to_string(),
+            subdag_root: block.header().subdag_root().to_string(),
+            network: block.network(),
+            round: block.round(),
+            cumulative_weight: block.cumulative_weight().to_string(),
+            cumulative_proof_target: block.cumulative_proof_target().to_string(),
+            coinbase_target: block.coinbase_target(),
+            proof_target: block.proof_target(),
+            last_coinbase_target: block.last_coinbase_target(),
+            last_coinbase_timestamp: block.last_coinbase_timestamp(),
+            timestamp: block.timestamp(),
+            authority: if block.authority().is_beacon() { "beacon" } else { "quorum" }.to_string(),
+            num_ratifications: block.ratifications().len() as u64,
+            num_solutions: block.puzzle_commitments().map_or(0, |commitments| commitments.count() as u64),
+            num_aborted_solutions: block.aborted_solution_ids().len() as u64,
+            num_transactions: block.transactions().len() as u64,
+            num_accepted_transactions: block.transactions().num_accepted() as u64,
+            num_rejected_transactions: block.transactions().num_rejected() as u64,
+            num_aborted_transactions: block.aborted_transaction_ids().len() as u64,
+        }
+    }
+}
+
+impl TransactionRow {
+    /// Returns the row for the given confirmed transaction, in the block with the given height and hash.
+    pub fn new<N: Network>(height: u32, block_hash: N::BlockHash, confirmed: &ConfirmedTransaction<N>) -> Result<Self> {
+        let transaction = confirmed.transaction();
+        // Determine the kind of transaction, prior to confirmation.
+        let kind = match confirmed {
+            ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::RejectedDeploy(..) => "deploy",
+            ConfirmedTransaction::AcceptedExecute(..) | ConfirmedTransaction::RejectedExecute(..) => "execute",
+        };
+        Ok(Self {
+            height,
+            block_hash: block_hash.to_string(),
+            index: confirmed.index(),
+            transaction_id: transaction.id().to_string(),
+            unconfirmed_transaction_id: confirmed.to_unconfirmed_transaction_id()?.to_string(),
+            status: if confirmed.is_accepted() { "accepted" } else { "rejected" }.to_string(),
+            kind: kind.to_string(),
+            deployed_program_id: transaction.deployment().map(|deployment| deployment.program_id().to_string()),
+            fee: *transaction.fee_amount()?,
+            base_fee: *transaction.base_fee_amount()?,
+            priority_fee: *transaction.priority_fee_amount()?,
+            num_transitions: transaction.transitions().count() as u64,
+            num_finalize_operations: confirmed.finalize_operations().len() as u64,
+        })
+    }
+}
+
+impl TransitionRow {
+    /// Returns the row for the transition at the given index, in the transaction with the given ID.
+    pub fn new<N: Network>(
+        height: u32,
+        transaction_id: N::TransactionID,
+        index: u32,
+        transition: &Transition<N>,
+    ) -> Self {
+        Self {
+            height,
+            transaction_id: transaction_id.to_string(),
+            index,
+            transition_id: transition.id().to_string(),
+            program_id: transition.program_id().to_string(),
+            function_name: transition.function_name().to_string(),
+            num_inputs: transition.inputs().len() as u64,
+            num_outputs: transition.outputs().len() as u64,
+            tpk: transition.tpk().to_string(),
+            tcm: transition.tcm().to_string(),
+        }
+    }
+}
+
+impl FinalizeOperationRow {
+    /// Returns the row for the decoded finalize operation at the given index, in the transaction with the given ID,
+    /// or among the ratified finalize operations of the block if `transaction_id` is `None`.
+    pub fn new<N: Network>(
+        height: u32,
+        transaction_id: Option<N::TransactionID>,
+        index: u32,
+        decoded: &DecodedFinalizeOperation<N>,
+    ) -> Self {
+        let (name, mapping_id, key_id, value_id) = match &decoded.operation {
+            FinalizeOperation::InitializeMapping(mapping_id) => ("initialize_mapping", mapping_id, None, None),
+            FinalizeOperation::InsertKeyValue(mapping_id, key_id, value_id) => {
+                ("insert_key_value", mapping_id, Some(key_id), Some(value_id))
+            }
+            FinalizeOperation::UpdateKeyValue(mapping_id, key_id, value_id) => {
+                ("update_key_value", mapping_id, Some(key_id), Some(value_id))
+            }
+            FinalizeOperation::RemoveKeyValue(mapping_id, key_id) => {
+                ("remove_key_value", mapping_id, Some(key_id), None)
+            }
+            FinalizeOperation::ReplaceMapping(mapping_id) => ("replace_mapping", mapping_id, None, None),
+            FinalizeOperation::RemoveMapping(mapping_id) => ("remove_mapping", mapping_id, None, None),
+        };
+        Self {
+            height,
+            transaction_id: transaction_id.map(|transaction_id| transaction_id.to_string()),
+            index,
+            operation: name.to_string(),
+            program_id: decoded.program_id.to_string(),
+            mapping: decoded.mapping_name.to_string(),
+            key: decoded.key.as_ref().map(ToString::to_string),
+            value: decoded.value.as_ref().map(ToString::to_string),
+            mapping_id: mapping_id.to_string(),
+            key_id: key_id.map(ToString::to_string),
+            value_id: value_id.map(ToString::to_string),
+        }
+    }
+}
+
+/// A writer for a table of the export, which writes one JSON object per line.
+pub struct NdjsonWriter<T: Serialize> {
+    /// The buffered file writer.
+    writer: BufWriter<File>,
+    /// The number of rows written.
+    num_rows: u64,
+    /// PhantomData.
+    _row: PhantomData<T>,
+}
+
+impl<T: Serialize> NdjsonWriter<T> {
+    /// Creates a new (empty) table at the given path.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self { writer: BufWriter::new(File::create(path)?), num_rows: 0, _row: PhantomData })
+    }
+
+    /// Returns the number of rows written.
+    pub const fn num_rows(&self) -> u64 {
+        self.num_rows
+    }
+
+    /// Appends the given row to the table.
+    pub fn write(&mut self, row: &T) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, row)?;
+        self.writer.write_all(b"\n")?;
+        self.num_rows += 1;
+        Ok(())
+    }
+
+    /// Flushes the table to disk, and returns the number of rows written.
+    pub fn finish(mut self) -> Result<u64> {
+        self.writer.flush()?;
+        self.writer.get_ref().sync_all()?;
+        Ok(self.num_rows)
+    }
+}
+
+/// Reads the rows of the table at the given path.
+pub fn read_ndjson<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> Result<Vec<T>> {
+    BufReader::new(File::open(path)?).lines().map(|line| Ok(serde_json::from_str(&line?)?)).collect()
+}
+
+/// The manifest of an export, which describes the exported range of blocks and the size of each table.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportManifest {
+    /// The version of the export format.
+    pub version: u8,
+    /// The network ID.
+    pub network_id: u16,
+    /// The first exported block height.
+    pub start_height: u32,
+    /// The block height after the last exported block.
+    pub end_height: u32,
+    /// The number of rows in the blocks table.
+    pub num_blocks: u64,
+    /// The number of rows in the transactions table.
+    pub num_transactions: u64,
+    /// The number of rows in the transitions table.
+    pub num_transitions: u64,
+    /// The number of rows in the finalize operations table.
+    pub num_finalize_operations: u64,
+}
+
+impl ExportManifest {
+    /// Reads the manifest from the given export directory.
+    pub fn read<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let manifest = fs::read_to_string(directory.as_ref().join(EXPORT_MANIFEST_FILE))?;
+        Ok(serde_json::from_str(&manifest)?)
+    }
+
+    /// Writes the manifest to the given export directory.
+    pub fn write<P: AsRef<Path>>(&self, directory: P) -> Result<()> {
+        let manifest = serde_json::to_string_pretty(self)?;
+        Ok(fs::write(directory.as_ref().join(EXPORT_MANIFEST_FILE), manifest)?)
+    }
+}
+
+/// The writers for the tables of an export.
+pub struct BlockExporter {
+    /// The blocks table.
+    blocks: NdjsonWriter<BlockRow>,
+    /// The transactions table.
+    transactions: NdjsonWriter<TransactionRow>,
+    /// The transitions table.
+    transitions: NdjsonWriter<TransitionRow>,
+    /// The finalize operations table.
+    finalize_operations: NdjsonWriter<FinalizeOperationRow>,
+}
+
+impl BlockExporter {
+    /// Creates the (empty) tables in the given (existing) export directory.
+    pub fn create<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let directory = directory.as_ref();
+        Ok(Self {
+            blocks: NdjsonWriter::create(directory.join(EXPORT_BLOCKS_FILE))?,
+            transactions: NdjsonWriter::create(directory.join(EXPORT_TRANSACTIONS_FILE))?,
+            transitions: NdjsonWriter::create(directory.join(EXPORT_TRANSITIONS_FILE))?,
+            finalize_operations: NdjsonWriter::create(directory.join(EXPORT_FINALIZE_OPERATIONS_FILE))?,
+        })
+    }
+
+    /// Appends the rows for the given block to the tables.
+    ///
+    /// The given decoded finalize operations must be those of the confirmed transactions of the block, in order,
+    /// followed by the ratified finalize operations of the block.
+    pub fn write_block<N: Network>(
+        &mut self,
+        block: &Block<N>,
+        decoded_operations: &[DecodedFinalizeOperation<N>],
+    ) -> Result<()> {
+        let height = block.height();
+        // Ensure the decoded finalize operations include the finalize operations of the transactions.
+        let num_operations = block.transactions().finalize_operations().count();
+        ensure!(
+            decoded_operations.len() >= num_operations,
+            "Missing decoded finalize operations for block {height} (found {}, expected at least {num_operations})",
+            decoded_operations.len()
+        );
+        let (mut transaction_operations, ratified_operations) = decoded_operations.split_at(num_operations);
+
+        // Write the block.
+        self.blocks.write(&BlockRow::new(block))?;
+        // Write the transactions, along with their transitions and finalize operations.
+        for confirmed in block.transactions().iter() {
+            let transaction_id = confirmed.id();
+            self.transactions.write(&TransactionRow::new(height, block.hash(), confirmed)?)?;
+            for (index, transition) in (0u32..).zip(confirmed.transitions()) {
+                self.transitions.write(&TransitionRow::new(height, transaction_id, index, transition))?;
+            }
+            let (operations, remaining) = transaction_operations.split_at(confirmed.finalize_operations().len());
+            for (index, (decoded, operation)) in (0u32..).zip(operations.iter().zip(confirmed.finalize_operations())) {
+                ensure!(decoded.operation == *operation, "Mismatching decoded finalize operation in block {height}");
+                let row = FinalizeOperationRow::new(height, Some(transaction_id), index, decoded);
+                self.finalize_operations.write(&row)?;
+            }
+            transaction_operations = remaining;
+        }
+        // Write the ratified finalize operations.
+        for (index, decoded) in (0u32..).zip(ratified_operations) {
+            self.finalize_operations.write(&FinalizeOperationRow::new(height, None, index, decoded))?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the tables to disk, and returns the manifest for the given range of heights.
+    pub fn finish(self, network_id: u16, start_height: u32, end_height: u32) -> Result<ExportManifest> {
+        Ok(ExportManifest {
+            version: EXPORT_VERSION,
+            network_id,
+            start_height,
+            end_height,
+            num_blocks: self.blocks.finish()?,
+            num_transactions: self.transactions.finish()?,
+            num_transitions: self.transitions.finish()?,
+            num_finalize_operations: self.finalize_operations.finish()?,
+        })
+    }
+}
@@ -15,5 +15,17 @@
 mod bft;
 pub use bft::*;
 
+mod events;
+pub use events::*;
+
+mod export;
+pub use export::*;
+
+mod scanner;
+pub use scanner::*;
+
+mod status;
+pub use status::*;
+
 mod supply;
 pub use supply::*;
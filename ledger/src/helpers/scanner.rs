@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::{Address, ViewKey},
+    network::prelude::*,
+    program::{Ciphertext, Record},
+    types::Field,
+};
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The number of the most recently scanned block hashes that are retained,
+/// to locate the fork point when the ledger is reorganized.
+const NUM_RECENT_BLOCK_HASHES: usize = 1_000;
+
+/// The scan progress of a single account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ScanCursor<N: Network> {
+    /// The height of the next block to scan.
+    next_height: u32,
+    /// The hash of the last scanned block (at `next_height - 1`), if it is known.
+    last_block_hash: Option<N::BlockHash>,
+    /// The commitments of the records found for the account, mapped to the height of their block.
+    commitments: IndexMap<Field<N>, u32>,
+}
+
+impl<N: Network> ScanCursor<N> {
+    /// Returns the height of the next block to scan.
+    pub const fn next_height(&self) -> u32 {
+        self.next_height
+    }
+
+    /// Returns the hash of the last scanned block (at `next_height - 1`), if it is known.
+    pub const fn last_block_hash(&self) -> Option<N::BlockHash> {
+        self.last_block_hash
+    }
+
+    /// Returns the commitments of the records found for the account, mapped to the height of their block.
+    pub const fn commitments(&self) -> &IndexMap<Field<N>, u32> {
+        &self.commitments
+    }
+}
+
+/// A view key prepared for scanning, along with its (precomputed) address.
+pub struct ScanKey<N: Network> {
+    /// The view key.
+    view_key: ViewKey<N>,
+    /// The address of the view key.
+    address: Address<N>,
+    /// The x-coordinate of the address.
+    address_x_coordinate: Field<N>,
+}
+
+impl<N: Network> ScanKey<N> {
+    /// Prepares the given view key for scanning.
+    pub fn new(view_key: ViewKey<N>) -> Self {
+        let address = view_key.to_address();
+        Self { view_key, address, address_x_coordinate: address.to_x_coordinate() }
+    }
+
+    /// Returns the address of the view key.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns `true` if the given record belongs to the view key.
+    fn is_owner(&self, record: &Record<N, Ciphertext<N>>) -> bool {
+        record.is_owner_with_address_x_coordinate(&self.view_key, &self.address_x_coordinate)
+    }
+}
+
+/// An incremental scanner for the records of many accounts.
+///
+/// The scanner keeps a cursor and the found record commitments for each account, keyed by address,
+/// so that a scan resumes from where it left off. The hashes of the recently scanned blocks are kept as well,
+/// so that a reorganization of the ledger is detected on resume (see `RecordScanner::rewind_to_fork_point`).
+/// The scanner state can be saved and loaded as JSON; the view keys are only provided during a scan,
+/// and are never persisted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct RecordScanner<N: Network> {
+    /// The scan progress of each account.
+    accounts: IndexMap<Address<N>, ScanCursor<N>>,
+    /// The hashes of the most recently scanned blocks, keyed by height.
+    block_hashes: BTreeMap<u32, N::BlockHash>,
+}
+
+impl<N: Network> Default for RecordScanner<N> {
+    /// Returns a new scanner without any accounts.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Initializes a new scanner without any accounts.
+    pub fn new() -> Self {
+        Self { accounts: Default::default(), block_hashes: Default::default() }
+    }
+
+    /// Loads the scanner from the given file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let scanner = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&scanner)?)
+    }
+
+    /// Saves the scanner to the given file.
+    /// The file is replaced atomically, so that an interrupted save does not lose the previous state.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let temporary_path = path.with_extension("tmp");
+        // Write the scanner to a temporary file, and ensure it is on disk before it replaces the file.
+        let mut file = File::create(&temporary_path)?;
+        file.write_all(serde_json::to_string(self)?.as_bytes())?;
+        file.sync_all()?;
+        Ok(fs::rename(temporary_path, path)?)
+    }
+
+    /// Registers the given account, to be scanned from the given height onwards,
+    /// and returns `false` if the account was already registered.
+    pub fn register(&mut self, address: Address<N>, start_height: u32) -> bool {
+        match self.accounts.contains_key(&address) {
+            true => false,
+            false => {
+                let cursor =
+                    ScanCursor { next_height: start_height, last_block_hash: None, commitments: Default::default() };
+                self.accounts.insert(address, cursor);
+                true
+            }
+        }
+    }
+
+    /// Removes the given account from the scanner, and returns its scan progress.
+    pub fn unregister(&mut self, address: &Address<N>) -> Option<ScanCursor<N>> {
+        self.accounts.shift_remove(address)
+    }
+
+    /// Returns the scan progress of the given account, if it is registered.
+    pub fn get(&self, address: &Address<N>) -> Option<&ScanCursor<N>> {
+        self.accounts.get(address)
+    }
+
+    /// Returns an iterator over the registered accounts and their scan progress.
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = (&Address<N>, &ScanCursor<N>)> {
+        self.accounts.iter()
+    }
+
+    /// Rewinds every account that has scanned past the given height, so that the block at the given height
+    /// is the next block to scan, and forgets the records found in the rewound blocks.
+    /// This is required after the ledger is rolled back to a height below any of the cursors.
+    pub fn rewind(&mut self, height: u32) {
+        // Forget the hashes of the rewound blocks.
+        self.block_hashes.retain(|block_height, _| *block_height < height);
+        // Retrieve the hash of the block preceding the given height, if it is known.
+        let last_block_hash =
+            height.checked_sub(1).and_then(|last_height| self.block_hashes.get(&last_height).copied());
+
+        for cursor in self.accounts.values_mut() {
+            if cursor.next_height > height {
+                cursor.next_height = height;
+                cursor.last_block_hash = last_block_hash;
+                cursor.commitments.retain(|_, block_height| *block_height < height);
+            }
+        }
+    }
+
+    /// Detects whether the last scanned block of any account is no longer in the ledger, where `get_block_hash`
+    /// returns the hash of the block at the given height in the ledger (if any). If so, every account is rewound
+    /// to the fork point, i.e. the block after the latest recently scanned block that is still in the ledger,
+    /// and the height of the fork point is returned.
+    ///
+    /// If none of the recently scanned blocks are still in the ledger, the accounts are rewound to the genesis block.
+    pub fn rewind_to_fork_point(
+        &mut self,
+        mut get_block_hash: impl FnMut(u32) -> Result<Option<N::BlockHash>>,
+    ) -> Result<Option<u32>> {
+        // Determine whether the last scanned block of any account is no longer in the ledger.
+        let mut is_reorganized = false;
+        for cursor in self.accounts.values() {
+            if let (Some(last_height), Some(last_block_hash)) =
+                (cursor.next_height.checked_sub(1), cursor.last_block_hash)
+            {
+                if get_block_hash(last_height)? != Some(last_block_hash) {
+                    is_reorganized = true;
+                    break;
+                }
+            }
+        }
+        if !is_reorganized {
+            return Ok(None);
+        }
+
+        // Locate the latest recently scanned block that is still in the ledger.
+        let mut fork_height = 0;
+        for (height, block_hash) in self.block_hashes.iter().rev() {
+            if get_block_hash(*height)? == Some(*block_hash) {
+                fork_height = height.saturating_add(1);
+                break;
+            }
+        }
+        // Rewind the accounts to the fork point.
+        self.rewind(fork_height);
+        Ok(Some(fork_height))
+    }
+
+    /// Scans the records of the block at the given height for the given keys, and returns the number of records found.
+    ///
+    /// Only the registered accounts whose cursor is at the block height are scanned, and their cursors
+    /// are then advanced past the block. The ownership checks run in parallel, across records and keys.
+    #[allow(clippy::type_complexity)]
+    pub fn scan_block(
+        &mut self,
+        height: u32,
+        block_hash: N::BlockHash,
+        records: &[(Field<N>, Record<N, Ciphertext<N>>)],
+        keys: &[ScanKey<N>],
+    ) -> Result<usize> {
+        // Select the keys whose accounts are due to scan this block.
+        let keys = keys
+            .iter()
+            .filter(|key| self.accounts.get(&key.address).is_some_and(|cursor| cursor.next_height == height))
+            .collect::<Vec<_>>();
+        if keys.is_empty() {
+            return Ok(0);
+        }
+
+        // Check the ownership of each record, for each of the selected keys.
+        let found: Vec<Vec<(Address<N>, Field<N>)>> = cfg_iter!(records)
+            .map(|(commitment, record)| {
+                keys.iter().filter(|key| key.is_owner(record)).map(|key| (key.address, *commitment)).collect()
+            })
+            .collect();
+
+        // Store the found commitments.
+        let mut num_found = 0;
+        for (address, commitment) in found.into_iter().flatten() {
+            if let Some(cursor) = self.accounts.get_mut(&address) {
+                cursor.commitments.insert(commitment, height);
+                num_found += 1;
+            }
+        }
+        // Advance the cursors past the block.
+        let next_height = height.checked_add(1).ok_or_else(|| anyhow!("The block height overflowed"))?;
+        for key in keys {
+            if let Some(cursor) = self.accounts.get_mut(&key.address) {
+                cursor.next_height = next_height;
+                cursor.last_block_hash = Some(block_hash);
+            }
+        }
+        // Record the hash of the block, retaining only the most recent block hashes.
+        self.block_hashes.insert(height, block_hash);
+        while self.block_hashes.len() > NUM_RECENT_BLOCK_HASHES {
+            self.block_hashes.pop_first();
+        }
+        Ok(num_found)
+    }
+}
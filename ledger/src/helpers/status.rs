@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::{Block, ConfirmedTransaction, Rejected, Transactions};
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use std::sync::mpsc::Sender;
+
+/// The status of a transaction in the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionStatus<N: Network> {
+    /// The transaction is not in the ledger, as it is still pending or is unknown.
+    Pending,
+    /// The transaction was accepted at the given index of the block at the given height,
+    /// with the given fee (in microcredits).
+    Accepted { height: u32, index: u32, fee: u64 },
+    /// The transaction was rejected at the given index of the block at the given height,
+    /// in which case only its fee (in microcredits) was paid.
+    ///
+    /// Note: The ledger records the rejected deployment or execution, but not the reason of the failed finalize.
+    Rejected { height: u32, index: u32, rejected: Rejected<N>, fee: u64 },
+    /// The transaction was aborted in the block at the given height, without paying a fee.
+    Aborted { height: u32 },
+}
+
+impl<N: Network> TransactionStatus<N> {
+    /// Returns the status of the given transaction in the given block contents,
+    /// or `None` if the transaction is not in the block.
+    pub fn from_block_contents(
+        height: u32,
+        transactions: &Transactions<N>,
+        aborted_transaction_ids: &[N::TransactionID],
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<Self>> {
+        // Check if the transaction was aborted.
+        if aborted_transaction_ids.contains(transaction_id) {
+            return Ok(Some(Self::Aborted { height }));
+        }
+        // Check if the transaction was accepted or rejected, where a rejected transaction may also be
+        // identified by the ID of its confirmed fee transaction.
+        let confirmed = transactions
+            .find_confirmed_transaction_for_unconfirmed_transaction_id(transaction_id)
+            .or_else(|| transactions.get(transaction_id));
+        match confirmed {
+            Some(confirmed) => Self::from_confirmed(height, confirmed).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the status of the given confirmed transaction in the block at the given height.
+    pub fn from_confirmed(height: u32, confirmed: &ConfirmedTransaction<N>) -> Result<Self> {
+        // Note: For a rejected transaction, the confirmed transaction is its fee transaction.
+        let index = confirmed.index();
+        let fee = *confirmed.transaction().fee_amount()?;
+        match confirmed.to_rejected() {
+            Some(rejected) => Ok(Self::Rejected { height, index, rejected: rejected.clone(), fee }),
+            None => Ok(Self::Accepted { height, index, fee }),
+        }
+    }
+
+    /// Returns the status of the given transaction in the given block, or `None` if the transaction is not in the block.
+    pub fn from_block(block: &Block<N>, transaction_id: &N::TransactionID) -> Result<Option<Self>> {
+        Self::from_block_contents(block.height(), block.transactions(), block.aborted_transaction_ids(), transaction_id)
+    }
+
+    /// Returns the height of the block that contains the transaction, or `None` if the transaction is pending.
+    pub const fn height(&self) -> Option<u32> {
+        match self {
+            Self::Pending => None,
+            Self::Accepted { height, .. } | Self::Rejected { height, .. } | Self::Aborted { height } => Some(*height),
+        }
+    }
+
+    /// Returns `true` if the transaction is pending.
+    pub const fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+}
+
+/// A change in the status of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionStatusUpdate<N: Network> {
+    /// The transaction ID.
+    pub transaction_id: N::TransactionID,
+    /// The new status of the transaction.
+    pub status: TransactionStatus<N>,
+}
+
+/// A subscription to the status changes of a set of transactions.
+pub(crate) struct TransactionSubscription<N: Network> {
+    /// The last status sent for each transaction.
+    statuses: IndexMap<N::TransactionID, TransactionStatus<N>>,
+    /// The sender of the status changes.
+    sender: Sender<TransactionStatusUpdate<N>>,
+}
+
+impl<N: Network> TransactionSubscription<N> {
+    /// Initializes a new subscription, without any transactions.
+    pub(crate) fn new(sender: Sender<TransactionStatusUpdate<N>>) -> Self {
+        Self { statuses: Default::default(), sender }
+    }
+
+    /// Returns the IDs of the pending transactions.
+    pub(crate) fn pending_transaction_ids(&self) -> Vec<N::TransactionID> {
+        self.statuses.iter().filter(|(_, status)| status.is_pending()).map(|(id, _)| *id).collect()
+    }
+
+    /// Returns the IDs of the transactions that are in a block above the given height.
+    pub(crate) fn transaction_ids_above(&self, height: u32) -> Vec<N::TransactionID> {
+        self.statuses
+            .iter()
+            .filter(|(_, status)| status.height().map_or(false, |status_height| status_height > height))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Sends the given status of the given transaction, if it changed since the last status sent.
+    /// Returns `false` if the receiver has been dropped.
+    pub(crate) fn update(&mut self, transaction_id: N::TransactionID, status: TransactionStatus<N>) -> bool {
+        if self.statuses.get(&transaction_id) == Some(&status) {
+            return true;
+        }
+        self.statuses.insert(transaction_id, status.clone());
+        self.sender.send(TransactionStatusUpdate { transaction_id, status }).is_ok()
+    }
+}
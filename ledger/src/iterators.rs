@@ -15,6 +15,17 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /* Block */
+
+    /// Returns a lazy iterator over the blocks in the given range of heights.
+    /// Unlike `get_blocks`, each block is only loaded from storage when the iterator reaches it.
+    pub fn blocks(
+        &self,
+        heights: Range<u32>,
+    ) -> impl '_ + DoubleEndedIterator<Item = Result<Block<N>>> + ExactSizeIterator {
+        heights.map(|height| self.get_block(height))
+    }
+
     /// Returns an iterator over the state roots, for all blocks in `self`.
     pub fn state_roots(&self) -> impl '_ + Iterator<Item = Cow<'_, N::StateRoot>> {
         self.vm.block_store().state_roots()
@@ -34,13 +34,23 @@ pub use ledger_test_helpers;
 mod helpers;
 pub use helpers::*;
 
+pub mod mempool;
+pub mod wallet;
+
 mod advance;
 mod check_next_block;
 mod check_transaction_basic;
 mod contains;
+mod events;
+mod export;
 mod find;
 mod get;
 mod iterators;
+mod scan;
+mod status;
+
+#[cfg(feature = "rocks")]
+mod snapshot;
 
 #[cfg(test)]
 mod tests;
@@ -48,7 +58,18 @@ mod tests;
 use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
+    program::{
+        Ciphertext,
+        Entry,
+        Identifier,
+        Literal,
+        MappingOperationPath,
+        Plaintext,
+        ProgramID,
+        Record,
+        StatePath,
+        Value,
+    },
     types::{Field, Group},
 };
 use ledger_authority::Authority;
@@ -69,9 +90,12 @@ use aleo_std::{
 use anyhow::Result;
 use core::ops::Range;
 use indexmap::IndexMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rand::{prelude::IteratorRandom, rngs::OsRng};
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    sync::{mpsc::Sender, Arc},
+};
 use time::OffsetDateTime;
 
 #[cfg(not(feature = "serial"))]
@@ -107,6 +131,10 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The subscriptions to the status changes of transactions.
+    transaction_subscriptions: Arc<Mutex<Vec<TransactionSubscription<N>>>>,
+    /// The subscriptions to the events of the ledger.
+    event_subscriptions: Arc<Mutex<Vec<Sender<LedgerEvent<N>>>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -133,7 +161,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let block_heights: Vec<u32> =
             (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
         cfg_into_iter!(block_heights).try_for_each(|height| {
-            ledger.get_block(height)?;
+            // Note: A pruned block can no longer be retrieved in full, so only its header is checked.
+            match ledger.vm.block_store().is_pruned(&ledger.get_hash(height)?)? {
+                true => ledger.get_header(height).map(|_| ())?,
+                false => ledger.get_block(height).map(|_| ())?,
+            }
             Ok::<_, Error>(())
         })?;
         lap!(timer, "Check existence of {NUM_BLOCKS} random blocks");
@@ -142,6 +174,21 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(ledger)
     }
 
+    /// Loads the ledger from storage, and sets the number of latest blocks to retain in full,
+    /// or disables pruning if `None`. The pruning depth is persisted, so `Ledger::load` restores it.
+    /// See `BlockStore::set_pruning_depth` for more details.
+    pub fn load_with_pruning_depth(
+        genesis_block: Block<N>,
+        storage_mode: StorageMode,
+        pruning_depth: Option<u32>,
+    ) -> Result<Self> {
+        // Load the ledger.
+        let ledger = Self::load(genesis_block, storage_mode)?;
+        // Set the pruning depth.
+        ledger.vm.block_store().set_pruning_depth(pruning_depth)?;
+        Ok(ledger)
+    }
+
     /// Loads the ledger from storage, without performing integrity checks.
     pub fn load_unchecked(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<Self> {
         let timer = timer!("Ledger::load_unchecked");
@@ -169,6 +216,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            transaction_subscriptions: Default::default(),
+            event_subscriptions: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -0,0 +1,365 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Ledger;
+use console::{account::PrivateKey, network::prelude::*, types::Field};
+use ledger_block::{Block, Transaction, Transactions};
+use ledger_store::ConsensusStorage;
+
+use anyhow::Result;
+use core::cmp::Ordering;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// The default maximum number of transactions in the mempool.
+pub const DEFAULT_MAX_TRANSACTIONS: usize = 10_000;
+/// The default maximum number of blocks between the anchor of a pending transaction and the latest block.
+pub const DEFAULT_MAX_ANCHOR_AGE: u32 = 1_000;
+
+/// The configuration of a mempool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MempoolConfig {
+    /// The maximum number of transactions in the mempool.
+    pub max_transactions: usize,
+    /// The maximum number of blocks between the anchor of a pending transaction and the latest block,
+    /// after which the transaction expires.
+    pub max_anchor_age: u32,
+}
+
+impl Default for MempoolConfig {
+    /// Returns the default mempool configuration.
+    fn default() -> Self {
+        Self { max_transactions: DEFAULT_MAX_TRANSACTIONS, max_anchor_age: DEFAULT_MAX_ANCHOR_AGE }
+    }
+}
+
+/// The priority of a pending transaction, as its priority fee per byte, with ties broken by arrival order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Priority {
+    /// The priority fee of the transaction, in microcredits.
+    priority_fee: u64,
+    /// The size of the transaction, in bytes.
+    size: u64,
+    /// The arrival sequence number of the transaction.
+    sequence: u64,
+}
+
+impl Ord for Priority {
+    /// Orders the priorities from the lowest to the highest priority fee per byte,
+    /// where an earlier arrival has the higher priority.
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare the priority fees per byte, without division.
+        let fee_rate = self.priority_fee as u128 * other.size as u128;
+        let other_fee_rate = other.priority_fee as u128 * self.size as u128;
+        fee_rate.cmp(&other_fee_rate).then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A transaction in the mempool.
+#[derive(Clone, Debug)]
+struct PendingTransaction<N: Network> {
+    /// The transaction.
+    transaction: Transaction<N>,
+    /// The priority of the transaction.
+    priority: Priority,
+    /// The height of the oldest block whose state root anchors the transaction.
+    anchor_height: u32,
+}
+
+/// A pool of pending transactions, which are validated against the ledger on insertion,
+/// and are ordered by their priority fee per byte.
+///
+/// Transactions that spend the same serial number or tag conflict, in which case only the transaction
+/// with the higher priority is kept. Transactions are evicted once they are included in a block,
+/// or once their anchor state root is more than `max_anchor_age` blocks behind the latest block.
+pub struct Mempool<N: Network> {
+    /// The configuration of the mempool.
+    config: MempoolConfig,
+    /// The pending transactions.
+    transactions: IndexMap<N::TransactionID, PendingTransaction<N>>,
+    /// The pending transaction for each serial number and tag that is spent.
+    spends: HashMap<Field<N>, N::TransactionID>,
+    /// The arrival sequence number of the next transaction.
+    next_sequence: u64,
+}
+
+impl<N: Network> Mempool<N> {
+    /// Initializes a new mempool with the given configuration.
+    pub fn new(config: MempoolConfig) -> Self {
+        Self { config, transactions: Default::default(), spends: Default::default(), next_sequence: 0 }
+    }
+
+    /// Returns the configuration of the mempool.
+    pub const fn config(&self) -> &MempoolConfig {
+        &self.config
+    }
+
+    /// Returns the number of pending transactions.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if there are no pending transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns `true` if the given transaction ID is pending.
+    pub fn contains(&self, transaction_id: &N::TransactionID) -> bool {
+        self.transactions.contains_key(transaction_id)
+    }
+
+    /// Returns the pending transaction for the given transaction ID.
+    pub fn get(&self, transaction_id: &N::TransactionID) -> Option<&Transaction<N>> {
+        self.transactions.get(transaction_id).map(|pending| &pending.transaction)
+    }
+
+    /// Returns the pending transactions, from the highest to the lowest priority.
+    pub fn transactions(&self) -> impl '_ + Iterator<Item = &Transaction<N>> {
+        let mut pending = self.transactions.values().collect::<Vec<_>>();
+        pending.sort_unstable_by(|a, b| b.priority.cmp(&a.priority));
+        pending.into_iter().map(|pending| &pending.transaction)
+    }
+
+    /// Returns up to the given number of pending transactions, from the highest to the lowest priority,
+    /// as the candidate transactions for the next block.
+    pub fn candidates(&self, max_transactions: usize) -> Vec<Transaction<N>> {
+        self.transactions().take(max_transactions).cloned().collect()
+    }
+
+    /// Validates the given transaction against the ledger, and adds it to the mempool.
+    ///
+    /// If the transaction conflicts with pending transactions, it replaces them only if its priority is higher.
+    /// If the mempool is full, the transaction evicts the lowest priority transaction, only if its priority is higher.
+    /// On success, this method returns the IDs of the transactions that were replaced or evicted.
+    pub fn insert<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        ledger: &Ledger<N, C>,
+        transaction: Transaction<N>,
+        rng: &mut R,
+    ) -> Result<Vec<N::TransactionID>> {
+        let transaction_id = transaction.id();
+
+        // Ensure the transaction is not already pending or in the ledger.
+        ensure!(!self.contains(&transaction_id), "Transaction '{transaction_id}' is already in the mempool");
+        ensure!(
+            !ledger.contains_transaction_id(&transaction_id)?,
+            "Transaction '{transaction_id}' already exists in the ledger"
+        );
+        // Ensure the serial numbers and tags are not already spent in the ledger.
+        for serial_number in transaction.serial_numbers() {
+            ensure!(
+                !ledger.contains_serial_number(serial_number)?,
+                "Transaction '{transaction_id}' spends the existing serial number '{serial_number}'"
+            );
+        }
+        for tag in transaction.tags() {
+            ensure!(!ledger.contains_tag(tag)?, "Transaction '{transaction_id}' spends the existing tag '{tag}'");
+        }
+
+        // Ensure the anchor of the transaction has not expired.
+        let anchor_height = Self::anchor_height(ledger, &transaction)?;
+        ensure!(
+            !self.is_expired(anchor_height, ledger.latest_height()),
+            "Transaction '{transaction_id}' is anchored to block {anchor_height}, which has expired"
+        );
+
+        // Compute the priority of the transaction.
+        let priority = Priority {
+            priority_fee: *transaction.priority_fee_amount()?,
+            size: transaction.to_bytes_le()?.len() as u64,
+            sequence: self.next_sequence,
+        };
+
+        // Determine the pending transactions that conflict with the transaction.
+        let mut evicted = Vec::new();
+        for spend in transaction.serial_numbers().chain(transaction.tags()) {
+            if let Some(conflict_id) = self.spends.get(spend) {
+                if !evicted.contains(conflict_id) {
+                    evicted.push(*conflict_id);
+                }
+            }
+        }
+        // Ensure the transaction has a higher priority than the conflicting transactions.
+        for conflict_id in &evicted {
+            ensure!(
+                self.transactions.get(conflict_id).map_or(true, |conflict| priority > conflict.priority),
+                "Transaction '{transaction_id}' conflicts with the pending transaction '{conflict_id}'"
+            );
+        }
+        // If the mempool is full, ensure the transaction has a higher priority than the lowest priority transaction.
+        if self.transactions.len().saturating_sub(evicted.len()) >= self.config.max_transactions {
+            let lowest = self
+                .transactions
+                .iter()
+                .filter(|(id, _)| !evicted.contains(id))
+                .min_by(|(_, a), (_, b)| a.priority.cmp(&b.priority));
+            match lowest {
+                Some((lowest_id, lowest)) if priority > lowest.priority => evicted.push(*lowest_id),
+                _ => bail!(
+                    "Transaction '{transaction_id}' does not have a high enough priority fee for the full mempool"
+                ),
+            }
+        }
+
+        // Check the transaction.
+        ledger.check_transaction_basic(&transaction, None, rng)?;
+
+        // Evict the replaced transactions.
+        for evicted_id in &evicted {
+            self.remove(evicted_id);
+        }
+        // Add the transaction.
+        for spend in transaction.serial_numbers().chain(transaction.tags()) {
+            self.spends.insert(*spend, transaction_id);
+        }
+        self.transactions.insert(transaction_id, PendingTransaction { transaction, priority, anchor_height });
+        self.next_sequence += 1;
+
+        Ok(evicted)
+    }
+
+    /// Removes the given transaction from the mempool, and returns it.
+    pub fn remove(&mut self, transaction_id: &N::TransactionID) -> Option<Transaction<N>> {
+        let pending = self.transactions.shift_remove(transaction_id)?;
+        for spend in pending.transaction.serial_numbers().chain(pending.transaction.tags()) {
+            self.spends.remove(spend);
+        }
+        Some(pending.transaction)
+    }
+
+    /// Removes all of the pending transactions.
+    pub fn clear(&mut self) {
+        self.transactions.clear();
+        self.spends.clear();
+    }
+
+    /// Evicts the pending transactions that are included in the given block (as accepted, rejected, or aborted),
+    /// or that spend a serial number or tag spent in the block, and returns their IDs.
+    pub fn evict_included(&mut self, block: &Block<N>) -> Vec<N::TransactionID> {
+        // Collect the unconfirmed IDs of the transactions in the block.
+        let mut included = block
+            .transactions()
+            .iter()
+            .filter_map(|confirmed| confirmed.to_unconfirmed_transaction_id().ok())
+            .chain(block.aborted_transaction_ids().iter().copied())
+            .filter(|transaction_id| self.contains(transaction_id))
+            .collect::<Vec<_>>();
+        // Collect the pending transactions that spend a serial number or tag spent in the block.
+        for spend in block.serial_numbers().chain(block.tags()) {
+            if let Some(conflict_id) = self.spends.get(spend) {
+                if !included.contains(conflict_id) {
+                    included.push(*conflict_id);
+                }
+            }
+        }
+        // Remove the transactions.
+        for transaction_id in &included {
+            self.remove(transaction_id);
+        }
+        included
+    }
+
+    /// Evicts the pending transactions whose anchor has expired relative to the latest block in the ledger,
+    /// or whose anchor is above the latest block (as the ledger was rolled back), and returns their IDs.
+    pub fn evict_expired<C: ConsensusStorage<N>>(&mut self, ledger: &Ledger<N, C>) -> Vec<N::TransactionID> {
+        let latest_height = ledger.latest_height();
+        let expired = self
+            .transactions
+            .iter()
+            .filter(|(_, pending)| {
+                pending.anchor_height > latest_height || self.is_expired(pending.anchor_height, latest_height)
+            })
+            .map(|(transaction_id, _)| *transaction_id)
+            .collect::<Vec<_>>();
+        for transaction_id in &expired {
+            self.remove(transaction_id);
+        }
+        expired
+    }
+
+    /// Evicts the pending transactions that are included in the given block, or that have expired
+    /// once the block is added to the ledger, and returns their IDs.
+    pub fn advance_to_next_block<C: ConsensusStorage<N>>(
+        &mut self,
+        ledger: &Ledger<N, C>,
+        block: &Block<N>,
+    ) -> Vec<N::TransactionID> {
+        let mut evicted = self.evict_included(block);
+        evicted.extend(self.evict_expired(ledger));
+        evicted
+    }
+
+    /// Returns a candidate for the next block in the ledger, with the highest priority pending transactions.
+    pub fn prepare_advance_to_next_beacon_block<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        ledger: &Ledger<N, C>,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        let candidate_transactions = self.candidates(Transactions::<N>::MAX_TRANSACTIONS);
+        ledger.prepare_advance_to_next_beacon_block(private_key, vec![], vec![], candidate_transactions, rng)
+    }
+}
+
+impl<N: Network> Mempool<N> {
+    /// Returns the height of the oldest block whose state root anchors the given transaction.
+    fn anchor_height<C: ConsensusStorage<N>>(ledger: &Ledger<N, C>, transaction: &Transaction<N>) -> Result<u32> {
+        // Retrieve the state roots of the execution and the fee.
+        let execution_root = transaction.execution().map(|execution| execution.global_state_root());
+        let fee_root = transaction.fee_transition().map(|fee| fee.global_state_root());
+        // Find the height of the oldest state root.
+        let mut anchor_height = None;
+        for state_root in execution_root.into_iter().chain(fee_root) {
+            match ledger.find_block_height_from_state_root(state_root)? {
+                Some(height) => anchor_height = Some(anchor_height.map_or(height, |anchor: u32| anchor.min(height))),
+                None => bail!("Transaction '{}' is anchored to an unknown state root '{state_root}'", transaction.id()),
+            }
+        }
+        anchor_height.ok_or_else(|| anyhow!("Transaction '{}' does not have an anchor", transaction.id()))
+    }
+
+    /// Returns `true` if the given anchor height has expired at the given latest height.
+    fn is_expired(&self, anchor_height: u32, latest_height: u32) -> bool {
+        latest_height.saturating_sub(anchor_height) > self.config.max_anchor_age
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_order() {
+        let priority = |priority_fee, size, sequence| Priority { priority_fee, size, sequence };
+
+        // Ensure a higher priority fee per byte has a higher priority.
+        assert!(priority(200, 100, 0) > priority(100, 100, 1));
+        assert!(priority(100, 100, 0) > priority(100, 200, 1));
+        assert!(priority(300, 200, 1) > priority(100, 100, 0));
+        // Ensure an earlier arrival has a higher priority, for the same priority fee per byte.
+        assert!(priority(100, 100, 0) > priority(200, 200, 1));
+        assert!(priority(0, 100, 0) > priority(0, 50, 1));
+        // Ensure the priorities are totally ordered.
+        let mut priorities = vec![priority(0, 10, 3), priority(50, 10, 2), priority(50, 10, 1), priority(10, 1, 0)];
+        priorities.sort();
+        assert_eq!(priorities, vec![priority(0, 10, 3), priority(50, 10, 2), priority(50, 10, 1), priority(10, 1, 0)]);
+    }
+}
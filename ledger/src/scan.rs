@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Scans the ledger for the records of the given view keys, resuming from the cursors in the given scanner,
+    /// and returns the number of records found.
+    ///
+    /// Accounts that are not yet registered in the scanner are scanned from the genesis block.
+    /// If the ledger was reorganized since the last scan, the accounts are rescanned from the fork point.
+    /// All accounts are scanned in a single pass over the blocks. The records of each block are read from
+    /// the transition store, instead of loading the full block, so that the pruned blocks are scanned as well.
+    pub fn scan_records(&self, scanner: &mut RecordScanner<N>, view_keys: &[ViewKey<N>]) -> Result<usize> {
+        let timer = timer!("Ledger::scan_records");
+
+        // Prepare the view keys for scanning.
+        let keys: Vec<_> = cfg_iter!(view_keys).map(|view_key| ScanKey::new(*view_key)).collect();
+        lap!(timer, "Prepare the view keys");
+
+        // Register the new accounts.
+        for key in &keys {
+            scanner.register(key.address(), 0);
+        }
+
+        // Rewind the scanner to the fork point, if the ledger was reorganized since the last scan.
+        if let Some(fork_height) =
+            scanner.rewind_to_fork_point(|height| self.vm.block_store().get_block_hash(height))?
+        {
+            warn!("The ledger was reorganized since the last scan, rescanning the records from block {fork_height}");
+        }
+
+        // Determine the range of blocks to scan.
+        let end_height = self.latest_height().saturating_add(1);
+        let mut start_height = end_height;
+        for key in &keys {
+            if let Some(cursor) = scanner.get(&key.address()) {
+                // Ensure the cursor is not ahead of the ledger.
+                ensure!(
+                    cursor.next_height() <= end_height,
+                    "The scanner for '{}' is ahead of the ledger (block {}), and must be rewound",
+                    key.address(),
+                    self.latest_height()
+                );
+                start_height = start_height.min(cursor.next_height());
+            }
+        }
+
+        // Scan the blocks.
+        let mut num_found = 0;
+        for height in start_height..end_height {
+            // Retrieve the block hash.
+            let Some(block_hash) = self.vm.block_store().get_block_hash(height)? else {
+                bail!("Missing block hash for block {height}")
+            };
+            // Retrieve the block records.
+            let Some(records) = self.vm.block_store().get_block_records(&block_hash)? else {
+                bail!("Missing records for block {height} ('{block_hash}')")
+            };
+            num_found += scanner.scan_block(height, block_hash, &records, &keys)?;
+        }
+        lap!(timer, "Scan blocks {start_height} to {end_height}");
+
+        finish!(timer);
+        Ok(num_found)
+    }
+
+    /// Returns the record ciphertexts found by the given scanner for the given address.
+    #[allow(clippy::type_complexity)]
+    pub fn scanned_record_ciphertexts(
+        &self,
+        scanner: &RecordScanner<N>,
+        address: &Address<N>,
+    ) -> Result<Vec<(Field<N>, Record<N, Ciphertext<N>>)>> {
+        // Retrieve the scan progress of the address.
+        let Some(cursor) = scanner.get(address) else {
+            bail!("The address '{address}' is not registered in the scanner")
+        };
+
+        cursor
+            .commitments()
+            .keys()
+            .map(|commitment| match self.vm.transition_store().get_record(commitment)? {
+                Some(record) => Ok((*commitment, record)),
+                None => bail!("Missing record for commitment '{commitment}'"),
+            })
+            .collect()
+    }
+}
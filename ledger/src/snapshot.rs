@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::helpers::rocksdb::{self, ConsensusDB, SnapshotCheckpoint, SnapshotManifest};
+use std::path::Path;
+
+impl<N: Network> Ledger<N, ConsensusDB<N>> {
+    /// Exports a snapshot of the ledger at the latest height into the given (new) directory,
+    /// and returns the manifest of the snapshot.
+    pub fn export_snapshot<P: AsRef<Path>>(&self, directory: P) -> Result<SnapshotManifest<N>> {
+        let timer = timer!("Ledger::export_snapshot");
+
+        // Export the snapshot.
+        let manifest = rocksdb::export_snapshot::<N, _, _>(self.vm.block_store().storage_mode().clone(), directory)?;
+        info!("Exported a snapshot of the ledger at block {}", manifest.height());
+
+        finish!(timer);
+        Ok(manifest)
+    }
+
+    /// Loads the ledger from the snapshot in the given directory, into the given (empty) storage.
+    ///
+    /// The snapshot must match the given `checkpoint`, which must come from a trusted source, as the manifest
+    /// of the snapshot is not trusted. The snapshot entries are checked against the digests in its manifest,
+    /// before they are imported. The ledger is then checked against the checkpoint, i.e. the state root is
+    /// recomputed from the imported block hashes, and the finalize checksum from the imported finalize state.
+    /// Lastly, each imported block (that is not pruned) must hash to its block hash, and commit to its imported
+    /// transactions and (if stored) ratified finalize operations. If any check fails, the imported entries are removed.
+    pub fn load_from_snapshot<P: AsRef<Path>>(
+        genesis_block: Block<N>,
+        directory: P,
+        checkpoint: SnapshotCheckpoint<N>,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::load_from_snapshot");
+
+        // Ensure the snapshot is for the checkpoint.
+        SnapshotManifest::<N>::read(&directory)?.check_checkpoint(&checkpoint)?;
+
+        // Import the snapshot.
+        info!("Importing a snapshot of the ledger...");
+        let manifest = rocksdb::import_snapshot::<N, _, _>(storage_mode.clone(), &directory)?;
+        lap!(timer, "Import the snapshot");
+
+        // Load the ledger, and ensure it matches the checkpoint (via the imported manifest, which is re-read).
+        let result = Self::load(genesis_block, storage_mode.clone()).and_then(|ledger| {
+            manifest.check_checkpoint(&checkpoint)?;
+            manifest.check(ledger.vm.block_store(), ledger.vm.finalize_store())?;
+            ledger.check_snapshot_blocks(checkpoint.height())?;
+            Ok(ledger)
+        });
+        lap!(timer, "Load and check the ledger");
+
+        match result {
+            Ok(ledger) => {
+                info!("Loaded the ledger from a snapshot at block {}", manifest.height());
+                finish!(timer);
+                Ok(ledger)
+            }
+            Err(error) => {
+                // Remove the imported entries.
+                rocksdb::remove_snapshot::<N, _>(storage_mode)?;
+                bail!("Failed to load the ledger from the snapshot - {error}")
+            }
+        }
+    }
+
+    /// Ensures each imported block up to the given height, that is not pruned, matches its block hash
+    /// (to which the state root commits), i.e. that its header hashes to the block hash, and commits to the
+    /// imported transactions and ratified finalize operations. The ratified finalize operations are required
+    /// for the block at the given height, and checked for the earlier blocks that store them.
+    fn check_snapshot_blocks(&self, height: u32) -> Result<()> {
+        for block_height in 0..=height {
+            let Some(block_hash) = self.vm.block_store().get_block_hash(block_height)? else {
+                bail!("Missing the block hash of the imported block {block_height}")
+            };
+            // Skip the pruned blocks, whose transactions are not stored.
+            if self.vm.block_store().is_pruned(&block_hash)? {
+                continue;
+            }
+            // Retrieve the block, which recomputes the block hash from the imported header.
+            let block = self.get_block(block_height)?;
+            ensure!(block.hash() == block_hash, "The imported block {block_height} does not hash to '{block_hash}'");
+            // Ensure the header commits to the imported transactions.
+            ensure!(
+                block.transactions().to_transactions_root()? == block.transactions_root(),
+                "The imported block {block_height} does not match its transactions root"
+            );
+            // Ensure the header commits to the imported finalize operations.
+            let ratified_finalize_operations = match self.vm.finalize_store().get_ratified_operations(block_height)? {
+                Some(ratified_finalize_operations) => ratified_finalize_operations,
+                None if block_height < height => continue,
+                None => bail!("Missing the ratified finalize operations for the imported block {block_height}"),
+            };
+            ensure!(
+                block.transactions().to_finalize_root(ratified_finalize_operations)? == block.finalize_root(),
+                "The imported block {block_height} does not match its finalize root"
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::sync::mpsc::{channel, Receiver};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the status of the given transaction ID.
+    ///
+    /// A transaction that is not in the ledger is reported as pending,
+    /// as the ledger cannot distinguish a pending transaction from an unknown one.
+    pub fn transaction_status(&self, transaction_id: &N::TransactionID) -> Result<TransactionStatus<N>> {
+        // Retrieve the block that contains the transaction.
+        let Some(block_hash) = self.find_block_hash(transaction_id)? else {
+            return Ok(TransactionStatus::Pending);
+        };
+        let height = self.get_height(&block_hash)?;
+        // Retrieve the status of the transaction in the block.
+        let transactions = self.get_transactions(height)?;
+        let aborted_transaction_ids = self.get_aborted_transaction_ids(height)?;
+        match TransactionStatus::from_block_contents(height, &transactions, &aborted_transaction_ids, transaction_id)? {
+            Some(status) => Ok(status),
+            None => bail!("Missing transaction '{transaction_id}' in block {height}"),
+        }
+    }
+
+    /// Subscribes to the status changes of the given transaction IDs, and returns the receiver of the changes.
+    ///
+    /// The current status of each transaction is sent upon subscribing. Afterwards, a change is sent
+    /// whenever a block that contains one of the transactions is added, or is removed by a rollback.
+    /// The subscription ends when the receiver is dropped.
+    pub fn subscribe_transaction_statuses(
+        &self,
+        transaction_ids: impl IntoIterator<Item = N::TransactionID>,
+    ) -> Result<Receiver<TransactionStatusUpdate<N>>> {
+        // Hold the lock on the subscriptions, so that no status change is missed while subscribing.
+        let mut subscriptions = self.transaction_subscriptions.lock();
+        // Send the current status of each transaction.
+        let (sender, receiver) = channel();
+        let mut subscription = TransactionSubscription::new(sender);
+        for transaction_id in transaction_ids {
+            subscription.update(transaction_id, self.transaction_status(&transaction_id)?);
+        }
+        subscriptions.push(subscription);
+        Ok(receiver)
+    }
+
+    /// Sends the status changes of the subscribed transactions that are in the given block.
+    pub(crate) fn notify_transaction_statuses(&self, block: &Block<N>) {
+        self.transaction_subscriptions.lock().retain_mut(|subscription| {
+            for transaction_id in subscription.pending_transaction_ids() {
+                match TransactionStatus::from_block(block, &transaction_id) {
+                    Ok(Some(status)) => {
+                        if !subscription.update(transaction_id, status) {
+                            return false;
+                        }
+                    }
+                    Ok(None) => (),
+                    Err(error) => warn!("Failed to determine the status of transaction '{transaction_id}': {error}"),
+                }
+            }
+            true
+        });
+    }
+
+    /// Sends the pending status of the subscribed transactions that were in a block above the given height.
+    pub(crate) fn notify_transaction_rollbacks(&self, height: u32) {
+        self.transaction_subscriptions.lock().retain_mut(|subscription| {
+            subscription
+                .transaction_ids_above(height)
+                .into_iter()
+                .all(|transaction_id| subscription.update(transaction_id, TransactionStatus::Pending))
+        });
+    }
+}
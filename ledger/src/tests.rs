@@ -20,11 +20,11 @@ use crate::{
 };
 use aleo_std::StorageMode;
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    program::{Entry, Identifier, Literal, MappingOperationPath, Plaintext, ProgramID, Value},
 };
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
@@ -448,6 +448,15 @@ finalize failed_assert:
 
     // Add the block with the rejected transaction to the ledger.
     ledger.advance_to_next_block(&next_block).unwrap();
+
+    // Check that the status of the rejected execution is correct.
+    let fee = *confirmed_transaction.transaction().fee_amount().unwrap();
+    let rejected = confirmed_transaction.to_rejected().unwrap().clone();
+    let expected = crate::TransactionStatus::Rejected { height: next_block.height(), index: 0, rejected, fee };
+    assert_eq!(ledger.transaction_status(&failed_assert_transaction_id).unwrap(), expected);
+
+    // Check that the confirmed transaction ID of the rejected execution has the same status.
+    assert_eq!(ledger.transaction_status(&confirmed_transaction.transaction().id()).unwrap(), expected);
 }
 
 #[test]
@@ -1672,3 +1681,788 @@ fn test_deployment_exceeding_max_transaction_spend() {
     // Check that the deployment failed.
     assert!(result.is_err());
 }
+
+#[test]
+fn test_rollback_to() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample recipient.
+    let recipient_private_key = PrivateKey::new(rng).unwrap();
+    let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+    // Prepare the mapping key for the recipient's public balance.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let mapping_name = Identifier::from_str("account").unwrap();
+    let key = Plaintext::from(Literal::Address(recipient_address));
+
+    // Fund the recipient with 1 million credits.
+    let inputs =
+        [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("1000000000000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the recipient's balance is available at the latest height, and not at the genesis height.
+    let finalize_store = ledger.vm().finalize_store();
+    let balance = Value::from_str("1000000000000u64").unwrap();
+    assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(), Some(balance.clone()));
+    assert_eq!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 1).unwrap(), Some(balance));
+    assert_eq!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).unwrap(), None);
+
+    // Rollback to the genesis block.
+    ledger.rollback_to(0).unwrap();
+    assert_eq!(ledger.latest_height(), 0);
+    assert_eq!(ledger.latest_hash(), ledger.genesis_block.hash());
+    assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(), None);
+
+    // Ensure the block can be added again.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+}
+
+#[test]
+fn test_export_blocks() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+    // Initialize the ledger without checks.
+    let ledger = CurrentLedger::load_unchecked(genesis.clone(), StorageMode::Production).unwrap();
+
+    // Ensure the lazy block iterator yields the same blocks as `get_blocks`.
+    let blocks = ledger.blocks(0..1).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(blocks, ledger.get_blocks(0..1).unwrap());
+
+    // Ensure an empty or out-of-bounds range is rejected.
+    let directory = tempfile::tempdir().unwrap();
+    assert!(ledger.export_blocks(0..0, directory.path().join("empty")).is_err());
+    assert!(ledger.export_blocks(0..2, directory.path().join("out_of_bounds")).is_err());
+
+    // Export the genesis block.
+    let export = directory.path().join("export");
+    let manifest = ledger.export_blocks(0..1, &export).unwrap();
+    assert_eq!(manifest, crate::ExportManifest::read(&export).unwrap());
+    assert_eq!(manifest.num_blocks, 1);
+    assert_eq!(manifest.num_transactions, genesis.transactions().len() as u64);
+    assert_eq!(manifest.num_transitions, genesis.transitions().count() as u64);
+    let ratified_operations = ledger.vm().finalize_store().get_ratified_operations(0).unwrap().unwrap();
+    assert_eq!(
+        manifest.num_finalize_operations,
+        (genesis.transactions().finalize_operations().count() + ratified_operations.len()) as u64
+    );
+
+    // Ensure the tables match the manifest and the genesis block.
+    let blocks: Vec<crate::BlockRow> = crate::read_ndjson(export.join(crate::EXPORT_BLOCKS_FILE)).unwrap();
+    assert_eq!(blocks, vec![crate::BlockRow::new(&genesis)]);
+    let transactions: Vec<crate::TransactionRow> =
+        crate::read_ndjson(export.join(crate::EXPORT_TRANSACTIONS_FILE)).unwrap();
+    assert_eq!(transactions.len() as u64, manifest.num_transactions);
+    assert!(transactions.iter().zip(genesis.transaction_ids()).all(|(row, id)| row.transaction_id == id.to_string()));
+    let transitions: Vec<crate::TransitionRow> =
+        crate::read_ndjson(export.join(crate::EXPORT_TRANSITIONS_FILE)).unwrap();
+    assert_eq!(transitions.len() as u64, manifest.num_transitions);
+    let operations: Vec<crate::FinalizeOperationRow> =
+        crate::read_ndjson(export.join(crate::EXPORT_FINALIZE_OPERATIONS_FILE)).unwrap();
+    assert_eq!(operations.len() as u64, manifest.num_finalize_operations);
+
+    // Ensure the export does not overwrite an existing directory.
+    assert!(ledger.export_blocks(0..1, &export).is_err());
+}
+
+#[test]
+fn test_export_finalize_operations() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample recipient.
+    let recipient_private_key = PrivateKey::new(rng).unwrap();
+    let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+    // Fund the recipient with 1 million credits.
+    let inputs =
+        [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("1000000000000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Export the block.
+    let directory = tempfile::tempdir().unwrap();
+    let export = directory.path().join("export");
+    let manifest = ledger.export_blocks(1..2, &export).unwrap();
+    let operations: Vec<crate::FinalizeOperationRow> =
+        crate::read_ndjson(export.join(crate::EXPORT_FINALIZE_OPERATIONS_FILE)).unwrap();
+    assert_eq!(operations.len() as u64, manifest.num_finalize_operations);
+
+    // Ensure the finalize operations of the transaction are exported, followed by the ratified finalize operations.
+    let num_transaction_operations = block.transactions().finalize_operations().count();
+    let ratified_operations = ledger.vm().finalize_store().get_ratified_operations(1).unwrap().unwrap();
+    assert_eq!(operations.len(), num_transaction_operations + ratified_operations.len());
+    let transaction_id = Some(transaction_id.to_string());
+    assert!(operations[..num_transaction_operations].iter().all(|row| row.transaction_id == transaction_id));
+    assert!(operations[num_transaction_operations..].iter().all(|row| row.transaction_id.is_none()));
+    assert!(operations.iter().all(|row| row.program_id == "credits.aleo"));
+
+    // Ensure the balance of the recipient is decoded.
+    let recipient = operations.iter().find(|row| row.key == Some(recipient_address.to_string())).unwrap();
+    assert_eq!(recipient.operation, "insert_key_value");
+    assert_eq!(recipient.mapping, "account");
+    assert_eq!(recipient.value, Some("1000000000000u64".to_string()));
+}
+
+#[test]
+fn test_scan_records() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+    // Initialize the ledger without checks.
+    let ledger = CurrentLedger::load_unchecked(genesis, StorageMode::Production).unwrap();
+
+    // Sample the view keys.
+    let view_keys =
+        (0..4).map(|_| ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap()).collect::<Vec<_>>();
+
+    // Scan the ledger for all view keys in one pass.
+    let mut scanner = crate::RecordScanner::new();
+    let num_found = ledger.scan_records(&mut scanner, &view_keys).unwrap();
+
+    // Ensure the scanner matches a full scan of the ledger, for each view key.
+    let mut expected_num_found = 0;
+    for view_key in &view_keys {
+        let expected = ledger
+            .find_record_ciphertexts(view_key, RecordsFilter::All)
+            .unwrap()
+            .map(|(commitment, _)| commitment)
+            .collect::<IndexSet<_>>();
+        let cursor = scanner.get(&view_key.to_address()).unwrap();
+        assert_eq!(cursor.next_height(), ledger.latest_height() + 1);
+        assert_eq!(cursor.commitments().keys().copied().collect::<IndexSet<_>>(), expected);
+        let records = ledger.scanned_record_ciphertexts(&scanner, &view_key.to_address()).unwrap();
+        assert_eq!(records.len(), expected.len());
+        expected_num_found += expected.len();
+    }
+    assert_eq!(num_found, expected_num_found);
+
+    // Ensure a repeated scan resumes from the cursors, and finds nothing new.
+    let expected_scanner = scanner.clone();
+    assert_eq!(ledger.scan_records(&mut scanner, &view_keys).unwrap(), 0);
+    assert_eq!(scanner, expected_scanner);
+
+    // Ensure the scanner is persisted.
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("scanner.json");
+    scanner.save(&path).unwrap();
+    assert_eq!(crate::RecordScanner::<CurrentNetwork>::load(&path).unwrap(), scanner);
+
+    // Ensure a rewound scanner rescans the rewound blocks.
+    scanner.rewind(0);
+    assert!(scanner.iter().all(|(_, cursor)| cursor.next_height() == 0 && cursor.commitments().is_empty()));
+    assert_eq!(ledger.scan_records(&mut scanner, &view_keys).unwrap(), num_found);
+    assert_eq!(scanner, expected_scanner);
+
+    // Ensure a scanner that is ahead of the ledger is rejected.
+    let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(scanner.register(view_key.to_address(), ledger.latest_height() + 2));
+    assert!(ledger.scan_records(&mut scanner, &[view_key]).is_err());
+}
+
+#[test]
+fn test_scan_records_after_reorganization() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample the view keys of two recipients.
+    let view_keys =
+        (0..2).map(|_| ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap()).collect::<Vec<_>>();
+
+    // Advances the ledger with a block that transfers a private record to the given recipient.
+    let advance_with_transfer = |recipient: Address<CurrentNetwork>, rng: &mut TestRng| {
+        let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("1000000u64").unwrap()];
+        let function = ("credits.aleo", "transfer_public_to_private");
+        let transaction = ledger.vm.execute(&private_key, function, inputs.into_iter(), None, 0, None, rng).unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        block
+    };
+
+    // Scan a block that transfers a record to the first recipient.
+    let block = advance_with_transfer(view_keys[0].to_address(), rng);
+    let mut scanner = crate::RecordScanner::new();
+    ledger.scan_records(&mut scanner, &view_keys).unwrap();
+    assert_eq!(scanner.get(&view_keys[0].to_address()).unwrap().commitments().len(), 1);
+    assert_eq!(scanner.get(&view_keys[0].to_address()).unwrap().last_block_hash(), Some(block.hash()));
+
+    // Replace the block with one that transfers a record to the second recipient.
+    ledger.rollback_to(0).unwrap();
+    let block = advance_with_transfer(view_keys[1].to_address(), rng);
+
+    // Ensure the scanner rescans from the fork point, and matches a scan of the reorganized ledger.
+    ledger.scan_records(&mut scanner, &view_keys).unwrap();
+    let mut expected_scanner = crate::RecordScanner::new();
+    ledger.scan_records(&mut expected_scanner, &view_keys).unwrap();
+    assert_eq!(scanner, expected_scanner);
+    for view_key in &view_keys {
+        let cursor = scanner.get(&view_key.to_address()).unwrap();
+        assert_eq!(cursor.next_height(), 2);
+        assert_eq!(cursor.last_block_hash(), Some(block.hash()));
+    }
+    assert!(scanner.get(&view_keys[0].to_address()).unwrap().commitments().is_empty());
+    assert_eq!(scanner.get(&view_keys[1].to_address()).unwrap().commitments().len(), 1);
+}
+
+#[test]
+fn test_scan_records_after_pruning() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample the view key of the recipient.
+    let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // Advance the ledger with a block that transfers a private record to the recipient.
+    let recipient = view_key.to_address();
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("1000000u64").unwrap()];
+    let function = ("credits.aleo", "transfer_public_to_private");
+    let transaction = ledger.vm.execute(&private_key, function, inputs.into_iter(), None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Scan the ledger before the block is pruned.
+    let mut expected_scanner = crate::RecordScanner::new();
+    assert_eq!(ledger.scan_records(&mut expected_scanner, &[view_key]).unwrap(), 1);
+
+    // Advance the ledger with an empty block, and prune the block with the transfer.
+    let next_block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&next_block).unwrap();
+    ledger.vm.block_store().prune(block.height()).unwrap();
+    assert!(ledger.get_block(block.height()).is_err());
+
+    // Ensure the pruned block is scanned, and its record is found.
+    let mut scanner = crate::RecordScanner::new();
+    assert_eq!(ledger.scan_records(&mut scanner, &[view_key]).unwrap(), 1);
+    let cursor = scanner.get(&view_key.to_address()).unwrap();
+    assert_eq!(cursor.commitments(), expected_scanner.get(&view_key.to_address()).unwrap().commitments());
+    assert_eq!(cursor.commitments().values().copied().collect::<Vec<_>>(), vec![block.height()]);
+    assert_eq!(ledger.scanned_record_ciphertexts(&scanner, &view_key.to_address()).unwrap().len(), 1);
+}
+
+#[test]
+fn test_wallet_sync() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+    // Initialize the ledger without checks.
+    let ledger = CurrentLedger::load_unchecked(genesis, StorageMode::Production).unwrap();
+
+    // Initialize a wallet for a new account.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let mut wallet = crate::wallet::Wallet::new(private_key, 0).unwrap();
+    wallet.sync(&ledger).unwrap();
+
+    // Ensure the wallet is synchronized, and holds no funds.
+    let cursor = wallet.scanner().get(&wallet.address()).unwrap();
+    assert_eq!(cursor.next_height(), ledger.latest_height() + 1);
+    assert_eq!(wallet.balance(), crate::wallet::Balance::default());
+    assert_eq!(wallet.records().count(), 0);
+
+    // Ensure a transfer fails without any funds.
+    let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(wallet.prepare_transfer_private(&ledger, recipient, 1, 0, 10_000, None, rng).is_err());
+    assert!(wallet.prepare_transfer_public_to_private(&ledger, recipient, 1, 0, 10_000, None, rng).is_err());
+    assert_eq!(wallet.pending_transactions().count(), 0);
+}
+
+#[test]
+fn test_wallet_transfers() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Adds a block with the given transactions to the ledger.
+    let advance = |transactions: Vec<Transaction<CurrentNetwork>>, rng: &mut TestRng| {
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    };
+
+    // Initialize the wallets of the funded account, and of two new accounts.
+    let mut funded = crate::wallet::Wallet::new(private_key, 0).unwrap();
+    let mut alice = crate::wallet::Wallet::new(PrivateKey::new(rng).unwrap(), 0).unwrap();
+    let mut bob = crate::wallet::Wallet::new(PrivateKey::new(rng).unwrap(), 0).unwrap();
+    funded.sync(&ledger).unwrap();
+
+    // Fund Alice with two records, and with a public balance for the fees.
+    let first = funded.prepare_transfer_public_to_private(&ledger, alice.address(), 3_000_000, 0, 100_000, None, rng);
+    let second = funded.prepare_transfer_public_to_private(&ledger, alice.address(), 2_000_000, 0, 100_000, None, rng);
+    assert_eq!(funded.balance().pending_public, 5_200_000);
+    let inputs = [Value::from_str(&format!("{}", alice.address())).unwrap(), Value::from_str("1000000u64").unwrap()];
+    let third = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    advance(vec![first.unwrap(), second.unwrap(), third], rng);
+    funded.sync(&ledger).unwrap();
+    alice.sync(&ledger).unwrap();
+    assert_eq!(funded.pending_transactions().count(), 0);
+    assert_eq!(alice.records().count(), 2);
+    assert_eq!(alice.balance().private, 5_000_000);
+    assert_eq!(alice.balance().public, 1_000_000);
+
+    // Ensure a transfer that no single record covers joins the records first, reserving them until confirmed.
+    let step = alice.prepare_transfer_private(&ledger, bob.address(), 4_000_000, 0, 100_000, None, rng).unwrap();
+    assert!(matches!(step, crate::wallet::TransferStep::Join(..)));
+    assert_eq!(alice.balance().spendable_private(), 0);
+    assert_eq!(alice.balance().pending_public, 100_000);
+    assert!(alice.prepare_transfer_private(&ledger, bob.address(), 1_000_000, 0, 100_000, None, rng).is_err());
+    advance(vec![step.transaction().clone()], rng);
+    alice.sync(&ledger).unwrap();
+    assert_eq!(alice.pending_transactions().count(), 0);
+    assert_eq!(alice.records().count(), 1);
+    assert_eq!(alice.balance().private, 5_000_000);
+
+    // Ensure the transfer completes from the joined record.
+    let step = alice.prepare_transfer_private(&ledger, bob.address(), 4_000_000, 0, 100_000, None, rng).unwrap();
+    assert!(step.is_transfer());
+    advance(vec![step.transaction().clone()], rng);
+    alice.sync(&ledger).unwrap();
+    bob.sync(&ledger).unwrap();
+    assert_eq!(alice.balance().private, 1_000_000);
+    assert_eq!(bob.records().count(), 1);
+    assert_eq!(bob.balance(), crate::wallet::Balance { private: 4_000_000, ..Default::default() });
+
+    // Ensure a transfer whose only record must also cover the fee splits the record first.
+    let step = bob.prepare_transfer_private(&ledger, alice.address(), 1_000_000, 0, 100_000, None, rng).unwrap();
+    assert!(matches!(step, crate::wallet::TransferStep::Split(..)));
+    advance(vec![step.transaction().clone()], rng);
+    bob.sync(&ledger).unwrap();
+    assert_eq!(bob.records().count(), 2);
+    assert_eq!(bob.balance().private, 4_000_000 - crate::wallet::SPLIT_FEE_IN_MICROCREDITS);
+
+    // Ensure the transfer completes from the split record, paying the fee from the remaining record.
+    let step = bob.prepare_transfer_private(&ledger, alice.address(), 1_000_000, 0, 100_000, None, rng).unwrap();
+    assert!(step.is_transfer());
+    advance(vec![step.transaction().clone()], rng);
+    alice.sync(&ledger).unwrap();
+    bob.sync(&ledger).unwrap();
+    assert_eq!(alice.balance().private, 2_000_000);
+    assert_eq!(bob.pending_transactions().count(), 0);
+    assert!(bob.balance().private < 3_000_000 - crate::wallet::SPLIT_FEE_IN_MICROCREDITS);
+
+    // Spend more than half of the public balance from two wallets of the funded account,
+    // so that the second transaction is rejected, as the public balance no longer covers it.
+    let mut other = crate::wallet::Wallet::new(private_key, 0).unwrap();
+    funded.sync(&ledger).unwrap();
+    other.sync(&ledger).unwrap();
+    let amount = funded.balance().public / 2 + 1;
+    let accepted = funded.prepare_transfer_public_to_private(&ledger, alice.address(), amount, 0, 100_000, None, rng);
+    let rejected = other.prepare_transfer_public_to_private(&ledger, alice.address(), amount, 0, 100_000, None, rng);
+    let (accepted, rejected) = (accepted.unwrap(), rejected.unwrap());
+    advance(vec![accepted, rejected.clone()], rng);
+    assert!(matches!(ledger.transaction_status(&rejected.id()).unwrap(), crate::TransactionStatus::Rejected { .. }));
+
+    // Ensure the rejected transaction is released, along with its reserved public balance.
+    other.sync(&ledger).unwrap();
+    assert_eq!(other.pending_transactions().count(), 0);
+    assert_eq!(other.balance().pending_public, 0);
+}
+
+#[test]
+fn test_wallet_sync_after_rollback() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Initialize the wallets of the funded account, and of a new account.
+    let mut funded = crate::wallet::Wallet::new(private_key, 0).unwrap();
+    let mut alice = crate::wallet::Wallet::new(PrivateKey::new(rng).unwrap(), 0).unwrap();
+    funded.sync(&ledger).unwrap();
+    let recipient = alice.address();
+
+    // Adds a block that transfers a record to Alice.
+    let mut advance_with_transfer = |rng: &mut TestRng| {
+        let transaction =
+            funded.prepare_transfer_public_to_private(&ledger, recipient, 1_000_000, 0, 100_000, None, rng).unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    };
+
+    // Ensure Alice holds the transferred record.
+    advance_with_transfer(rng);
+    alice.sync(&ledger).unwrap();
+    assert_eq!(alice.records().count(), 1);
+    assert_eq!(alice.balance().private, 1_000_000);
+
+    // Roll back the block, and ensure Alice no longer holds the record.
+    ledger.rollback_to(0).unwrap();
+    alice.sync(&ledger).unwrap();
+    assert_eq!(alice.records().count(), 0);
+    assert_eq!(alice.balance(), crate::wallet::Balance::default());
+
+    // Ensure Alice holds the record of the replacing block.
+    advance_with_transfer(rng);
+    alice.sync(&ledger).unwrap();
+    assert_eq!(alice.records().count(), 1);
+    assert_eq!(alice.balance().private, 1_000_000);
+}
+
+#[test]
+fn test_mempool_rejects_existing_transactions() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+    // Initialize the ledger without checks.
+    let ledger = CurrentLedger::load_unchecked(genesis.clone(), StorageMode::Production).unwrap();
+
+    // Ensure the transactions in the ledger are rejected by the mempool.
+    let mut mempool = crate::mempool::Mempool::new(Default::default());
+    for confirmed in genesis.transactions().iter() {
+        let error = mempool.insert(&ledger, confirmed.transaction().clone(), rng).unwrap_err();
+        assert!(error.to_string().contains("already exists in the ledger"));
+    }
+    assert!(mempool.is_empty());
+}
+
+#[test]
+fn test_mempool() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare `transfer_public` transactions with the given priority fees.
+    let transfer = |priority_fee: u64, rng: &mut TestRng| {
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("1000000u64").unwrap()];
+        let function = ("credits.aleo", "transfer_public");
+        ledger.vm.execute(&private_key, function, inputs.into_iter(), None, priority_fee, None, rng).unwrap()
+    };
+    let low = transfer(0, rng);
+    let high = transfer(10_000, rng);
+    let medium = transfer(100, rng);
+
+    // Add the transactions to a mempool, which holds at most two transactions.
+    let config = crate::mempool::MempoolConfig { max_transactions: 2, max_anchor_age: 1 };
+    let mut mempool = crate::mempool::Mempool::new(config);
+    assert!(mempool.insert(&ledger, low.clone(), rng).unwrap().is_empty());
+    assert!(mempool.insert(&ledger, medium.clone(), rng).unwrap().is_empty());
+    assert!(mempool.insert(&ledger, medium.clone(), rng).is_err());
+
+    // Ensure the highest priority transaction evicts the lowest priority transaction, once the mempool is full.
+    assert_eq!(mempool.insert(&ledger, high.clone(), rng).unwrap(), vec![low.id()]);
+    assert!(mempool.insert(&ledger, low.clone(), rng).is_err());
+    assert_eq!(mempool.candidates(2), vec![high.clone(), medium.clone()]);
+    assert_eq!(mempool.candidates(1), vec![high.clone()]);
+
+    // Produce a block with the highest priority transaction.
+    let candidates = mempool.candidates(1);
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], candidates, rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the included transaction is evicted.
+    assert_eq!(mempool.advance_to_next_block(&ledger, &block), vec![high.id()]);
+    assert_eq!(mempool.candidates(2), vec![medium.clone()]);
+    assert!(mempool.insert(&ledger, high, rng).is_err());
+
+    // Produce an empty block, after which the remaining transaction has expired.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(mempool.advance_to_next_block(&ledger, &block), vec![medium.id()]);
+    assert!(mempool.is_empty());
+    assert!(mempool.insert(&ledger, medium, rng).unwrap_err().to_string().contains("has expired"));
+
+    // Ensure the mempool feeds the next block.
+    assert!(mempool.insert(&ledger, low, rng).is_err());
+    let transaction = transfer(1, rng);
+    mempool.insert(&ledger, transaction.clone(), rng).unwrap();
+    let block = mempool.prepare_advance_to_next_beacon_block(&ledger, &private_key, rng).unwrap();
+    assert!(block.transactions().get(&transaction.id()).is_some());
+
+    // Ensure the transaction expires once its anchor is rolled back.
+    ledger.rollback_to(ledger.latest_height() - 1).unwrap();
+    assert_eq!(mempool.evict_expired(&ledger), vec![transaction.id()]);
+}
+
+#[test]
+fn test_transaction_status() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Create a transaction that will be accepted.
+    let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let accepted_transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let accepted_transaction_id = accepted_transaction.id();
+    let fee = *accepted_transaction.fee_amount().unwrap();
+
+    // Create a transaction that will be aborted, as its signer cannot pay the fee.
+    let unfunded_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let aborted_transaction = ledger
+        .vm
+        .execute(&unfunded_private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let aborted_transaction_id = aborted_transaction.id();
+
+    // Ensure the transactions are pending.
+    assert_eq!(ledger.transaction_status(&accepted_transaction_id).unwrap(), crate::TransactionStatus::Pending);
+    assert_eq!(ledger.transaction_status(&aborted_transaction_id).unwrap(), crate::TransactionStatus::Pending);
+
+    // Subscribe to the status changes, and ensure the current statuses are sent.
+    let receiver = ledger.subscribe_transaction_statuses([accepted_transaction_id, aborted_transaction_id]).unwrap();
+    let pending =
+        |transaction_id| crate::TransactionStatusUpdate { transaction_id, status: crate::TransactionStatus::Pending };
+    assert_eq!(receiver.try_recv().unwrap(), pending(accepted_transaction_id));
+    assert_eq!(receiver.try_recv().unwrap(), pending(aborted_transaction_id));
+    assert!(receiver.try_recv().is_err());
+
+    // Add a block with the transactions.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![accepted_transaction, aborted_transaction],
+            rng,
+        )
+        .unwrap();
+    assert_eq!(block.aborted_transaction_ids(), &[aborted_transaction_id]);
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the statuses are correct, and that their changes are sent.
+    let height = block.height();
+    let accepted = crate::TransactionStatus::Accepted { height, index: 0, fee };
+    let aborted = crate::TransactionStatus::Aborted { height };
+    assert_eq!(ledger.transaction_status(&accepted_transaction_id).unwrap(), accepted);
+    assert_eq!(ledger.transaction_status(&aborted_transaction_id).unwrap(), aborted);
+    let update = receiver.try_recv().unwrap();
+    assert_eq!((update.transaction_id, update.status), (accepted_transaction_id, accepted.clone()));
+    let update = receiver.try_recv().unwrap();
+    assert_eq!((update.transaction_id, update.status), (aborted_transaction_id, aborted.clone()));
+    assert!(receiver.try_recv().is_err());
+
+    // Ensure a new subscription receives the current statuses.
+    let receiver_2 = ledger.subscribe_transaction_statuses([accepted_transaction_id]).unwrap();
+    assert_eq!(receiver_2.try_recv().unwrap().status, accepted);
+    drop(receiver_2);
+
+    // Roll back the block, and ensure the transactions are pending again.
+    ledger.rollback_to(height - 1).unwrap();
+    assert_eq!(ledger.transaction_status(&accepted_transaction_id).unwrap(), crate::TransactionStatus::Pending);
+    assert_eq!(receiver.try_recv().unwrap(), pending(accepted_transaction_id));
+    assert_eq!(receiver.try_recv().unwrap(), pending(aborted_transaction_id));
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn test_ledger_events() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Subscribe to the events of the ledger.
+    let receiver = ledger.subscribe_events();
+
+    // Deploy a program with a mapping, and transfer credits publicly, in the same block.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("test_ledger_events.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping counts:
+    key as address.public;
+    value as u64.public;
+function foo:
+    async foo self.caller into r0;
+    output r0 as {program_id}/foo.future;
+finalize foo:
+    input r0 as address.public;
+    set 1u64 into counts[r0];"
+    ))
+    .unwrap();
+    let deployment = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transfer = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![deployment.clone(), transfer.clone()],
+            rng,
+        )
+        .unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the events of the block are sent, and match the events retrieved from the ledger.
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    assert_eq!(events, ledger.get_block_events(block.height()).unwrap());
+    assert_eq!(events[0], crate::LedgerEvent::NewBlock(Box::new(block.clone())));
+
+    // Ensure the transactions are accepted.
+    let statuses = events
+        .iter()
+        .filter_map(|event| match event {
+            crate::LedgerEvent::Transaction { transaction_id, status } => Some((*transaction_id, status.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[0], (deployment.id(), ledger.transaction_status(&deployment.id()).unwrap()));
+    assert_eq!(statuses[1], (transfer.id(), ledger.transaction_status(&transfer.id()).unwrap()));
+
+    // Ensure the deployment is sent.
+    let height = block.height();
+    assert!(events.contains(&crate::LedgerEvent::Deployment { height, transaction_id: deployment.id(), program_id }));
+
+    // Ensure the mapping updates are decoded.
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let account = Identifier::from_str("account").unwrap();
+    let mut account_updates = Vec::new();
+    let mut ratified_updates = Vec::new();
+    for event in &events {
+        if let crate::LedgerEvent::MappingUpdate {
+            transaction_id,
+            program_id: event_program_id,
+            mapping_name,
+            operations,
+            ..
+        } = event
+        {
+            // Collect the mapping updates of the ratifications.
+            if transaction_id.is_none() {
+                ratified_updates.extend(operations.iter().map(|operation| operation.operation));
+                continue;
+            }
+            // Ensure the mapping of the deployment is initialized.
+            if *event_program_id == program_id {
+                assert_eq!(*mapping_name, Identifier::from_str("counts").unwrap());
+                assert!(operations.iter().all(|operation| operation.key.is_none() && operation.value.is_none()));
+            }
+            if (*event_program_id, *mapping_name) == (credits, account) {
+                account_updates.extend(
+                    operations.iter().map(|operation| (operation.key.clone().unwrap(), operation.value.clone())),
+                );
+            }
+        }
+    }
+    // Ensure the decoded balances end with the balances in the ledger.
+    // Note: The sender balance is updated for each fee and the transfer, and each of its values is decoded.
+    for key in [Plaintext::from(Literal::Address(address)), Plaintext::from(Literal::Address(recipient))] {
+        let value = ledger.vm.finalize_store().get_value_confirmed(credits, account, &key).unwrap();
+        assert!(value.is_some());
+        let updates = account_updates.iter().filter(|(update_key, _)| *update_key == key).collect::<Vec<_>>();
+        assert_eq!(updates.last().unwrap().1, value);
+        assert!(updates.iter().all(|(_, update_value)| update_value.is_some()));
+        assert!(updates.windows(2).all(|pair| pair[0].1 != pair[1].1));
+    }
+    assert_eq!(account_updates.iter().filter(|(key, _)| *key == Plaintext::from(Literal::Address(address))).count(), 3);
+
+    // Ensure the mapping updates of the ratifications are sent.
+    let ratified_operations = ledger.vm.finalize_store().get_ratified_operations(height).unwrap().unwrap();
+    assert!(!ratified_operations.is_empty());
+    assert_eq!(ratified_updates.len(), ratified_operations.len());
+    assert!(ratified_updates.iter().all(|operation| ratified_operations.contains(operation)));
+
+    // Roll back the block, and ensure the rollback event is sent, with the hash of the removed block.
+    ledger.rollback_to(height - 1).unwrap();
+    let rollback = crate::LedgerEvent::Rollback { height: height - 1, block_hashes: vec![block.hash()] };
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![rollback]);
+}
+
+#[test]
+fn test_get_mapping_operation_path() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample recipient.
+    let recipient_private_key = PrivateKey::new(rng).unwrap();
+    let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+    // Prepare the mapping keys for the public balances.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let mapping_name = Identifier::from_str("account").unwrap();
+    let key = Plaintext::from(Literal::Address(address));
+    let recipient_key = Plaintext::from(Literal::Address(recipient_address));
+
+    // Fund the recipient with 1 million credits.
+    let inputs =
+        [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("1000000000000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the operation writing the recipient's balance is proven against the finalize root of the block.
+    let operation_path = ledger.get_mapping_operation_path(1, program_id, mapping_name, &recipient_key).unwrap();
+    assert_eq!(operation_path.value(), &Value::from_str("1000000000000u64").unwrap());
+    operation_path.verify(block.finalize_root()).unwrap();
+    // Ensure the mapping operation path is *not* valid against the finalize root of another block.
+    operation_path.verify(ledger.genesis_block.finalize_root()).unwrap_err();
+    // Ensure no operation writing the recipient's balance can be proven in a block that did not write it.
+    ledger.get_mapping_operation_path(0, program_id, mapping_name, &recipient_key).unwrap_err();
+
+    // Ensure the operations writing the sender's balance are proven in the block, and in the genesis block.
+    let operation_path = ledger.get_mapping_operation_path(1, program_id, mapping_name, &key).unwrap();
+    let balance = ledger.vm().finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+    assert_eq!(Some(operation_path.value().clone()), balance);
+    operation_path.verify(block.finalize_root()).unwrap();
+    let genesis_operation_path = ledger.get_mapping_operation_path(0, program_id, mapping_name, &key).unwrap();
+    assert_ne!(genesis_operation_path.value(), operation_path.value());
+    genesis_operation_path.verify(ledger.genesis_block.finalize_root()).unwrap();
+
+    // Ensure the mapping operation path survives a round trip through its string representation.
+    let candidate = MappingOperationPath::<CurrentNetwork>::from_str(&operation_path.to_string()).unwrap();
+    assert_eq!(candidate, operation_path);
+
+    // Roll back the block, and ensure its mapping operation paths are no longer available.
+    ledger.rollback_to(0).unwrap();
+    ledger.get_mapping_operation_path(1, program_id, mapping_name, &recipient_key).unwrap_err();
+    assert!(ledger.vm().finalize_store().get_ratified_operations(1).unwrap().is_none());
+}
@@ -0,0 +1,408 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod selection;
+pub use selection::*;
+
+use crate::{Ledger, RecordScanner};
+use console::{
+    account::{Address, GraphKey, PrivateKey, ViewKey},
+    network::prelude::*,
+    program::{Entry, Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    types::Field,
+};
+use ledger_block::Transaction;
+use ledger_query::Query;
+use ledger_store::ConsensusStorage;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+
+/// The balance of a wallet, in microcredits.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Balance {
+    /// The total amount of the unspent `credits.aleo` records.
+    pub private: u64,
+    /// The public balance in the `credits.aleo/account` mapping.
+    pub public: u64,
+    /// The amount of the unspent records that are reserved by pending transactions.
+    pub pending_private: u64,
+    /// The amount of the public balance that is reserved by pending transactions.
+    pub pending_public: u64,
+}
+
+impl Balance {
+    /// Returns the amount of the unspent records that is not reserved by pending transactions.
+    pub const fn spendable_private(&self) -> u64 {
+        self.private.saturating_sub(self.pending_private)
+    }
+
+    /// Returns the amount of the public balance that is not reserved by pending transactions.
+    pub const fn spendable_public(&self) -> u64 {
+        self.public.saturating_sub(self.pending_public)
+    }
+}
+
+/// A transaction created by the wallet, towards a transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferStep<N: Network> {
+    /// A transaction that completes the transfer.
+    Transfer(Transaction<N>),
+    /// A transaction that joins two records, which must be confirmed before the transfer can proceed.
+    Join(Transaction<N>),
+    /// A transaction that splits a record, which must be confirmed before the transfer can proceed.
+    Split(Transaction<N>),
+}
+
+impl<N: Network> TransferStep<N> {
+    /// Returns the transaction.
+    pub const fn transaction(&self) -> &Transaction<N> {
+        match self {
+            Self::Transfer(transaction) | Self::Join(transaction) | Self::Split(transaction) => transaction,
+        }
+    }
+
+    /// Returns `true` if the transaction completes the transfer.
+    pub const fn is_transfer(&self) -> bool {
+        matches!(self, Self::Transfer(..))
+    }
+}
+
+/// The funds that a pending transaction spends.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct PendingSpend<N: Network> {
+    /// The commitments of the records spent by the transaction.
+    commitments: Vec<Field<N>>,
+    /// The amount of the public balance reserved by the transaction.
+    public_amount: u64,
+}
+
+/// A wallet for the `credits.aleo` funds of an account, which tracks the unspent records, the public balance,
+/// and the funds spent by pending transactions.
+///
+/// The wallet finds its records with a `RecordScanner`, so that each `sync` only scans the new blocks.
+pub struct Wallet<N: Network> {
+    /// The private key of the account.
+    private_key: PrivateKey<N>,
+    /// The view key of the account.
+    view_key: ViewKey<N>,
+    /// The address of the account.
+    address: Address<N>,
+    /// The `sk_tag` of the account, used to determine if a record is spent.
+    sk_tag: Field<N>,
+    /// The record scanner of the account.
+    scanner: RecordScanner<N>,
+    /// The unspent `credits.aleo` records, along with their microcredits.
+    #[allow(clippy::type_complexity)]
+    records: IndexMap<Field<N>, (Record<N, Plaintext<N>>, u64)>,
+    /// The public balance of the account.
+    public_balance: u64,
+    /// The funds spent by the pending transactions.
+    pending: IndexMap<N::TransactionID, PendingSpend<N>>,
+}
+
+impl<N: Network> Wallet<N> {
+    /// Initializes a new wallet for the given private key, which finds records from the given height onwards.
+    pub fn new(private_key: PrivateKey<N>, start_height: u32) -> Result<Self> {
+        let mut scanner = RecordScanner::new();
+        scanner.register(Address::try_from(&private_key)?, start_height);
+        Self::from_scanner(private_key, scanner)
+    }
+
+    /// Initializes a new wallet for the given private key, which resumes from the given record scanner.
+    /// The records found by the scanner are loaded on the next `sync`.
+    pub fn from_scanner(private_key: PrivateKey<N>, mut scanner: RecordScanner<N>) -> Result<Self> {
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = view_key.to_address();
+        let sk_tag = GraphKey::try_from(&view_key)?.sk_tag();
+        scanner.register(address, 0);
+        Ok(Self {
+            private_key,
+            view_key,
+            address,
+            sk_tag,
+            scanner,
+            records: Default::default(),
+            public_balance: 0,
+            pending: Default::default(),
+        })
+    }
+
+    /// Returns the address of the account.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the record scanner of the account, which may be saved to resume the wallet later.
+    pub const fn scanner(&self) -> &RecordScanner<N> {
+        &self.scanner
+    }
+
+    /// Returns the unspent `credits.aleo` records.
+    pub fn records(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Plaintext<N>>)> {
+        self.records.iter().map(|(commitment, (record, _))| (commitment, record))
+    }
+
+    /// Returns the IDs of the pending transactions.
+    pub fn pending_transactions(&self) -> impl '_ + Iterator<Item = &N::TransactionID> {
+        self.pending.keys()
+    }
+
+    /// Returns the balance of the wallet.
+    pub fn balance(&self) -> Balance {
+        let pending_records = self.pending.values().flat_map(|pending| &pending.commitments);
+        Balance {
+            private: self.records.values().map(|(_, amount)| *amount).fold(0, u64::saturating_add),
+            public: self.public_balance,
+            pending_private: pending_records
+                .filter_map(|commitment| self.records.get(commitment).map(|(_, amount)| *amount))
+                .fold(0, u64::saturating_add),
+            pending_public: self.pending.values().map(|pending| pending.public_amount).fold(0, u64::saturating_add),
+        }
+    }
+
+    /// Releases the funds reserved by the given pending transaction, e.g. if it was dropped.
+    pub fn release(&mut self, transaction_id: &N::TransactionID) -> bool {
+        self.pending.shift_remove(transaction_id).is_some()
+    }
+
+    /// Synchronizes the wallet with the given ledger.
+    ///
+    /// The new blocks are scanned for records, the spent records (and those that were rolled back) are removed,
+    /// the public balance is refreshed, and the pending transactions that are accepted, rejected, or aborted
+    /// (or whose records are spent) are released.
+    pub fn sync<C: ConsensusStorage<N>>(&mut self, ledger: &Ledger<N, C>) -> Result<()> {
+        let credits = ProgramID::from_str("credits.aleo")?;
+
+        // Scan the new blocks for records.
+        ledger.scan_records(&mut self.scanner, &[self.view_key])?;
+
+        // Retrieve the cursor of the wallet.
+        let Some(cursor) = self.scanner.get(&self.address) else {
+            bail!("The wallet is not registered in its scanner")
+        };
+        // Drop the records that are no longer in the ledger, e.g. after a rollback.
+        self.records.retain(|commitment, _| cursor.commitments().contains_key(commitment));
+
+        // Load the new `credits.aleo` records.
+        for commitment in cursor.commitments().keys() {
+            if self.records.contains_key(commitment) {
+                continue;
+            }
+            // Ensure the record is output by a `credits.aleo` transition.
+            let transition_id = ledger.find_transition_id(commitment)?;
+            if ledger.vm().transition_store().get_program_id(&transition_id)? != Some(credits) {
+                continue;
+            }
+            // Decrypt the record, and retrieve its microcredits.
+            let Some(record) = ledger.vm().transition_store().get_record(commitment)? else {
+                bail!("Missing record for commitment '{commitment}'")
+            };
+            let record = record.decrypt(&self.view_key)?;
+            if let Some(amount) = microcredits(&record)? {
+                self.records.insert(*commitment, (record, amount));
+            }
+        }
+
+        // Remove the spent records.
+        let mut spent = Vec::new();
+        for commitment in self.records.keys() {
+            if ledger.contains_tag(&Record::<N, Plaintext<N>>::tag(self.sk_tag, *commitment)?)? {
+                spent.push(*commitment);
+            }
+        }
+        for commitment in &spent {
+            self.records.shift_remove(commitment);
+        }
+
+        // Refresh the public balance.
+        let key = Plaintext::from(Literal::Address(self.address));
+        self.public_balance =
+            match ledger.vm().finalize_store().get_value_confirmed(credits, Identifier::from_str("account")?, &key)? {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                Some(value) => bail!("Invalid public balance '{value}' for '{}'", self.address),
+                None => 0,
+            };
+
+        // Release the pending transactions that are in a block (as accepted, rejected, or aborted),
+        // or whose records are spent. Note: The records of a rejected or aborted transaction, besides its fee record,
+        // remain unspent, and become spendable again.
+        let mut released = Vec::new();
+        for (transaction_id, pending) in &self.pending {
+            if !ledger.transaction_status(transaction_id)?.is_pending()
+                || pending.commitments.iter().any(|commitment| spent.contains(commitment))
+            {
+                released.push(*transaction_id);
+            }
+        }
+        for transaction_id in &released {
+            self.pending.shift_remove(transaction_id);
+        }
+        Ok(())
+    }
+
+    /// Creates the next transaction towards a private transfer of the given amount to the given address,
+    /// reserving up to `max_fee_in_microcredits` for the fee.
+    ///
+    /// If the transfer needs the records to be joined or split first, that transaction is returned instead,
+    /// and this method should be called again once it is confirmed (and the wallet is synchronized).
+    /// The records spent by the returned transaction are reserved until it is in a block or released.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prepare_transfer_private<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        ledger: &Ledger<N, C>,
+        to: Address<N>,
+        amount_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        max_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<TransferStep<N>> {
+        // Select the records, from the records that are not reserved by pending transactions.
+        let reserved = self.pending.values().flat_map(|pending| &pending.commitments).collect::<Vec<_>>();
+        let records = self
+            .records
+            .iter()
+            .filter(|(commitment, _)| !reserved.contains(commitment))
+            .map(|(commitment, (_, amount))| (*commitment, *amount))
+            .collect::<IndexMap<_, _>>();
+        let balance = self.balance();
+        let selection =
+            select_records(&records, amount_in_microcredits, max_fee_in_microcredits, balance.spendable_public())?;
+
+        // Returns the record with the given commitment.
+        let record = |commitment: &Field<N>| match self.records.get(commitment) {
+            Some((record, _)) => Ok(record.clone()),
+            None => bail!("Missing record for commitment '{commitment}'"),
+        };
+        // Returns the fee record and the reserved public amount, for the given fee source.
+        let fee = |fee: &FeeSource<N>| -> Result<_> {
+            match fee {
+                FeeSource::Public => Ok((None, max_fee_in_microcredits)),
+                FeeSource::Private(commitment) => Ok((Some(record(commitment)?), 0)),
+            }
+        };
+
+        // Create the transaction.
+        let (step, commitments, public_amount) = match selection {
+            CoinSelection::Transfer { record: commitment, fee: source } => {
+                let inputs = [
+                    Value::Record(record(&commitment)?),
+                    Value::from_str(&format!("{to}"))?,
+                    Value::from_str(&format!("{amount_in_microcredits}u64"))?,
+                ];
+                let (fee_record, public_amount) = fee(&source)?;
+                let transaction = ledger.vm().execute(
+                    &self.private_key,
+                    ("credits.aleo", "transfer_private"),
+                    inputs.iter(),
+                    fee_record,
+                    priority_fee_in_microcredits,
+                    query,
+                    rng,
+                )?;
+                (TransferStep::Transfer(transaction), fee_commitments(&[commitment], &source), public_amount)
+            }
+            CoinSelection::Join { first, second, fee: source } => {
+                let inputs = [Value::Record(record(&first)?), Value::Record(record(&second)?)];
+                let (fee_record, public_amount) = fee(&source)?;
+                let transaction = ledger.vm().execute(
+                    &self.private_key,
+                    ("credits.aleo", "join"),
+                    inputs.iter(),
+                    fee_record,
+                    priority_fee_in_microcredits,
+                    query,
+                    rng,
+                )?;
+                (TransferStep::Join(transaction), fee_commitments(&[first, second], &source), public_amount)
+            }
+            CoinSelection::Split { record: commitment, amount } => {
+                let inputs = [Value::Record(record(&commitment)?), Value::from_str(&format!("{amount}u64"))?];
+                // Note: A split does not require a fee.
+                let transaction = ledger.vm().execute(
+                    &self.private_key,
+                    ("credits.aleo", "split"),
+                    inputs.iter(),
+                    None,
+                    0,
+                    query,
+                    rng,
+                )?;
+                (TransferStep::Split(transaction), vec![commitment], 0)
+            }
+        };
+
+        // Reserve the spent funds.
+        self.pending.insert(step.transaction().id(), PendingSpend { commitments, public_amount });
+        Ok(step)
+    }
+
+    /// Creates a transaction that transfers the given amount from the public balance to a record for the given
+    /// address, reserving up to `max_fee_in_microcredits` of the public balance for the fee.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prepare_transfer_public_to_private<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        ledger: &Ledger<N, C>,
+        to: Address<N>,
+        amount_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        max_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the public balance covers the amount and the fee.
+        let required = amount_in_microcredits
+            .checked_add(max_fee_in_microcredits)
+            .ok_or_else(|| anyhow!("The amount and fee overflowed"))?;
+        let spendable = self.balance().spendable_public();
+        ensure!(
+            spendable >= required,
+            "Insufficient public balance ({spendable} microcredits) to spend {required} microcredits"
+        );
+
+        // Create the transaction.
+        let inputs = [Value::from_str(&format!("{to}"))?, Value::from_str(&format!("{amount_in_microcredits}u64"))?];
+        let transaction = ledger.vm().execute(
+            &self.private_key,
+            ("credits.aleo", "transfer_public_to_private"),
+            inputs.iter(),
+            None,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )?;
+
+        // Reserve the spent funds.
+        self.pending.insert(transaction.id(), PendingSpend { commitments: vec![], public_amount: required });
+        Ok(transaction)
+    }
+}
+
+/// Returns the given commitments, along with the commitment of the fee record (if any).
+fn fee_commitments<N: Network>(commitments: &[Field<N>], fee: &FeeSource<N>) -> Vec<Field<N>> {
+    let mut commitments = commitments.to_vec();
+    if let FeeSource::Private(commitment) = fee {
+        commitments.push(*commitment);
+    }
+    commitments
+}
+
+/// Returns the microcredits of the given record, if it is a `credits.aleo` record.
+fn microcredits<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<Option<u64>> {
+    match record.data().get(&Identifier::from_str("microcredits")?) {
+        Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => Ok(Some(**amount)),
+        _ => Ok(None),
+    }
+}
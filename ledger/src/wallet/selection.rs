@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+
+use anyhow::Result;
+use indexmap::IndexMap;
+
+/// The amount in microcredits that the `credits.aleo/split` function deducts from the split record.
+pub const SPLIT_FEE_IN_MICROCREDITS: u64 = 10_000;
+
+/// The source of the fee for a transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FeeSource<N: Network> {
+    /// The fee is paid from the public balance.
+    Public,
+    /// The fee is paid from the record with the given commitment.
+    Private(Field<N>),
+}
+
+/// The next step towards spending a target amount from a set of records.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CoinSelection<N: Network> {
+    /// Spend the amount from the given record, paying the fee from the given source.
+    Transfer { record: Field<N>, fee: FeeSource<N> },
+    /// Join the given records (as no single record covers the amount), paying the fee from the given source.
+    Join { first: Field<N>, second: Field<N>, fee: FeeSource<N> },
+    /// Split the amount from the given record (as no other record covers the fee), which requires no fee.
+    Split { record: Field<N>, amount: u64 },
+}
+
+/// Selects the records to spend the given amount from the given records (mapping commitments to microcredits),
+/// reserving up to `max_fee` microcredits for the fee, from the public balance if it suffices.
+///
+/// A single record is preferred, and the smallest one that covers the amount is selected, to limit fragmentation.
+/// If no single record covers the amount, the two largest records are joined first. If the only record that covers
+/// the amount must also cover the fee, it is split first. Each `Join` or `Split` must be confirmed before the
+/// next selection, as its output record can only be spent once it is in the ledger.
+pub fn select_records<N: Network>(
+    records: &IndexMap<Field<N>, u64>,
+    amount: u64,
+    max_fee: u64,
+    public_balance: u64,
+) -> Result<CoinSelection<N>> {
+    ensure!(amount > 0, "The amount to spend must be greater than zero");
+
+    // Sort the records by amount, in ascending order.
+    let mut records = records.iter().map(|(commitment, value)| (*commitment, *value)).collect::<Vec<_>>();
+    records.sort_by_key(|(_, value)| *value);
+    // Compute the total amount of the records.
+    let total = records.iter().try_fold(0u64, |total, (_, value)| total.checked_add(*value));
+    let total = total.ok_or_else(|| anyhow!("The total amount of the records overflowed"))?;
+
+    // Returns the smallest record that covers the given amount, excluding the given record.
+    let smallest_covering = |amount: u64, excluded: Option<Field<N>>| {
+        records.iter().find(|(commitment, value)| *value >= amount && Some(*commitment) != excluded).map(|(c, _)| *c)
+    };
+    // Returns the two largest records, excluding the given record.
+    let two_largest = |excluded: Option<Field<N>>| {
+        let mut largest = records.iter().rev().filter(|(commitment, _)| Some(*commitment) != excluded);
+        match (largest.next(), largest.next()) {
+            (Some((first, _)), Some((second, _))) => Some((*first, *second)),
+            _ => None,
+        }
+    };
+
+    // If the public balance covers the fee, pay the fee publicly.
+    if public_balance >= max_fee {
+        // Select the smallest record that covers the amount.
+        if let Some(record) = smallest_covering(amount, None) {
+            return Ok(CoinSelection::Transfer { record, fee: FeeSource::Public });
+        }
+        // Otherwise, join the two largest records.
+        if total >= amount {
+            if let Some((first, second)) = two_largest(None) {
+                return Ok(CoinSelection::Join { first, second, fee: FeeSource::Public });
+            }
+        }
+        bail!("Insufficient records to spend {amount} microcredits (the records hold {total} microcredits)")
+    }
+
+    // Otherwise, select the smallest record that covers the amount, along with a separate record for the fee.
+    for (record, _) in records.iter().filter(|(_, value)| *value >= amount) {
+        if let Some(fee_record) = smallest_covering(max_fee, Some(*record)) {
+            return Ok(CoinSelection::Transfer { record: *record, fee: FeeSource::Private(fee_record) });
+        }
+    }
+    // Otherwise, split a record that covers both the amount and the fee.
+    let required = amount.saturating_add(max_fee).saturating_add(SPLIT_FEE_IN_MICROCREDITS);
+    if let Some(record) = smallest_covering(required, None) {
+        return Ok(CoinSelection::Split { record, amount });
+    }
+    // Otherwise, join the two largest records that remain after selecting a record for the fee.
+    if let Some(fee_record) = smallest_covering(max_fee, None) {
+        let fee_value = records.iter().find(|(commitment, _)| *commitment == fee_record).map_or(0, |(_, value)| *value);
+        if total - fee_value >= amount {
+            if let Some((first, second)) = two_largest(Some(fee_record)) {
+                return Ok(CoinSelection::Join { first, second, fee: FeeSource::Private(fee_record) });
+            }
+        }
+    }
+    bail!("Insufficient records to spend {amount} microcredits and a fee of up to {max_fee} microcredits")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the records with the given amounts, where the commitment of each record is its index.
+    fn sample_records(amounts: &[u64]) -> IndexMap<Field<CurrentNetwork>, u64> {
+        (0u64..).zip(amounts).map(|(index, amount)| (Field::from_u64(index), *amount)).collect()
+    }
+
+    #[test]
+    fn test_select_records_with_public_fee() {
+        let records = sample_records(&[50, 300, 100, 200]);
+
+        // Ensure the smallest record that covers the amount is selected.
+        let selection = select_records(&records, 150, 10, 10).unwrap();
+        assert_eq!(selection, CoinSelection::Transfer { record: Field::from_u64(3), fee: FeeSource::Public });
+        let selection = select_records(&records, 300, 10, 10).unwrap();
+        assert_eq!(selection, CoinSelection::Transfer { record: Field::from_u64(1), fee: FeeSource::Public });
+
+        // Ensure the two largest records are joined, if no single record covers the amount.
+        let selection = select_records(&records, 450, 10, 10).unwrap();
+        let expected =
+            CoinSelection::Join { first: Field::from_u64(1), second: Field::from_u64(3), fee: FeeSource::Public };
+        assert_eq!(selection, expected);
+
+        // Ensure the selection fails if the records do not cover the amount.
+        assert!(select_records(&records, 651, 10, 10).is_err());
+        assert!(select_records(&records, 0, 10, 10).is_err());
+        assert!(select_records(&sample_records(&[]), 1, 10, 10).is_err());
+    }
+
+    #[test]
+    fn test_select_records_with_private_fee() {
+        let records = sample_records(&[50, 300, 100, 200]);
+
+        // Ensure a separate record is selected for the fee.
+        let selection = select_records(&records, 150, 60, 0).unwrap();
+        let expected =
+            CoinSelection::Transfer { record: Field::from_u64(3), fee: FeeSource::Private(Field::from_u64(2)) };
+        assert_eq!(selection, expected);
+        let selection = select_records(&records, 200, 250, 0).unwrap();
+        let expected =
+            CoinSelection::Transfer { record: Field::from_u64(3), fee: FeeSource::Private(Field::from_u64(1)) };
+        assert_eq!(selection, expected);
+
+        // Ensure the only record that covers the amount is skipped as the fee record.
+        let records = sample_records(&[20_000, 100_000]);
+        let selection = select_records(&records, 50_000, 10_000, 0).unwrap();
+        let expected =
+            CoinSelection::Transfer { record: Field::from_u64(1), fee: FeeSource::Private(Field::from_u64(0)) };
+        assert_eq!(selection, expected);
+
+        // Ensure a single record is split, if it must cover both the amount and the fee.
+        let records = sample_records(&[100_000]);
+        let selection = select_records(&records, 50_000, 10_000, 0).unwrap();
+        assert_eq!(selection, CoinSelection::Split { record: Field::from_u64(0), amount: 50_000 });
+        assert!(select_records(&records, 85_000, 10_000, 0).is_err());
+
+        // Ensure the records are joined, excluding the fee record, if no single record covers the amount.
+        let records = sample_records(&[30_000, 40_000, 10_000]);
+        let selection = select_records(&records, 60_000, 10_000, 0).unwrap();
+        let expected = CoinSelection::Join {
+            first: Field::from_u64(1),
+            second: Field::from_u64(0),
+            fee: FeeSource::Private(Field::from_u64(2)),
+        };
+        assert_eq!(selection, expected);
+        assert!(select_records(&records, 70_001, 10_000, 0).is_err());
+    }
+}
@@ -0,0 +1,384 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    atomic_batch_scope,
+    cow_to_cloned,
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
+    to_key_id,
+    to_mapping_id,
+};
+use console::{
+    network::prelude::*,
+    program::{Argument, Future, Identifier, Literal, Plaintext, ProgramID},
+    types::{Address, Field},
+};
+use ledger_block::{Block, ConfirmedTransaction, Input, Output, Transition};
+use synthesizer_program::FinalizeOperation;
+
+use aleo_std_storage::StorageMode;
+use anyhow::Result;
+use core::marker::PhantomData;
+use indexmap::IndexSet;
+
+/// The address index entries of a block, as the
+/// (`(address, transition ID)` entries, `(address, transaction ID)` entries, `(address, program ID)` entries).
+pub type AddressEntries<N> = (
+    Vec<(Address<N>, <N as Network>::TransitionID)>,
+    Vec<(Address<N>, <N as Network>::TransactionID)>,
+    Vec<(Address<N>, ProgramID<N>)>,
+);
+
+/// A trait for address index storage.
+pub trait AddressStorage<N: Network>: Clone + Send + Sync {
+    /// The mapping of `address` to `[(transition ID, block height)]`, for transitions that reference the address
+    /// in a public input, public output, or future.
+    type TransitionsMap: for<'a> NestedMap<'a, Address<N>, N::TransitionID, u32>;
+    /// The mapping of `address` to `[(transaction ID, block height)]`, for transactions that wrote to the
+    /// `credits.aleo/account` mapping of the address.
+    /// Note: Only the addresses that the transaction references in a public input, public output, or future
+    /// are recorded, and the writes of the ratifications (e.g. the block rewards) are not recorded.
+    type AccountsMap: for<'a> NestedMap<'a, Address<N>, N::TransactionID, u32>;
+    /// The mapping of `owner address` to `[(program ID, block height)]`, for programs deployed by the owner.
+    type ProgramsMap: for<'a> NestedMap<'a, Address<N>, ProgramID<N>, u32>;
+    /// The mapping of `block height` to the address index entries of the block.
+    type EntriesMap: for<'a> Map<'a, u32, AddressEntries<N>>;
+
+    /// Initializes the address storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
+
+    /// Returns the transitions map.
+    fn transitions_map(&self) -> &Self::TransitionsMap;
+    /// Returns the accounts map.
+    fn accounts_map(&self) -> &Self::AccountsMap;
+    /// Returns the programs map.
+    fn programs_map(&self) -> &Self::ProgramsMap;
+    /// Returns the entries map.
+    fn entries_map(&self) -> &Self::EntriesMap;
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode;
+
+    /// Starts an atomic batch write operation.
+    fn start_atomic(&self) {
+        self.transitions_map().start_atomic();
+        self.accounts_map().start_atomic();
+        self.programs_map().start_atomic();
+        self.entries_map().start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    fn is_atomic_in_progress(&self) -> bool {
+        self.transitions_map().is_atomic_in_progress()
+            || self.accounts_map().is_atomic_in_progress()
+            || self.programs_map().is_atomic_in_progress()
+            || self.entries_map().is_atomic_in_progress()
+    }
+
+    /// Checkpoints the atomic batch.
+    fn atomic_checkpoint(&self) {
+        self.transitions_map().atomic_checkpoint();
+        self.accounts_map().atomic_checkpoint();
+        self.programs_map().atomic_checkpoint();
+        self.entries_map().atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    fn clear_latest_checkpoint(&self) {
+        self.transitions_map().clear_latest_checkpoint();
+        self.accounts_map().clear_latest_checkpoint();
+        self.programs_map().clear_latest_checkpoint();
+        self.entries_map().clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the previous checkpoint.
+    fn atomic_rewind(&self) {
+        self.transitions_map().atomic_rewind();
+        self.accounts_map().atomic_rewind();
+        self.programs_map().atomic_rewind();
+        self.entries_map().atomic_rewind();
+    }
+
+    /// Aborts an atomic batch write operation.
+    fn abort_atomic(&self) {
+        self.transitions_map().abort_atomic();
+        self.accounts_map().abort_atomic();
+        self.programs_map().abort_atomic();
+        self.entries_map().abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    fn finish_atomic(&self) -> Result<()> {
+        self.transitions_map().finish_atomic()?;
+        self.accounts_map().finish_atomic()?;
+        self.programs_map().finish_atomic()?;
+        self.entries_map().finish_atomic()
+    }
+
+    /// Stores the address index entries of the given block.
+    fn insert(&self, block: &Block<N>) -> Result<()> {
+        // Compute the address index entries of the block.
+        let (transitions, transactions, programs) = to_address_entries(block)?;
+
+        atomic_batch_scope!(self, {
+            // Store the transition entries.
+            for (address, transition_id) in &transitions {
+                self.transitions_map().insert(*address, *transition_id, block.height())?;
+            }
+            // Store the account entries.
+            for (address, transaction_id) in &transactions {
+                self.accounts_map().insert(*address, *transaction_id, block.height())?;
+            }
+            // Store the program entries.
+            for (address, program_id) in &programs {
+                self.programs_map().insert(*address, *program_id, block.height())?;
+            }
+            // Store the entries of the block.
+            self.entries_map().insert(block.height(), (transitions, transactions, programs))?;
+
+            Ok(())
+        })
+    }
+
+    /// Removes the address index entries of the block at the given `block height`.
+    fn remove(&self, height: u32) -> Result<()> {
+        // Retrieve the entries of the block.
+        let (transitions, transactions, programs) = match self.entries_map().get_confirmed(&height)? {
+            Some(entries) => cow_to_cloned!(entries),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the transition entries.
+            for (address, transition_id) in &transitions {
+                self.transitions_map().remove_key(address, transition_id)?;
+            }
+            // Remove the account entries.
+            for (address, transaction_id) in &transactions {
+                self.accounts_map().remove_key(address, transaction_id)?;
+            }
+            // Remove the program entries.
+            for (address, program_id) in &programs {
+                self.programs_map().remove_key(address, program_id)?;
+            }
+            // Remove the entries of the block.
+            self.entries_map().remove(&height)?;
+
+            Ok(())
+        })
+    }
+}
+
+/// The address index store.
+#[derive(Clone)]
+pub struct AddressStore<N: Network, A: AddressStorage<N>> {
+    /// The address storage.
+    storage: A,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network, A: AddressStorage<N>> AddressStore<N, A> {
+    /// Initializes the address store.
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self { storage: A::open(storage)?, _phantom: PhantomData })
+    }
+
+    /// Initializes an address store from storage.
+    pub fn from(storage: A) -> Self {
+        Self { storage, _phantom: PhantomData }
+    }
+
+    /// Stores the address index entries of the given block.
+    pub fn insert(&self, block: &Block<N>) -> Result<()> {
+        self.storage.insert(block)
+    }
+
+    /// Removes the address index entries of the block at the given `block height`.
+    pub fn remove(&self, height: u32) -> Result<()> {
+        self.storage.remove(height)
+    }
+
+    /// Starts an atomic batch write operation.
+    pub fn start_atomic(&self) {
+        self.storage.start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    pub fn is_atomic_in_progress(&self) -> bool {
+        self.storage.is_atomic_in_progress()
+    }
+
+    /// Checkpoints the atomic batch.
+    pub fn atomic_checkpoint(&self) {
+        self.storage.atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    pub fn clear_latest_checkpoint(&self) {
+        self.storage.clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the previous checkpoint.
+    pub fn atomic_rewind(&self) {
+        self.storage.atomic_rewind();
+    }
+
+    /// Aborts an atomic batch write operation.
+    pub fn abort_atomic(&self) {
+        self.storage.abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    pub fn finish_atomic(&self) -> Result<()> {
+        self.storage.finish_atomic()
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+}
+
+impl<N: Network, A: AddressStorage<N>> AddressStore<N, A> {
+    /// Returns the `(transition ID, block height)` pairs of the transitions that reference the given address
+    /// in a public input, public output, or future, in order of block height.
+    pub fn get_transitions(&self, address: &Address<N>) -> Result<Vec<(N::TransitionID, u32)>> {
+        let mut transitions = self.storage.transitions_map().get_map_confirmed(address)?;
+        transitions.sort_by_key(|(_, height)| *height);
+        Ok(transitions)
+    }
+
+    /// Returns the `(transaction ID, block height)` pairs of the transactions that wrote to
+    /// the `credits.aleo/account` mapping of the given address, in order of block height.
+    /// Note: Only the transactions that reference the address in a public input, public output, or future
+    /// are returned, and the writes of the ratifications (e.g. the block rewards) are not returned.
+    pub fn get_account_transactions(&self, address: &Address<N>) -> Result<Vec<(N::TransactionID, u32)>> {
+        let mut transactions = self.storage.accounts_map().get_map_confirmed(address)?;
+        transactions.sort_by_key(|(_, height)| *height);
+        Ok(transactions)
+    }
+
+    /// Returns the `(program ID, block height)` pairs of the programs deployed by the given owner,
+    /// in order of block height.
+    pub fn get_programs(&self, owner: &Address<N>) -> Result<Vec<(ProgramID<N>, u32)>> {
+        let mut programs = self.storage.programs_map().get_map_confirmed(owner)?;
+        programs.sort_by_key(|(_, height)| *height);
+        Ok(programs)
+    }
+}
+
+/// Returns the address index entries of the given block.
+fn to_address_entries<N: Network>(block: &Block<N>) -> Result<AddressEntries<N>> {
+    // Compute the ID of the `credits.aleo/account` mapping.
+    let credits_id = ProgramID::<N>::from_str("credits.aleo")?;
+    let account_name = Identifier::<N>::from_str("account")?;
+    let account_mapping_id = to_mapping_id(&credits_id, &account_name)?;
+
+    let mut transitions = Vec::new();
+    let mut transactions = Vec::new();
+    let mut programs = Vec::new();
+
+    for confirmed in block.transactions().iter() {
+        let transaction = confirmed.transaction();
+
+        // Collect the addresses that are publicly referenced by the transaction, as candidates for account writes.
+        let mut candidate_addresses = IndexSet::new();
+        for transition in transaction.transitions() {
+            // Index the addresses that are publicly referenced by the transition.
+            for address in public_addresses(transition) {
+                transitions.push((address, *transition.id()));
+                candidate_addresses.insert(address);
+            }
+        }
+
+        // Index the candidate addresses whose `credits.aleo/account` entry was written by the transaction.
+        let account_key_ids = account_key_ids(confirmed, account_mapping_id);
+        for address in candidate_addresses {
+            let key_id = to_key_id(&credits_id, &account_name, &Plaintext::from(Literal::Address(address)))?;
+            if account_key_ids.contains(&key_id) {
+                transactions.push((address, transaction.id()));
+            }
+        }
+
+        // Index the owner of a deployed program.
+        if let (ConfirmedTransaction::AcceptedDeploy(..), Some(owner), Some(deployment)) =
+            (confirmed, transaction.owner(), transaction.deployment())
+        {
+            programs.push((owner.address(), *deployment.program_id()));
+        }
+    }
+
+    Ok((transitions, transactions, programs))
+}
+
+/// Returns the addresses in the public inputs, public outputs, and futures of the given transition.
+fn public_addresses<N: Network>(transition: &Transition<N>) -> IndexSet<Address<N>> {
+    let mut addresses = IndexSet::new();
+    for input in transition.inputs() {
+        if let Input::Public(_, Some(plaintext)) = input {
+            collect_plaintext_addresses(plaintext, &mut addresses);
+        }
+    }
+    for output in transition.outputs() {
+        match output {
+            Output::Public(_, Some(plaintext)) => collect_plaintext_addresses(plaintext, &mut addresses),
+            Output::Future(_, Some(future)) => collect_future_addresses(future, &mut addresses),
+            _ => (),
+        }
+    }
+    addresses
+}
+
+/// Adds the addresses in the given plaintext to `addresses`.
+fn collect_plaintext_addresses<N: Network>(plaintext: &Plaintext<N>, addresses: &mut IndexSet<Address<N>>) {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(address), _) => {
+            addresses.insert(*address);
+        }
+        Plaintext::Literal(..) => (),
+        Plaintext::Struct(members, _) => {
+            members.values().for_each(|member| collect_plaintext_addresses(member, addresses));
+        }
+        Plaintext::Array(elements, _) => {
+            elements.iter().for_each(|element| collect_plaintext_addresses(element, addresses));
+        }
+    }
+}
+
+/// Adds the addresses in the arguments of the given future (and its nested futures) to `addresses`.
+fn collect_future_addresses<N: Network>(future: &Future<N>, addresses: &mut IndexSet<Address<N>>) {
+    for argument in future.arguments() {
+        match argument {
+            Argument::Plaintext(plaintext) => collect_plaintext_addresses(plaintext, addresses),
+            Argument::Future(future) => collect_future_addresses(future, addresses),
+        }
+    }
+}
+
+/// Returns the key IDs that were inserted or updated in the given mapping by the given transaction.
+fn account_key_ids<N: Network>(confirmed: &ConfirmedTransaction<N>, mapping_id: Field<N>) -> IndexSet<Field<N>> {
+    confirmed
+        .finalize_operations()
+        .iter()
+        .filter_map(|operation| match operation {
+            FinalizeOperation::InsertKeyValue(id, key_id, _) | FinalizeOperation::UpdateKeyValue(id, key_id, _)
+                if *id == mapping_id =>
+            {
+                Some(*key_id)
+            }
+            _ => None,
+        })
+        .collect()
+}
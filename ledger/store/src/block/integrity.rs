@@ -0,0 +1,416 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// An inconsistency found between the maps of the block storage, or between the storage and the finalize state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegrityIssue<N: Network> {
+    /// The block hash at the given height is missing, although a later block exists.
+    MissingBlockHash { height: u32 },
+    /// The block hash at the given height is not mirrored in the reverse block hash map.
+    MissingReverseBlockHash { height: u32, block_hash: N::BlockHash },
+    /// The reverse block hash map contains an entry that is not mirrored in the block hash map.
+    DanglingReverseBlockHash { block_hash: N::BlockHash, height: u32 },
+    /// The state root at the given height is not mirrored in the reverse state root map.
+    MissingReverseStateRoot { height: u32, state_root: N::StateRoot },
+    /// The reverse state root map contains an entry that is not mirrored in the state root map.
+    DanglingReverseStateRoot { state_root: N::StateRoot, height: u32 },
+    /// The header of the given block is missing.
+    MissingHeader { block_hash: N::BlockHash },
+    /// The transaction IDs of the given block are missing.
+    MissingBlockTransactions { block_hash: N::BlockHash },
+    /// A transaction ID of the given block does not resolve in the transaction store.
+    MissingTransaction { block_hash: N::BlockHash, transaction_id: N::TransactionID },
+    /// A transaction ID of the given block does not resolve to a confirmed transaction in the given block.
+    MissingConfirmedTransaction { block_hash: N::BlockHash, transaction_id: N::TransactionID },
+    /// The confirmed transaction map contains a transaction that does not belong to the block it references.
+    DanglingConfirmedTransaction { block_hash: N::BlockHash, transaction_id: N::TransactionID },
+    /// The transaction store contains a transaction that does not belong to any block.
+    DanglingTransaction { transaction_id: N::TransactionID },
+    /// The root of the block tree does not match the stored state root of the latest block.
+    StateRootMismatch { height: u32, expected: N::StateRoot, found: N::StateRoot },
+    /// The ratified finalize operations of the block at the given height are missing.
+    MissingRatifiedOperations { height: u32 },
+    /// The ratified finalize operations of the blocks before the given height are unavailable, as those blocks
+    /// were finalized before the ratified finalize operations were stored.
+    RatifiedOperationsUnavailable { before: u32 },
+    /// The finalize root recomputed from the stored finalize operations of the given block does not match
+    /// the finalize root in its header.
+    FinalizeRootMismatch { height: u32, expected: Field<N>, found: Field<N> },
+}
+
+impl<N: Network> IntegrityIssue<N> {
+    /// Returns `true` if the issue is a dangling or unmirrored entry, which may be repaired in place.
+    pub const fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingReverseBlockHash { .. }
+                | Self::DanglingReverseBlockHash { .. }
+                | Self::MissingReverseStateRoot { .. }
+                | Self::DanglingReverseStateRoot { .. }
+                | Self::DanglingConfirmedTransaction { .. }
+                | Self::DanglingTransaction { .. }
+        )
+    }
+}
+
+impl<N: Network> Display for IntegrityIssue<N> {
+    /// Displays the integrity issue.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingBlockHash { height } => write!(f, "Missing block hash for block {height}"),
+            Self::MissingReverseBlockHash { height, block_hash } => {
+                write!(f, "Block hash '{block_hash}' (block {height}) is missing from the reverse block hash map")
+            }
+            Self::DanglingReverseBlockHash { block_hash, height } => {
+                write!(f, "Dangling reverse block hash '{block_hash}' (block {height})")
+            }
+            Self::MissingReverseStateRoot { height, state_root } => {
+                write!(f, "State root '{state_root}' (block {height}) is missing from the reverse state root map")
+            }
+            Self::DanglingReverseStateRoot { state_root, height } => {
+                write!(f, "Dangling reverse state root '{state_root}' (block {height})")
+            }
+            Self::MissingHeader { block_hash } => write!(f, "Missing header for block '{block_hash}'"),
+            Self::MissingBlockTransactions { block_hash } => {
+                write!(f, "Missing transaction IDs for block '{block_hash}'")
+            }
+            Self::MissingTransaction { block_hash, transaction_id } => {
+                write!(
+                    f,
+                    "Transaction '{transaction_id}' of block '{block_hash}' is missing from the transaction store"
+                )
+            }
+            Self::MissingConfirmedTransaction { block_hash, transaction_id } => {
+                write!(f, "Transaction '{transaction_id}' of block '{block_hash}' is not confirmed in that block")
+            }
+            Self::DanglingConfirmedTransaction { block_hash, transaction_id } => {
+                write!(f, "Dangling confirmed transaction '{transaction_id}' (block '{block_hash}')")
+            }
+            Self::DanglingTransaction { transaction_id } => {
+                write!(f, "Transaction '{transaction_id}' does not belong to any block")
+            }
+            Self::StateRootMismatch { height, expected, found } => {
+                write!(f, "Block tree root '{found}' does not match the state root '{expected}' of block {height}")
+            }
+            Self::MissingRatifiedOperations { height } => {
+                write!(f, "Missing the ratified finalize operations for block {height}")
+            }
+            Self::RatifiedOperationsUnavailable { before } => {
+                write!(f, "The ratified finalize operations are unavailable before block {before}")
+            }
+            Self::FinalizeRootMismatch { height, expected, found } => {
+                write!(f, "Finalize root '{found}' does not match the finalize root '{expected}' of block {height}")
+            }
+        }
+    }
+}
+
+/// A report of the inconsistencies found by an integrity check of the storage.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport<N: Network> {
+    /// The issues found.
+    issues: Vec<IntegrityIssue<N>>,
+    /// The number of issues that were repaired.
+    num_repaired: usize,
+}
+
+impl<N: Network> IntegrityReport<N> {
+    /// Returns the issues found.
+    pub fn issues(&self) -> &[IntegrityIssue<N>] {
+        &self.issues
+    }
+
+    /// Returns the number of issues that were repaired.
+    pub const fn num_repaired(&self) -> usize {
+        self.num_repaired
+    }
+
+    /// Returns `true` if no issues were found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Records an issue found by an additional check.
+    pub fn push(&mut self, issue: IntegrityIssue<N>) {
+        self.issues.push(issue);
+    }
+}
+
+impl<N: Network> Display for IntegrityReport<N> {
+    /// Displays the integrity report.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.issues.is_empty() {
+            true => write!(f, "No integrity issues found"),
+            false => {
+                write!(f, "Found {} integrity issue(s), repaired {}:", self.issues.len(), self.num_repaired)?;
+                self.issues.iter().try_for_each(|issue| write!(f, "\n  - {issue}"))
+            }
+        }
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Checks the cross-map invariants of the block storage, and returns a report of the issues found.
+    ///
+    /// The check ensures that the reverse block hash and state root maps mirror their forward maps,
+    /// that every block has a header and its transaction IDs resolve to transactions confirmed in that block,
+    /// that no transactions are dangling, and that the block tree root equals the latest stored state root.
+    ///
+    /// If `repair` is `true`, the repairable issues (see `IntegrityIssue::is_repairable`) are fixed in a single
+    /// atomic batch, by restoring the unmirrored reverse entries and removing the dangling entries.
+    pub fn check_integrity(&self, repair: bool) -> Result<IntegrityReport<N>> {
+        let mut issues = Vec::new();
+
+        // Step 1. Ensure the block hashes are contiguous, and mirrored in the reverse block hash map.
+        let block_hashes = self
+            .storage
+            .id_map()
+            .iter_confirmed()
+            .map(|(k, v)| (cow_to_copied!(k), cow_to_copied!(v)))
+            .collect::<IndexMap<_, _>>();
+        if let Some(latest_height) = block_hashes.keys().max() {
+            for height in 0..=*latest_height {
+                if !block_hashes.contains_key(&height) {
+                    issues.push(IntegrityIssue::MissingBlockHash { height });
+                }
+            }
+        }
+        for (height, block_hash) in &block_hashes {
+            if self.storage.reverse_id_map().get_confirmed(block_hash)?.map(|h| cow_to_copied!(h)) != Some(*height) {
+                issues.push(IntegrityIssue::MissingReverseBlockHash { height: *height, block_hash: *block_hash });
+            }
+        }
+        for (block_hash, height) in self.storage.reverse_id_map().iter_confirmed() {
+            let (block_hash, height) = (cow_to_copied!(block_hash), cow_to_copied!(height));
+            if block_hashes.get(&height) != Some(&block_hash) {
+                issues.push(IntegrityIssue::DanglingReverseBlockHash { block_hash, height });
+            }
+        }
+
+        // Step 2. Ensure the state roots are mirrored in the reverse state root map.
+        let state_roots = self
+            .storage
+            .state_root_map()
+            .iter_confirmed()
+            .map(|(k, v)| (cow_to_copied!(k), cow_to_copied!(v)))
+            .collect::<IndexMap<_, _>>();
+        for (height, state_root) in &state_roots {
+            if self.storage.reverse_state_root_map().get_confirmed(state_root)?.map(|h| cow_to_copied!(h))
+                != Some(*height)
+            {
+                issues.push(IntegrityIssue::MissingReverseStateRoot { height: *height, state_root: *state_root });
+            }
+        }
+        for (state_root, height) in self.storage.reverse_state_root_map().iter_confirmed() {
+            let (state_root, height) = (cow_to_copied!(state_root), cow_to_copied!(height));
+            if state_roots.get(&height) != Some(&state_root) {
+                issues.push(IntegrityIssue::DanglingReverseStateRoot { state_root, height });
+            }
+        }
+
+        // Step 3. Ensure every block has a header, and its transaction IDs resolve to transactions confirmed in it.
+        let mut block_transaction_ids = IndexMap::new();
+        for block_hash in block_hashes.values() {
+            if !self.storage.header_map().contains_key_confirmed(block_hash)? {
+                issues.push(IntegrityIssue::MissingHeader { block_hash: *block_hash });
+            }
+            let Some(transaction_ids) = self.storage.transactions_map().get_confirmed(block_hash)? else {
+                issues.push(IntegrityIssue::MissingBlockTransactions { block_hash: *block_hash });
+                continue;
+            };
+            for transaction_id in transaction_ids.iter() {
+                if !self.transaction_store().contains_transaction_id(transaction_id)? {
+                    issues.push(IntegrityIssue::MissingTransaction {
+                        block_hash: *block_hash,
+                        transaction_id: *transaction_id,
+                    });
+                }
+                match self.storage.confirmed_transactions_map().get_confirmed(transaction_id)? {
+                    Some(confirmed) if confirmed.0 == *block_hash => (),
+                    _ => issues.push(IntegrityIssue::MissingConfirmedTransaction {
+                        block_hash: *block_hash,
+                        transaction_id: *transaction_id,
+                    }),
+                }
+                block_transaction_ids.insert(*transaction_id, *block_hash);
+            }
+        }
+
+        // Step 4. Ensure there are no dangling confirmed transactions or transactions.
+        for (transaction_id, confirmed) in self.storage.confirmed_transactions_map().iter_confirmed() {
+            let (transaction_id, block_hash) = (cow_to_copied!(transaction_id), confirmed.0);
+            if block_transaction_ids.get(&transaction_id) != Some(&block_hash) {
+                issues.push(IntegrityIssue::DanglingConfirmedTransaction { block_hash, transaction_id });
+            }
+        }
+        let dangling_transaction_ids = self
+            .transaction_store()
+            .transaction_ids()
+            .map(|transaction_id| cow_to_copied!(transaction_id))
+            .filter(|transaction_id| !block_transaction_ids.contains_key(transaction_id))
+            .collect::<IndexSet<_>>();
+        issues.extend(
+            dangling_transaction_ids
+                .into_iter()
+                .map(|transaction_id| IntegrityIssue::DanglingTransaction { transaction_id }),
+        );
+
+        // Step 5. Ensure the block tree root equals the state root of the latest block.
+        if let Some((height, expected)) = state_roots.iter().max_by_key(|(height, _)| **height) {
+            let found: N::StateRoot = (*self.tree.read().root()).into();
+            if found != *expected {
+                issues.push(IntegrityIssue::StateRootMismatch { height: *height, expected: *expected, found });
+            }
+        }
+
+        // Step 6. If requested, repair the repairable issues.
+        let num_repaired = match repair {
+            true => self.repair_integrity_issues(&issues)?,
+            false => 0,
+        };
+
+        Ok(IntegrityReport { issues, num_repaired })
+    }
+
+    /// Repairs the repairable issues in a single atomic batch, and returns the number of issues repaired.
+    fn repair_integrity_issues(&self, issues: &[IntegrityIssue<N>]) -> Result<usize> {
+        // Ensure there is no atomic batch in progress, as the repairs must not be interleaved with other writes.
+        ensure!(!self.is_atomic_in_progress(), "Cannot repair the storage while an atomic batch is in progress");
+
+        atomic_batch_scope!(self, {
+            // Remove the dangling entries first, so that re-inserting a mirrored entry with the same key is not undone.
+            for issue in issues {
+                match issue {
+                    IntegrityIssue::DanglingReverseBlockHash { block_hash, .. } => {
+                        self.storage.reverse_id_map().remove(block_hash)?
+                    }
+                    IntegrityIssue::DanglingReverseStateRoot { state_root, .. } => {
+                        self.storage.reverse_state_root_map().remove(state_root)?
+                    }
+                    IntegrityIssue::DanglingConfirmedTransaction { transaction_id, .. } => {
+                        self.storage.confirmed_transactions_map().remove(transaction_id)?
+                    }
+                    IntegrityIssue::DanglingTransaction { transaction_id } => {
+                        self.transaction_store().remove(transaction_id)?
+                    }
+                    _ => (),
+                }
+            }
+            // Restore the unmirrored reverse entries.
+            for issue in issues {
+                match issue {
+                    IntegrityIssue::MissingReverseBlockHash { height, block_hash } => {
+                        self.storage.reverse_id_map().insert(*block_hash, *height)?
+                    }
+                    IntegrityIssue::MissingReverseStateRoot { height, state_root } => {
+                        self.storage.reverse_state_root_map().insert(*state_root, *height)?
+                    }
+                    _ => (),
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(issues.iter().filter(|issue| issue.is_repairable()).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::BlockMemory;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns a block store containing the genesis block.
+    fn sample_block_store() -> (BlockStore<CurrentNetwork, BlockMemory<CurrentNetwork>>, Block<CurrentNetwork>) {
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&block).unwrap();
+        (block_store, block)
+    }
+
+    #[test]
+    fn test_check_integrity() {
+        let (block_store, _) = sample_block_store();
+
+        // Ensure a consistent store has no issues.
+        let report = block_store.check_integrity(true).unwrap();
+        assert!(report.is_ok(), "{report}");
+        assert_eq!(report.num_repaired(), 0);
+    }
+
+    #[test]
+    fn test_check_integrity_reverse_maps() {
+        let (block_store, block) = sample_block_store();
+        let rng = &mut TestRng::default();
+
+        // Remove the reverse block hash, and insert a dangling reverse state root.
+        let dangling_state_root: <CurrentNetwork as Network>::StateRoot = Field::<CurrentNetwork>::rand(rng).into();
+        block_store.storage.reverse_id_map().remove(&block.hash()).unwrap();
+        block_store.storage.reverse_state_root_map().insert(dangling_state_root, 1).unwrap();
+
+        // Ensure the issues are reported, but not repaired.
+        let report = block_store.check_integrity(false).unwrap();
+        assert_eq!(report.issues(), &[
+            IntegrityIssue::MissingReverseBlockHash { height: 0, block_hash: block.hash() },
+            IntegrityIssue::DanglingReverseStateRoot { state_root: dangling_state_root, height: 1 },
+        ]);
+        assert_eq!(report.num_repaired(), 0);
+        assert_eq!(block_store.check_integrity(false).unwrap(), report);
+
+        // Repair the issues.
+        let report = block_store.check_integrity(true).unwrap();
+        assert_eq!(report.issues().len(), 2);
+        assert_eq!(report.num_repaired(), 2);
+
+        // Ensure the store is consistent again.
+        assert!(block_store.check_integrity(false).unwrap().is_ok());
+        assert_eq!(block_store.get_block_height(&block.hash()).unwrap(), Some(0));
+        assert!(!block_store.storage.reverse_state_root_map().contains_key_confirmed(&dangling_state_root).unwrap());
+    }
+
+    #[test]
+    fn test_check_integrity_transactions() {
+        let (block_store, block) = sample_block_store();
+        let transaction_id = *block.transaction_ids().next().unwrap();
+
+        // Remove the transaction IDs of the block, leaving its transactions dangling.
+        block_store.storage.transactions_map().remove(&block.hash()).unwrap();
+
+        // Ensure the issues are reported.
+        let report = block_store.check_integrity(false).unwrap();
+        let num_transactions = block.transactions().len();
+        assert_eq!(report.issues().len(), 1 + 2 * num_transactions);
+        assert_eq!(report.issues()[0], IntegrityIssue::MissingBlockTransactions { block_hash: block.hash() });
+        assert!(report.issues().contains(&IntegrityIssue::DanglingTransaction { transaction_id }));
+        assert!(report
+            .issues()
+            .contains(&IntegrityIssue::DanglingConfirmedTransaction { block_hash: block.hash(), transaction_id }));
+
+        // Repair the issues, and ensure the dangling transactions were removed.
+        let report = block_store.check_integrity(true).unwrap();
+        assert_eq!(report.num_repaired(), 2 * num_transactions);
+        assert!(!block_store.contains_transaction_id(&transaction_id).unwrap());
+        assert!(block_store.transaction_store().transaction_ids().next().is_none());
+
+        // Ensure only the unrepairable issue remains.
+        let report = block_store.check_integrity(false).unwrap();
+        assert_eq!(report.issues(), &[IntegrityIssue::MissingBlockTransactions { block_hash: block.hash() }]);
+    }
+}
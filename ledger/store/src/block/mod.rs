@@ -15,6 +15,9 @@
 pub mod confirmed_tx_type;
 pub use confirmed_tx_type::*;
 
+mod integrity;
+pub use integrity::*;
+
 use crate::{
     atomic_batch_scope,
     cow_to_cloned,
@@ -25,9 +28,11 @@ use crate::{
     TransitionStorage,
     TransitionStore,
 };
+#[cfg(feature = "address-index")]
+use crate::{AddressStorage, AddressStore};
 use console::{
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockTree, Ciphertext, HeaderLeaf, ProgramID, Record, StatePath},
     types::Field,
 };
 use ledger_authority::Authority;
@@ -48,11 +53,14 @@ use synthesizer_program::{FinalizeOperation, Program};
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use parking_lot::RwLock;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, ops::Range, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The key of the pruning depth in the pruning depth map.
+const PRUNING_DEPTH_KEY: u8 = 0;
+
 /// Separates the confirmed transaction into a tuple.
 #[allow(clippy::type_complexity)]
 fn to_confirmed_tuple<N: Network>(
@@ -139,10 +147,17 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `PRUNING_DEPTH_KEY` to the pruning depth, if pruning is enabled.
+    type PruningDepthMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of pruned `block hash` to `block height`.
+    type PrunedMap: for<'a> Map<'a, N::BlockHash, u32>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
     type TransitionStorage: TransitionStorage<N>;
+    /// The address index storage.
+    #[cfg(feature = "address-index")]
+    type AddressStorage: AddressStorage<N>;
 
     /// Initializes the block storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -181,8 +196,15 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap;
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
+    /// Returns the address index store.
+    #[cfg(feature = "address-index")]
+    fn address_store(&self) -> &AddressStore<N, Self::AddressStorage>;
 
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
@@ -213,11 +235,19 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.pruning_depth_map().start_atomic();
+        self.pruned_map().start_atomic();
         self.transaction_store().start_atomic();
+        #[cfg(feature = "address-index")]
+        self.address_store().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
+        #[cfg(feature = "address-index")]
+        if self.address_store().is_atomic_in_progress() {
+            return true;
+        }
         self.state_root_map().is_atomic_in_progress()
             || self.reverse_state_root_map().is_atomic_in_progress()
             || self.id_map().is_atomic_in_progress()
@@ -235,6 +265,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.pruning_depth_map().is_atomic_in_progress()
+            || self.pruned_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -257,7 +289,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.pruning_depth_map().atomic_checkpoint();
+        self.pruned_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
+        #[cfg(feature = "address-index")]
+        self.address_store().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -279,7 +315,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.pruning_depth_map().clear_latest_checkpoint();
+        self.pruned_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
+        #[cfg(feature = "address-index")]
+        self.address_store().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -301,7 +341,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.pruning_depth_map().atomic_rewind();
+        self.pruned_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
+        #[cfg(feature = "address-index")]
+        self.address_store().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -323,7 +367,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.pruning_depth_map().abort_atomic();
+        self.pruned_map().abort_atomic();
         self.transaction_store().abort_atomic();
+        #[cfg(feature = "address-index")]
+        self.address_store().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -345,6 +393,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.pruning_depth_map().finish_atomic()?;
+        self.pruned_map().finish_atomic()?;
+        #[cfg(feature = "address-index")]
+        self.address_store().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -442,6 +494,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.rejected_or_aborted_transaction_id_map().insert(rejected_transaction_id, block.hash())?;
             }
 
+            // Store the address index entries.
+            #[cfg(feature = "address-index")]
+            self.address_store().insert(block)?;
+
             // Store the confirmed transactions.
             for (confirmed_type, transaction, finalize_operations) in confirmed {
                 // Store the block hash and confirmed transaction data.
@@ -534,6 +590,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
             // Remove the block authority.
             self.authority_map().remove(block_hash)?;
+            // Remove the pruned marker of the block, if any.
+            self.pruned_map().remove(block_hash)?;
 
             // Remove the block certificates.
             for certificate_id in certificate_ids_to_remove.iter() {
@@ -578,6 +636,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 }
             }
 
+            // Remove the address index entries.
+            #[cfg(feature = "address-index")]
+            self.address_store().remove(block_height)?;
+
             // Remove the block transactions.
             for transaction_id in transaction_ids.iter() {
                 // Remove the reverse transaction ID.
@@ -590,6 +652,54 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Prunes the execution proofs, fee proofs, and transition values of the block for the given `block hash`.
+    /// The header, authority, transaction IDs, and records of the block are retained.
+    fn prune(&self, block_hash: &N::BlockHash) -> Result<()> {
+        // If the block has already been pruned, return early.
+        if self.pruned_map().contains_key_confirmed(block_hash)? {
+            return Ok(());
+        }
+        // Retrieve the block height.
+        let block_height = match self.get_block_height(block_hash)? {
+            Some(height) => height,
+            None => bail!("Failed to prune block: missing block height for block hash '{block_hash}'"),
+        };
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+            None => bail!("Failed to prune block: missing transactions for block '{block_hash}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the block transactions.
+            for transaction_id in transaction_ids.iter() {
+                self.transaction_store().prune(transaction_id)?;
+            }
+            // Mark the block as pruned.
+            self.pruned_map().insert(*block_hash, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the block for the given `block hash` has been pruned.
+    fn is_pruned(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        self.pruned_map().contains_key_confirmed(block_hash)
+    }
+
+    /// Returns the persisted pruning depth, if pruning is enabled.
+    fn get_pruning_depth(&self) -> Result<Option<u32>> {
+        Ok(self.pruning_depth_map().get_confirmed(&PRUNING_DEPTH_KEY)?.map(|depth| cow_to_copied!(depth)))
+    }
+
+    /// Persists the given pruning depth, or disables pruning if `None`.
+    fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        match depth {
+            Some(depth) => self.pruning_depth_map().insert(PRUNING_DEPTH_KEY, depth),
+            None => self.pruning_depth_map().remove(&PRUNING_DEPTH_KEY),
+        }
+    }
+
     /// Returns `true` if the given transaction ID exists.
     fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
         Ok(self.transaction_store().contains_transaction_id(transaction_id)?
@@ -671,15 +781,31 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(transition) => transition,
             None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
         };
-        // Retrieve the block.
-        let block = match self.get_block(&block_hash)? {
-            Some(block) => block,
+        // Retrieve the block height.
+        // Note: The block is not retrieved as a whole, as it may have been pruned.
+        let block_height = match self.get_block_height(&block_hash)? {
+            Some(block_height) => block_height,
             None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
         };
+        // Retrieve the previous block hash.
+        let previous_hash = match self.get_previous_block_hash(block_height)? {
+            Some(previous_hash) => previous_hash,
+            None => bail!("The previous block hash of block {block_height} for commitment '{commitment}' is missing"),
+        };
+        // Retrieve the block header.
+        let block_header = match self.get_block_header(&block_hash)? {
+            Some(block_header) => block_header,
+            None => bail!("The block header of block {block_height} for commitment '{commitment}' is missing"),
+        };
+        // Retrieve the block transactions.
+        let transactions = match self.get_block_transactions(&block_hash)? {
+            Some(transactions) => transactions,
+            None => bail!("The transactions of block {block_height} for commitment '{commitment}' are missing"),
+        };
 
         // Construct the global state root and block path.
         let global_state_root = *block_tree.root();
-        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+        let block_path = block_tree.prove(block_height as usize, &block_hash.to_bits_le())?;
 
         // Ensure the global state root exists in storage.
         if !self.reverse_state_root_map().contains_key_confirmed(&global_state_root.into())? {
@@ -692,7 +818,6 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         let transition_path = transition.to_path(&transition_leaf)?;
 
         // Construct the transactions path.
-        let transactions = block.transactions();
         let transactions_path = match transactions.to_path(transaction_id) {
             Ok(transactions_path) => transactions_path,
             Err(_) => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
@@ -707,7 +832,6 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         let transaction_path = transaction.to_path(&transaction_leaf)?;
 
         // Construct the block header path.
-        let block_header = block.header();
         let header_root = block_header.to_root()?;
         let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
         let header_path = block_header.to_path(&header_leaf)?;
@@ -715,8 +839,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(StatePath::from(
             global_state_root.into(),
             block_path,
-            block.hash(),
-            block.previous_hash(),
+            block_hash,
+            previous_hash,
             header_root,
             header_path,
             header_leaf,
@@ -870,6 +994,32 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .collect::<Result<Option<Transactions<_>>>>()
     }
 
+    /// Returns the block records for the given `block hash`, as a tuple of `(commitment, record)`.
+    /// Note: The records are read from the transition store, so they are available even if the block was pruned.
+    #[allow(clippy::type_complexity)]
+    fn get_block_records(
+        &self,
+        block_hash: &N::BlockHash,
+    ) -> Result<Option<Vec<(Field<N>, Record<N, Ciphertext<N>>)>>> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+            None => return Ok(None),
+        };
+        // Retrieve the records of each transition, in the order of the block transactions.
+        let mut records = Vec::new();
+        for transaction_id in &transaction_ids {
+            let transition_ids = match self.transaction_store().get_transition_ids(transaction_id)? {
+                Some(transition_ids) => transition_ids,
+                None => bail!("Failed to get the transitions for transaction '{transaction_id}'"),
+            };
+            for transition_id in &transition_ids {
+                records.extend(self.transition_store().get_records(transition_id)?);
+            }
+        }
+        Ok(Some(records))
+    }
+
     /// Returns the block aborted transaction IDs for the given `block hash`.
     fn get_block_aborted_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
         match self.aborted_transaction_ids_map().get_confirmed(block_hash)? {
@@ -941,9 +1091,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 
     /// Returns the block for the given `block hash`.
+    /// Note: This method fails if the block has been pruned, as its proofs and transition values are discarded.
     fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         // Retrieve the block height.
         let Some(height) = self.get_block_height(block_hash)? else { return Ok(None) };
+        // Ensure the block has not been pruned.
+        if self.is_pruned(block_hash)? {
+            bail!("Block {height} ('{block_hash}') has been pruned, and is no longer available in full")
+        }
 
         // Retrieve the block header.
         let Some(header) = self.get_block_header(block_hash)? else {
@@ -1004,6 +1159,8 @@ pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     storage: B,
     /// The block tree.
     tree: Arc<RwLock<BlockTree<N>>>,
+    /// The number of latest blocks to retain in full, if pruning is enabled.
+    pruning_depth: Arc<RwLock<Option<u32>>>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1030,8 +1187,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
         };
 
+        // Retrieve the persisted pruning depth.
+        let pruning_depth = Arc::new(RwLock::new(storage.get_pruning_depth()?));
+
         // Return the block store.
-        Ok(Self { storage, tree })
+        Ok(Self { storage, tree, pruning_depth })
     }
 
     /// Stores the given block into storage.
@@ -1044,14 +1204,68 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // If pruning is enabled, prune the block that is now beyond the pruning depth.
+            if let Some(height) = self.pruning_depth().and_then(|depth| block.height().checked_sub(depth)) {
+                self.prune(height)?;
+            }
+            Ok(())
+        })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
         Ok(())
     }
 
+    /// Returns the number of latest blocks that are retained in full, if pruning is enabled.
+    pub fn pruning_depth(&self) -> Option<u32> {
+        *self.pruning_depth.read()
+    }
+
+    /// Sets the number of latest blocks to retain in full, or disables pruning if `None`.
+    /// The pruning depth is persisted, and restored when the block store is reopened.
+    ///
+    /// When pruning is enabled, inserting a block prunes the block that is `depth` blocks below it.
+    /// Blocks that are already beyond the pruning depth are not pruned, see `BlockStore::prune_blocks`.
+    /// The VM also discards the finalize history of the blocks beyond the pruning depth (see
+    /// `FinalizeStore::set_history_depth`), which bounds the depth of `VM::rollback_to` and of the historical queries.
+    pub fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        // Ensure the latest block is always retained in full.
+        ensure!(depth != Some(0), "The pruning depth must be at least 1");
+        // Acquire the write lock on the pruning depth.
+        let mut pruning_depth = self.pruning_depth.write();
+        // Persist the pruning depth.
+        self.storage.set_pruning_depth(depth)?;
+        *pruning_depth = depth;
+        Ok(())
+    }
+
+    /// Prunes the block at the given height.
+    ///
+    /// Pruning discards the execution proofs, fee proofs, and the values of the (non-record) transition inputs
+    /// and outputs of the block. The block header, authority, transaction and transition IDs, records, serial
+    /// numbers, deployments, and rejected deployments or executions are retained, so `get_state_path_for_commitment`
+    /// is unaffected. However, `get_block` fails for a pruned block, as it can no longer be retrieved in full.
+    /// Pruning a block that has already been pruned has no effect.
+    pub fn prune(&self, height: u32) -> Result<()> {
+        match self.storage.get_block_hash(height)? {
+            Some(block_hash) => self.storage.prune(&block_hash),
+            None => bail!("Failed to prune block {height}: missing block hash"),
+        }
+    }
+
+    /// Prunes the blocks in the given range of heights. See `BlockStore::prune` for more details.
+    pub fn prune_blocks(&self, heights: Range<u32>) -> Result<()> {
+        // Ensure the latest block is not pruned.
+        if let Some(latest_height) = self.heights().max().map(|height| cow_to_copied!(height)) {
+            ensure!(heights.end <= latest_height, "Cannot prune the latest block {latest_height}");
+        }
+        // Prune each block in its own atomic batch, to bound the size of the batches.
+        heights.into_iter().try_for_each(|height| self.prune(height))
+    }
+
     /// Reverts the Merkle tree to its shape before the insertion of the last 'n' blocks.
     pub fn remove_last_n_from_tree_only(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
@@ -1125,6 +1339,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.transaction_store().transition_store()
     }
 
+    /// Returns the address index store.
+    #[cfg(feature = "address-index")]
+    pub fn address_store(&self) -> &AddressStore<N, B::AddressStorage> {
+        self.storage.address_store()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -1257,6 +1477,15 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block_transactions(block_hash)
     }
 
+    /// Returns the block records for the given `block hash`, as a tuple of `(commitment, record)`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_block_records(
+        &self,
+        block_hash: &N::BlockHash,
+    ) -> Result<Option<Vec<(Field<N>, Record<N, Ciphertext<N>>)>>> {
+        self.storage.get_block_records(block_hash)
+    }
+
     /// Returns the block aborted transaction IDs for the given `block hash`.
     pub fn get_block_aborted_transaction_ids(
         &self,
@@ -1284,10 +1513,16 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 
     /// Returns the block for the given `block hash`.
+    /// Note: This method fails if the block has been pruned, see `BlockStore::prune`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         self.storage.get_block(block_hash)
     }
 
+    /// Returns `true` if the block for the given `block hash` has been pruned.
+    pub fn is_pruned(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        self.storage.is_pruned(block_hash)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
@@ -1373,6 +1608,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 mod tests {
     use super::*;
     use crate::helpers::memory::BlockMemory;
+    use ledger_block::{Input, Output};
 
     type CurrentNetwork = console::network::MainnetV0;
 
@@ -1485,6 +1721,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_prune() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let block_hash = block.hash();
+        assert!(block.transactions().num_accepted() > 0, "This test must be run with at least one transaction.");
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure the pruning depth must be non-zero.
+        assert!(block_store.set_pruning_depth(Some(0)).is_err());
+        // Ensure the pruning depth is persisted.
+        block_store.set_pruning_depth(Some(2)).unwrap();
+        assert_eq!(block_store.storage.get_pruning_depth().unwrap(), Some(2));
+        block_store.set_pruning_depth(None).unwrap();
+        assert_eq!(block_store.storage.get_pruning_depth().unwrap(), None);
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+        // Ensure the latest block cannot be pruned in a range.
+        assert!(block_store.prune_blocks(0..1).is_err());
+
+        // Retrieve the state paths of the records, prior to pruning.
+        let commitments = block.commitments().copied().collect::<Vec<_>>();
+        assert!(!commitments.is_empty(), "This test must be run with at least one record.");
+        let state_paths = commitments
+            .iter()
+            .map(|commitment| block_store.get_state_path_for_commitment(commitment).unwrap())
+            .collect::<Vec<_>>();
+
+        // Prune the block, twice.
+        assert!(!block_store.is_pruned(&block_hash).unwrap());
+        block_store.prune(0).unwrap();
+        block_store.prune(0).unwrap();
+        assert!(block_store.is_pruned(&block_hash).unwrap());
+
+        // Ensure the pruned block can no longer be retrieved in full.
+        let error = block_store.get_block(&block_hash).unwrap_err();
+        assert!(error.to_string().contains("has been pruned"));
+
+        // Ensure the header and transactions are retained, without their proofs and transition values.
+        assert_eq!(block_store.get_block_header(&block_hash).unwrap().as_ref(), Some(block.header()));
+        let transactions = block_store.get_block_transactions(&block_hash).unwrap().unwrap();
+        assert_eq!(transactions.transaction_ids().collect::<Vec<_>>(), block.transaction_ids().collect::<Vec<_>>());
+        for transaction in transactions.iter() {
+            if let Some(execution) = transaction.transaction().execution() {
+                assert!(execution.proof().is_none());
+            }
+            for transition in transaction.transaction().transitions() {
+                assert!(transition.inputs().iter().all(|input| matches!(
+                    input,
+                    Input::Constant(_, None)
+                        | Input::Public(_, None)
+                        | Input::Private(_, None)
+                        | Input::Record(..)
+                        | Input::ExternalRecord(_)
+                )));
+                assert!(transition.outputs().iter().all(|output| matches!(
+                    output,
+                    Output::Constant(_, None)
+                        | Output::Public(_, None)
+                        | Output::Private(_, None)
+                        | Output::Record(..)
+                        | Output::ExternalRecord(_)
+                        | Output::Future(_, None)
+                )));
+            }
+        }
+
+        // Ensure the records and state paths are retained.
+        for (commitment, state_path) in commitments.iter().zip_eq(state_paths) {
+            assert!(block_store.transition_store().get_record(commitment).unwrap().is_some());
+            assert_eq!(block_store.get_state_path_for_commitment(commitment).unwrap(), state_path);
+        }
+
+        // Ensure the pruned block can be removed.
+        block_store.remove_last_n(1).unwrap();
+        assert_eq!(None, block_store.get_block(&block_hash).unwrap());
+        assert!(!block_store.is_pruned(&block_hash).unwrap());
+    }
+
     #[test]
     fn test_get_unconfirmed_transaction() {
         let rng = &mut TestRng::default();
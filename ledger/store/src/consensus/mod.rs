@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use crate::{
+    atomic_batch_scope,
+    cow_to_copied,
     BlockStorage,
     BlockStore,
     FinalizeStorage,
     FinalizeStore,
+    IntegrityIssue,
+    IntegrityReport,
     TransactionStorage,
     TransactionStore,
     TransitionStorage,
@@ -146,6 +150,88 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.transition_store()
     }
 
+    /// Removes the last 'n' blocks from storage, and reverts the finalize state to its state prior to those blocks.
+    /// The blocks are removed and the finalize state is reverted within a single atomic batch.
+    pub fn remove_last_n(&self, n: u32) -> Result<()> {
+        // Ensure 'n' is non-zero.
+        ensure!(n > 0, "Cannot remove zero blocks");
+        // Retrieve the latest block height.
+        let Some(latest_height) = self.block_store().heights().max().map(|height| cow_to_copied!(height)) else {
+            bail!("Failed to remove last '{n}' blocks: no blocks in storage")
+        };
+        // Ensure there are enough blocks to remove.
+        ensure!(n <= latest_height.saturating_add(1), "Failed to remove last '{n}' blocks: block height underflow");
+
+        atomic_batch_scope!(self, {
+            // Revert the finalize state of the blocks, in descending order.
+            for height in (latest_height + 1 - n..=latest_height).rev() {
+                self.finalize_store().rollback(height)?;
+            }
+            // Remove the blocks.
+            // Note: This is performed last, as the block tree is updated once the blocks are removed.
+            self.block_store().remove_last_n(n)
+        })
+    }
+
+    /// Checks the integrity of the consensus storage, and returns a report of the issues found.
+    ///
+    /// The cross-map invariants of the block storage are always checked (see `BlockStore::check_integrity`),
+    /// and if `repair` is `true`, the dangling and unmirrored entries are repaired.
+    ///
+    /// If `deep` is `true`, the finalize root of every block is also recomputed from the finalize operations
+    /// stored for the block, and compared against the finalize root in its header.
+    /// Note: The pruned blocks are skipped by the deep check, as their transactions are no longer stored.
+    /// The blocks finalized before the ratified finalize operations were stored cannot be checked,
+    /// and are reported at once as `IntegrityIssue::RatifiedOperationsUnavailable`.
+    pub fn check_integrity(&self, repair: bool, deep: bool) -> Result<IntegrityReport<N>> {
+        // Check the block storage.
+        let mut report = self.block_store().check_integrity(repair)?;
+
+        // Check the stored finalize operations against the block headers.
+        if deep {
+            let num_blocks = self.block_store().heights().max().map_or(0, |height| *height + 1);
+            // Determine the first block height with stored ratified finalize operations.
+            let start = self.finalize_store().get_ratified_operations_start()?.unwrap_or(num_blocks).min(num_blocks);
+            if start > 0 {
+                report.push(IntegrityIssue::RatifiedOperationsUnavailable { before: start });
+            }
+            for height in start..num_blocks {
+                // Retrieve the block hash.
+                // Note: The missing block hashes, headers, and transactions are reported by the block storage check.
+                let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+                    continue;
+                };
+                // Skip the pruned blocks.
+                if self.block_store().is_pruned(&block_hash)? {
+                    continue;
+                }
+                // Retrieve the header and the transactions.
+                let (Some(header), Some(transactions)) = (
+                    self.block_store().get_block_header(&block_hash)?,
+                    self.block_store().get_block_transactions(&block_hash)?,
+                ) else {
+                    continue;
+                };
+                // Retrieve the ratified finalize operations.
+                let Some(ratified_finalize_operations) = self.finalize_store().get_ratified_operations(height)? else {
+                    report.push(IntegrityIssue::MissingRatifiedOperations { height });
+                    continue;
+                };
+                // Ensure the finalize root matches the header.
+                let finalize_root = transactions.to_finalize_root(ratified_finalize_operations)?;
+                if finalize_root != header.finalize_root() {
+                    report.push(IntegrityIssue::FinalizeRootMismatch {
+                        height,
+                        expected: header.finalize_root(),
+                        found: finalize_root,
+                    });
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
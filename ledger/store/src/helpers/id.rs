@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-/// The RocksDB map prefix broken down into the entry category and the specific type of the entry.
+/// The persistent storage map prefix broken down into the entry category and the specific type of the entry.
 // Note: the order of these variants can be changed at any point in time.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u16)]
@@ -28,6 +28,8 @@ pub enum MapID {
     TransitionInput(TransitionInputMap),
     TransitionOutput(TransitionOutputMap),
     Program(ProgramMap),
+    Address(AddressMap),
+    Schema(SchemaMap),
     #[cfg(test)]
     Test(TestMap),
 }
@@ -46,13 +48,15 @@ impl From<MapID> for u16 {
             MapID::TransitionInput(id) => id as u16,
             MapID::TransitionOutput(id) => id as u16,
             MapID::Program(id) => id as u16,
+            MapID::Address(id) => id as u16,
+            MapID::Schema(id) => id as u16,
             #[cfg(test)]
             MapID::Test(id) => id as u16,
         }
     }
 }
 
-/// The RocksDB map prefix for BFT-related entries.
+/// The persistent storage map prefix for BFT-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -61,7 +65,7 @@ pub enum BFTMap {
     Transmissions = DataID::BFTTransmissionsMap as u16,
 }
 
-/// The RocksDB map prefix for block-related entries.
+/// The persistent storage map prefix for block-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -84,9 +88,11 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    PruningDepth = DataID::BlockPruningDepthMap as u16,
+    Pruned = DataID::BlockPrunedMap as u16,
 }
 
-/// The RocksDB map prefix for committee-related entries.
+/// The persistent storage map prefix for committee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -97,7 +103,7 @@ pub enum CommitteeMap {
     Committee = DataID::CommitteeMap as u16,
 }
 
-/// The RocksDB map prefix for deployment-related entries.
+/// The persistent storage map prefix for deployment-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -112,7 +118,7 @@ pub enum DeploymentMap {
     Certificate = DataID::DeploymentCertificateMap as u16,
 }
 
-/// The RocksDB map prefix for execution-related entries.
+/// The persistent storage map prefix for execution-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -123,7 +129,7 @@ pub enum ExecutionMap {
     Inclusion = DataID::ExecutionInclusionMap as u16,
 }
 
-/// The RocksDB map prefix for fee-related entries.
+/// The persistent storage map prefix for fee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -133,7 +139,7 @@ pub enum FeeMap {
     ReverseFee = DataID::FeeReverseFeeMap as u16,
 }
 
-/// The RocksDB map prefix for transition input entries.
+/// The persistent storage map prefix for transition input entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -149,7 +155,7 @@ pub enum TransitionInputMap {
     ExternalRecord = DataID::InputExternalRecordMap as u16,
 }
 
-/// The RocksDB map prefix for transition output entries.
+/// The persistent storage map prefix for transition output entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -166,7 +172,7 @@ pub enum TransitionOutputMap {
     Future = DataID::OutputFutureMap as u16,
 }
 
-/// The RocksDB map prefix for transaction-related entries.
+/// The persistent storage map prefix for transaction-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -175,7 +181,7 @@ pub enum TransactionMap {
     ID = DataID::TransactionIDMap as u16,
 }
 
-/// The RocksDB map prefix for transition-related entries.
+/// The persistent storage map prefix for transition-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -189,7 +195,7 @@ pub enum TransitionMap {
     SCM = DataID::TransitionSCMMap as u16,
 }
 
-/// The RocksDB map prefix for program-related entries.
+/// The persistent storage map prefix for program-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -197,10 +203,37 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    History = DataID::FinalizeHistoryMap as u16,
+    KeyUndo = DataID::FinalizeKeyUndoMap as u16,
+    ProgramUndo = DataID::FinalizeProgramUndoMap as u16,
+    RatifiedOperations = DataID::FinalizeRatifiedOperationsMap as u16,
+    HistoryRange = DataID::FinalizeHistoryRangeMap as u16,
+    OperationValue = DataID::FinalizeOperationValueMap as u16,
+    RatifiedOperationsStart = DataID::FinalizeRatifiedOperationsStartMap as u16,
+}
+
+/// The persistent storage map prefix for the entries of the address index, i.e. the transitions,
+/// `credits.aleo/account` writes, and deployed programs that reference each address.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum AddressMap {
+    Transitions = DataID::AddressTransitionsMap as u16,
+    Accounts = DataID::AddressAccountsMap as u16,
+    Programs = DataID::AddressProgramsMap as u16,
+    Entries = DataID::AddressEntriesMap as u16,
+}
+
+/// The persistent storage map prefix for schema-related entries.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum SchemaMap {
+    Version = DataID::SchemaVersionMap as u16,
 }
 
-/// The RocksDB map prefix for test-related entries.
-// Note: the order of these variants can be changed at any point in time.
 #[cfg(test)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u16)]
@@ -212,7 +245,7 @@ pub enum TestMap {
     Test5 = DataID::Test5 as u16,
 }
 
-/// The RocksDB map prefix.
+/// The persistent storage map prefix.
 // Note: the order of these variants can NOT be changed once the database is populated:
 // - any new variant MUST be added as the last one (ignoring the Test one)
 // - any deprecated variant MUST remain in its position (it can't be removed)
@@ -290,6 +323,26 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Address
+    AddressTransitionsMap,
+    AddressAccountsMap,
+    AddressProgramsMap,
+    AddressEntriesMap,
+    // Finalize
+    FinalizeHistoryMap,
+    FinalizeKeyUndoMap,
+    FinalizeProgramUndoMap,
+    // Schema
+    SchemaVersionMap,
+    // Finalize
+    FinalizeRatifiedOperationsMap,
+    FinalizeHistoryRangeMap,
+    // Block
+    BlockPruningDepthMap,
+    BlockPrunedMap,
+    // Finalize
+    FinalizeOperationValueMap,
+    FinalizeRatifiedOperationsStartMap,
 
     // Testing
     #[cfg(test)]
@@ -303,3 +356,114 @@ enum DataID {
     #[cfg(test)]
     Test5,
 }
+
+impl DataID {
+    /// The data IDs of the maps, in the order of their values.
+    #[cfg(any(test, feature = "metrics"))]
+    const MAPS: [DataID; 73] = [
+        DataID::BFTTransmissionsMap,
+        DataID::BlockStateRootMap,
+        DataID::BlockReverseStateRootMap,
+        DataID::BlockIDMap,
+        DataID::BlockReverseIDMap,
+        DataID::BlockHeaderMap,
+        DataID::BlockAuthorityMap,
+        DataID::BlockCertificateMap,
+        DataID::BlockRatificationsMap,
+        DataID::BlockSolutionsMap,
+        DataID::BlockPuzzleCommitmentsMap,
+        DataID::BlockAbortedSolutionIDsMap,
+        DataID::BlockAbortedSolutionHeightsMap,
+        DataID::BlockTransactionsMap,
+        DataID::BlockAbortedTransactionIDsMap,
+        DataID::BlockRejectedOrAbortedTransactionIDMap,
+        DataID::BlockConfirmedTransactionsMap,
+        DataID::BlockRejectedDeploymentOrExecutionMap,
+        DataID::CurrentRoundMap,
+        DataID::RoundToHeightMap,
+        DataID::CommitteeMap,
+        DataID::DeploymentIDMap,
+        DataID::DeploymentEditionMap,
+        DataID::DeploymentReverseIDMap,
+        DataID::DeploymentOwnerMap,
+        DataID::DeploymentProgramMap,
+        DataID::DeploymentVerifyingKeyMap,
+        DataID::DeploymentCertificateMap,
+        DataID::ExecutionIDMap,
+        DataID::ExecutionReverseIDMap,
+        DataID::ExecutionInclusionMap,
+        DataID::FeeFeeMap,
+        DataID::FeeReverseFeeMap,
+        DataID::InputIDMap,
+        DataID::InputReverseIDMap,
+        DataID::InputConstantMap,
+        DataID::InputPublicMap,
+        DataID::InputPrivateMap,
+        DataID::InputRecordMap,
+        DataID::InputRecordTagMap,
+        DataID::InputExternalRecordMap,
+        DataID::OutputIDMap,
+        DataID::OutputReverseIDMap,
+        DataID::OutputConstantMap,
+        DataID::OutputPublicMap,
+        DataID::OutputPrivateMap,
+        DataID::OutputRecordMap,
+        DataID::OutputRecordNonceMap,
+        DataID::OutputExternalRecordMap,
+        DataID::OutputFutureMap,
+        DataID::TransactionIDMap,
+        DataID::TransitionLocatorMap,
+        DataID::TransitionTPKMap,
+        DataID::TransitionReverseTPKMap,
+        DataID::TransitionTCMMap,
+        DataID::TransitionReverseTCMMap,
+        DataID::TransitionSCMMap,
+        DataID::ProgramIDMap,
+        DataID::KeyValueMap,
+        DataID::AddressTransitionsMap,
+        DataID::AddressAccountsMap,
+        DataID::AddressProgramsMap,
+        DataID::AddressEntriesMap,
+        DataID::FinalizeHistoryMap,
+        DataID::FinalizeKeyUndoMap,
+        DataID::FinalizeProgramUndoMap,
+        DataID::SchemaVersionMap,
+        DataID::FinalizeRatifiedOperationsMap,
+        DataID::FinalizeHistoryRangeMap,
+        DataID::BlockPruningDepthMap,
+        DataID::BlockPrunedMap,
+        DataID::FinalizeOperationValueMap,
+        DataID::FinalizeRatifiedOperationsStartMap,
+    ];
+}
+
+/// Returns the name of the map with the given map ID, e.g. `BlockHeaderMap`, if it is known.
+#[cfg(any(test, feature = "metrics"))]
+pub(crate) fn map_name(map_id: u16) -> Option<String> {
+    DataID::MAPS.get(usize::from(map_id)).map(|data_id| format!("{data_id:?}"))
+}
+
+/// Returns the map ID and name of each map.
+#[cfg(any(test, feature = "metrics"))]
+pub(crate) fn map_names() -> impl Iterator<Item = (u16, String)> {
+    DataID::MAPS.iter().map(|data_id| (*data_id as u16, format!("{data_id:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_names() {
+        // Ensure the data IDs of the maps are listed in the order of their values, and are complete.
+        for (index, data_id) in DataID::MAPS.iter().enumerate() {
+            assert_eq!(*data_id as usize, index);
+        }
+        assert_eq!(DataID::MAPS.len(), DataID::Test as usize);
+
+        assert_eq!(map_name(BlockMap::Header as u16), Some("BlockHeaderMap".to_string()));
+        assert_eq!(map_name(ProgramMap::KeyValueID as u16), Some("KeyValueMap".to_string()));
+        assert_eq!(map_name(DataID::Test as u16), None);
+        assert_eq!(map_names().count(), DataID::MAPS.len());
+    }
+}
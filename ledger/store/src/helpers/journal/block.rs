@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "address-index")]
+use crate::{helpers::journal::AddressJournal, AddressStore};
+use crate::{
+    helpers::journal::{
+        internal::{self, Database, JournalMap},
+        BlockMap,
+        MapID,
+        TransactionJournal,
+        TransitionJournal,
+    },
+    BlockStorage,
+    ConfirmedTxType,
+    TransactionStore,
+    TransitionStore,
+};
+use console::{prelude::*, types::Field};
+use ledger_authority::Authority;
+use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_coinbase::PuzzleCommitment;
+use synthesizer_program::FinalizeOperation;
+
+use aleo_std_storage::StorageMode;
+
+/// A journal block storage.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct BlockJournal<N: Network> {
+    /// The mapping of `block height` to `state root`.
+    state_root_map: JournalMap<u32, N::StateRoot>,
+    /// The mapping of `state root` to `block height`.
+    reverse_state_root_map: JournalMap<N::StateRoot, u32>,
+    /// The mapping of `block height` to `block hash`.
+    id_map: JournalMap<u32, N::BlockHash>,
+    /// The mapping of `block hash` to `block height`.
+    reverse_id_map: JournalMap<N::BlockHash, u32>,
+    /// The header map.
+    header_map: JournalMap<N::BlockHash, Header<N>>,
+    /// The authority map.
+    authority_map: JournalMap<N::BlockHash, Authority<N>>,
+    /// The certificate map.
+    certificate_map: JournalMap<Field<N>, (u32, u64)>,
+    /// The ratifications map.
+    ratifications_map: JournalMap<N::BlockHash, Ratifications<N>>,
+    /// The solutions map.
+    solutions_map: JournalMap<N::BlockHash, Solutions<N>>,
+    /// The puzzle commitments map.
+    puzzle_commitments_map: JournalMap<PuzzleCommitment<N>, u32>,
+    /// The aborted solution IDs map.
+    aborted_solution_ids_map: JournalMap<N::BlockHash, Vec<PuzzleCommitment<N>>>,
+    /// The aborted solution heights map.
+    aborted_solution_heights_map: JournalMap<PuzzleCommitment<N>, u32>,
+    /// The transactions map.
+    transactions_map: JournalMap<N::BlockHash, Vec<N::TransactionID>>,
+    /// The aborted transaction IDs map.
+    aborted_transaction_ids_map: JournalMap<N::BlockHash, Vec<N::TransactionID>>,
+    /// The rejected or aborted transaction ID map.
+    rejected_or_aborted_transaction_id_map: JournalMap<N::TransactionID, N::BlockHash>,
+    /// The confirmed transactions map.
+    confirmed_transactions_map:
+        JournalMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
+    /// The rejected deployment or execution map.
+    rejected_deployment_or_execution_map: JournalMap<Field<N>, Rejected<N>>,
+    /// The mapping of `PRUNING_DEPTH_KEY` to the pruning depth.
+    pruning_depth_map: JournalMap<u8, u32>,
+    /// The mapping of pruned `block hash` to `block height`.
+    pruned_map: JournalMap<N::BlockHash, u32>,
+    /// The transaction store.
+    transaction_store: TransactionStore<N, TransactionJournal<N>>,
+    /// The address index store.
+    #[cfg(feature = "address-index")]
+    address_store: AddressStore<N, AddressJournal<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> BlockStorage<N> for BlockJournal<N> {
+    type StateRootMap = JournalMap<u32, N::StateRoot>;
+    type ReverseStateRootMap = JournalMap<N::StateRoot, u32>;
+    type IDMap = JournalMap<u32, N::BlockHash>;
+    type ReverseIDMap = JournalMap<N::BlockHash, u32>;
+    type HeaderMap = JournalMap<N::BlockHash, Header<N>>;
+    type AuthorityMap = JournalMap<N::BlockHash, Authority<N>>;
+    type CertificateMap = JournalMap<Field<N>, (u32, u64)>;
+    type RatificationsMap = JournalMap<N::BlockHash, Ratifications<N>>;
+    type SolutionsMap = JournalMap<N::BlockHash, Solutions<N>>;
+    type PuzzleCommitmentsMap = JournalMap<PuzzleCommitment<N>, u32>;
+    type AbortedSolutionIDsMap = JournalMap<N::BlockHash, Vec<PuzzleCommitment<N>>>;
+    type AbortedSolutionHeightsMap = JournalMap<PuzzleCommitment<N>, u32>;
+    type TransactionsMap = JournalMap<N::BlockHash, Vec<N::TransactionID>>;
+    type AbortedTransactionIDsMap = JournalMap<N::BlockHash, Vec<N::TransactionID>>;
+    type RejectedOrAbortedTransactionIDMap = JournalMap<N::TransactionID, N::BlockHash>;
+    type ConfirmedTransactionsMap = JournalMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
+    type RejectedDeploymentOrExecutionMap = JournalMap<Field<N>, Rejected<N>>;
+    type PruningDepthMap = JournalMap<u8, u32>;
+    type PrunedMap = JournalMap<N::BlockHash, u32>;
+    type TransactionStorage = TransactionJournal<N>;
+    type TransitionStorage = TransitionJournal<N>;
+    #[cfg(feature = "address-index")]
+    type AddressStorage = AddressJournal<N>;
+
+    /// Initializes the block storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the address index store.
+        #[cfg(feature = "address-index")]
+        let address_store = AddressStore::<N, AddressJournal<N>>::open(storage.clone())?;
+        // Initialize the transition store.
+        let transition_store = TransitionStore::<N, TransitionJournal<N>>::open(storage.clone())?;
+        // Initialize the transaction store.
+        let transaction_store = TransactionStore::<N, TransactionJournal<N>>::open(transition_store)?;
+        // Return the block storage.
+        Ok(Self {
+            state_root_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::StateRoot))?,
+            reverse_state_root_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ReverseStateRoot))?,
+            id_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ID))?,
+            reverse_id_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ReverseID))?,
+            header_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Header))?,
+            authority_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Authority))?,
+            certificate_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Certificate))?,
+            ratifications_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Ratifications))?,
+            solutions_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Solutions))?,
+            puzzle_commitments_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::PuzzleCommitments))?,
+            aborted_solution_ids_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedSolutionIDs))?,
+            aborted_solution_heights_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedSolutionHeights))?,
+            transactions_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Transactions))?,
+            aborted_transaction_ids_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
+            rejected_or_aborted_transaction_id_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
+            confirmed_transactions_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
+            rejected_deployment_or_execution_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            pruning_depth_map: internal::JournalDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::PruningDepth))?,
+            pruned_map: internal::JournalDB::open_map(N::ID, storage, MapID::Block(BlockMap::Pruned))?,
+            transaction_store,
+            #[cfg(feature = "address-index")]
+            address_store,
+        })
+    }
+
+    /// Returns the state root map.
+    fn state_root_map(&self) -> &Self::StateRootMap {
+        &self.state_root_map
+    }
+
+    /// Returns the reverse state root map.
+    fn reverse_state_root_map(&self) -> &Self::ReverseStateRootMap {
+        &self.reverse_state_root_map
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the header map.
+    fn header_map(&self) -> &Self::HeaderMap {
+        &self.header_map
+    }
+
+    /// Returns the authority map.
+    fn authority_map(&self) -> &Self::AuthorityMap {
+        &self.authority_map
+    }
+
+    /// Returns the certificate map.
+    fn certificate_map(&self) -> &Self::CertificateMap {
+        &self.certificate_map
+    }
+
+    /// Returns the ratifications map.
+    fn ratifications_map(&self) -> &Self::RatificationsMap {
+        &self.ratifications_map
+    }
+
+    /// Returns the solutions map.
+    fn solutions_map(&self) -> &Self::SolutionsMap {
+        &self.solutions_map
+    }
+
+    /// Returns the puzzle commitments map.
+    fn puzzle_commitments_map(&self) -> &Self::PuzzleCommitmentsMap {
+        &self.puzzle_commitments_map
+    }
+
+    /// Returns the aborted solution IDs map.
+    fn aborted_solution_ids_map(&self) -> &Self::AbortedSolutionIDsMap {
+        &self.aborted_solution_ids_map
+    }
+
+    /// Returns the aborted solution heights map.
+    fn aborted_solution_heights_map(&self) -> &Self::AbortedSolutionHeightsMap {
+        &self.aborted_solution_heights_map
+    }
+
+    /// Returns the transactions map.
+    fn transactions_map(&self) -> &Self::TransactionsMap {
+        &self.transactions_map
+    }
+
+    /// Returns the aborted transaction IDs map.
+    fn aborted_transaction_ids_map(&self) -> &Self::AbortedTransactionIDsMap {
+        &self.aborted_transaction_ids_map
+    }
+
+    /// Returns the rejected transaction ID or aborted transaction ID map.
+    fn rejected_or_aborted_transaction_id_map(&self) -> &Self::RejectedOrAbortedTransactionIDMap {
+        &self.rejected_or_aborted_transaction_id_map
+    }
+
+    /// Returns the confirmed transactions map.
+    fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap {
+        &self.confirmed_transactions_map
+    }
+
+    /// Returns the rejected deployment or execution map.
+    fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap {
+        &self.rejected_deployment_or_execution_map
+    }
+
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap {
+        &self.pruning_depth_map
+    }
+
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
+    /// Returns the transaction store.
+    fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
+        &self.transaction_store
+    }
+
+    /// Returns the address index store.
+    #[cfg(feature = "address-index")]
+    fn address_store(&self) -> &AddressStore<N, Self::AddressStorage> {
+        &self.address_store
+    }
+}
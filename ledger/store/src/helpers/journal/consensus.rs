@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::journal::{BlockJournal, FinalizeJournal, TransactionJournal, TransitionJournal},
+    BlockStore,
+    ConsensusStorage,
+    FinalizeStore,
+};
+use console::prelude::*;
+
+use aleo_std_storage::StorageMode;
+
+/// A journal consensus storage.
+#[derive(Clone)]
+pub struct ConsensusJournal<N: Network> {
+    /// The finalize store.
+    finalize_store: FinalizeStore<N, FinalizeJournal<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockJournal<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusJournal<N> {
+    type FinalizeStorage = FinalizeJournal<N>;
+    type BlockStorage = BlockJournal<N>;
+    type TransactionStorage = TransactionJournal<N>;
+    type TransitionStorage = TransitionJournal<N>;
+
+    /// Initializes the consensus storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the finalize store.
+        let finalize_store = FinalizeStore::<N, FinalizeJournal<N>>::open(storage.clone())?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockJournal<N>>::open(storage)?;
+        // Return the consensus storage.
+        Ok(Self {
+            finalize_store,
+            block_store,
+        })
+    }
+
+    /// Returns the finalize store.
+    fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage> {
+        &self.finalize_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+}
@@ -0,0 +1,600 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use super::*;
+use crate::helpers::{Map, MapRead};
+
+use core::{fmt, fmt::Debug, hash::Hash, mem};
+use indexmap::IndexMap;
+use std::{borrow::Cow, ops::Deref, sync::atomic::Ordering};
+use tracing::error;
+
+#[derive(Clone)]
+pub struct JournalMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>(
+    pub(super) Arc<InnerJournalMap<K, V>>,
+);
+
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Deref for JournalMap<K, V> {
+    type Target = InnerJournalMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct InnerJournalMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> {
+    pub(super) database: JournalDB,
+    pub(super) context: Vec<u8>,
+    /// The tracker for whether a database transaction is in progress.
+    pub(super) batch_in_progress: AtomicBool,
+    /// The database transaction.
+    pub(super) atomic_batch: Mutex<Vec<(K, Option<V>)>>,
+    /// The checkpoint stack for the batched operations within the map.
+    pub(super) checkpoints: Mutex<Vec<usize>>,
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+> Map<'a, K, V> for JournalMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        // Determine if an atomic batch is in progress.
+        match self.is_atomic_in_progress() {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => {
+                self.atomic_batch.lock().push((key, Some(value)));
+            }
+            // Otherwise, insert the key-value pair directly into the map.
+            false => {
+                // Prepare the prefixed key and serialized value.
+                let raw_key = self.create_prefixed_key(&key)?;
+                let raw_value = bincode::serialize(&value)?;
+                self.database.put(raw_key, raw_value)?;
+            }
+        }
+
+        #[cfg(feature = "metrics")]
+        map_metrics::record_insert_latency(&self.context, start);
+
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.is_atomic_in_progress() {
+            // If a batch is in progress, add the key to the batch.
+            true => {
+                self.atomic_batch.lock().push((*key, None));
+            }
+            // Otherwise, remove the key-value pair directly from the map.
+            false => {
+                // Prepare the prefixed key.
+                let raw_key = self.create_prefixed_key(key)?;
+                self.database.delete(raw_key)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Increment the atomic depth index.
+        self.database.atomic_depth.fetch_add(1, Ordering::SeqCst);
+
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+        // Ensure that the database atomic batch is empty; skip this check if the atomic
+        // writes are paused, as there may be pending operations.
+        if !self.database.are_atomic_writes_paused() {
+            assert!(self.database.atomic_batch.lock().is_empty());
+        }
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
+    /// we roll back all future operations, and return to the start of this checkpoint.
+    ///
+    fn atomic_checkpoint(&self) {
+        // Push the current length of the atomic batch to the checkpoint stack.
+        let mut checkpoints = self.checkpoints.lock();
+        checkpoints.push(self.atomic_batch.lock().len());
+        #[cfg(feature = "metrics")]
+        map_metrics::record_checkpoint_depth(&self.context, checkpoints.len());
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        // Removes the latest checkpoint.
+        let _ = self.checkpoints.lock().pop();
+    }
+
+    ///
+    /// Removes all pending operations to the last `atomic_checkpoint`
+    /// (or to `start_atomic` if no checkpoints have been created).
+    ///
+    fn atomic_rewind(&self) {
+        // Acquire the write lock on the atomic batch.
+        let mut atomic_batch = self.atomic_batch.lock();
+
+        // Retrieve the last checkpoint.
+        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+
+        // Remove all operations after the checkpoint.
+        atomic_batch.truncate(checkpoint);
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        // Clear the atomic batch.
+        self.atomic_batch.lock().clear();
+        // Clear the checkpoint stack.
+        self.checkpoints.lock().clear();
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+        // Clear the database-wide atomic batch.
+        self.database.atomic_batch.lock().clear();
+        // Reset the atomic batch depth.
+        self.database.atomic_depth.store(0, Ordering::SeqCst);
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch belonging to the map.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+
+        if !operations.is_empty() {
+            // Insert the operations into an index map to remove any operations that would have been overwritten anyways.
+            let operations: IndexMap<_, _> = IndexMap::from_iter(operations);
+
+            // Prepare the key and value for each queued operation.
+            //
+            // Note: This step is taken to ensure (with 100% certainty) that there will be
+            // no chance to fail partway through committing the queued operations.
+            //
+            // The expected behavior is that either all the operations will be committed
+            // or none of them will be.
+            let prepared_operations = operations
+                .into_iter()
+                .map(|(key, value)| match value {
+                    Some(value) => Ok((self.create_prefixed_key(&key)?, Some(bincode::serialize(&value)?))),
+                    None => Ok((self.create_prefixed_key(&key)?, None)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Enqueue all the operations from the map in the database-wide batch.
+            let mut atomic_batch = self.database.atomic_batch.lock();
+            for (raw_key, raw_value) in prepared_operations {
+                match raw_value {
+                    Some(raw_value) => atomic_batch.put(raw_key, raw_value),
+                    None => atomic_batch.delete(raw_key),
+                };
+            }
+        }
+
+        // Clear the checkpoint stack.
+        self.checkpoints.lock().clear();
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+
+        // Subtract the atomic depth index.
+        let previous_atomic_depth = self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst);
+
+        // Ensure that the value of `atomic_depth` doesn't overflow, meaning that all the
+        // calls to `start_atomic` have corresponding calls to `finish_atomic`.
+        assert!(previous_atomic_depth != 0);
+
+        // If we're at depth 0, it is the final call to `finish_atomic` and the
+        // atomic write batch can be physically executed. This is skipped if the
+        // atomic writes are paused.
+        if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
+            // Empty the collection of pending operations.
+            let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            #[cfg(feature = "metrics")]
+            map_metrics::record_atomic_batch_size(batch.len());
+            // Execute all the operations atomically.
+            self.database.write(batch)?;
+            // Ensure that the database atomic batch is empty.
+            assert!(self.database.atomic_batch.lock().is_empty());
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Once called, the subsequent atomic write batches will be queued instead of being executed
+    /// at the end of their scope. `unpause_atomic_writes` needs to be called in order to
+    /// restore the usual behavior.
+    ///
+    fn pause_atomic_writes(&self) -> Result<()> {
+        self.database.pause_atomic_writes()
+    }
+
+    ///
+    /// Executes all of the queued writes as a single atomic operation and restores the usual
+    /// behavior of atomic write batches that was altered by calling `pause_atomic_writes`.
+    ///
+    fn unpause_atomic_writes<const DISCARD_BATCH: bool>(&self) -> Result<()> {
+        self.database.unpause_atomic_writes::<DISCARD_BATCH>()
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+> MapRead<'a, K, V> for JournalMap<K, V>
+{
+    type Iterator = Iter<'a, K, V>;
+    type Keys = Keys<'a, K>;
+    type PendingIterator =
+        core::iter::Map<indexmap::map::IntoIter<K, Option<V>>, fn((K, Option<V>)) -> (Cow<'a, K>, Option<Cow<'a, V>>)>;
+    type Values = Values<'a, V>;
+
+    ///
+    /// Returns the number of confirmed entries in the map.
+    ///
+    fn len_confirmed(&self) -> usize {
+        self.database.prefix_len(&self.context)
+    }
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        self.get_raw(key).map(|v| v.is_some())
+    }
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    /// This method first checks the atomic batch, and if it does not exist, then checks the map.
+    ///
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // If a batch is in progress, check the atomic batch first.
+        if self.is_atomic_in_progress() {
+            // If the key is present in the atomic batch, then check if the value is 'Some(V)'.
+            // We iterate from the back of the `atomic_batch` to find the latest value.
+            if let Some((_, value)) = self.atomic_batch.lock().iter().rev().find(|&(k, _)| k.borrow() == key) {
+                // If the value is 'Some(V)', then the key exists.
+                // If the value is 'Some(None)', then the key is scheduled to be removed.
+                return Ok(value.is_some());
+            }
+        }
+
+        // Otherwise, check the map for the key.
+        self.contains_key_confirmed(key)
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let value = match self.get_raw(key) {
+            Ok(Some(bytes)) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        };
+        #[cfg(feature = "metrics")]
+        map_metrics::record_get_confirmed_latency(&self.context, start);
+        value
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_pending<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early if there is no atomic batch in progress.
+        if self.is_atomic_in_progress() {
+            // We iterate from the back of the `atomic_batch` to find the latest value.
+            self.atomic_batch.lock().iter().rev().find(|&(k, _)| k.borrow() == key).map(|(_, value)| value).cloned()
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the atomic batch.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        let filtered_atomic_batch: IndexMap<_, _> = IndexMap::from_iter(self.atomic_batch.lock().clone());
+        filtered_atomic_batch.into_iter().map(|(k, v)| (Cow::Owned(k), v.map(|v| Cow::Owned(v))))
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter_confirmed(&'a self) -> Self::Iterator {
+        Iter::new(self.database.prefix_iterator(&self.context))
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys_confirmed(&'a self) -> Self::Keys {
+        Keys::new(self.database.prefix_iterator(&self.context))
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values_confirmed(&'a self) -> Self::Values {
+        Values::new(self.database.prefix_iterator(&self.context))
+    }
+}
+
+/// An iterator over all key-value pairs in a data map.
+pub struct Iter<
+    'a,
+    K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+    V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
+> {
+    db_iter: PrefixIterator,
+    _phantom: PhantomData<&'a (K, V)>,
+}
+
+impl<
+    'a,
+    K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+    V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
+> Iter<'a, K, V>
+{
+    pub(super) fn new(db_iter: PrefixIterator) -> Self {
+        Self { db_iter, _phantom: PhantomData }
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+    V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned,
+> Iterator for Iter<'a, K, V>
+{
+    type Item = (Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.db_iter.next()?;
+
+        // Deserialize the key and value.
+        let key = bincode::deserialize(&key[PREFIX_LEN..])
+            .map_err(|e| {
+                error!("JournalDB Iter deserialize(key) error: {e}");
+            })
+            .ok()?;
+        let value = bincode::deserialize(&value)
+            .map_err(|e| {
+                error!("JournalDB Iter deserialize(value) error: {e}");
+            })
+            .ok()?;
+
+        Some((Cow::Owned(key), Cow::Owned(value)))
+    }
+}
+
+/// An iterator over the keys of a prefix.
+pub struct Keys<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> {
+    db_iter: PrefixIterator,
+    _phantom: PhantomData<&'a K>,
+}
+
+impl<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> Keys<'a, K> {
+    pub(crate) fn new(db_iter: PrefixIterator) -> Self {
+        Self { db_iter, _phantom: PhantomData }
+    }
+}
+
+impl<'a, K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> Iterator for Keys<'a, K> {
+    type Item = Cow<'a, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, _) = self.db_iter.next()?;
+
+        // Deserialize the key.
+        let key = bincode::deserialize(&key[PREFIX_LEN..])
+            .map_err(|e| {
+                error!("JournalDB Keys deserialize(key) error: {e}");
+            })
+            .ok()?;
+
+        Some(Cow::Owned(key))
+    }
+}
+
+/// An iterator over the values of a prefix.
+pub struct Values<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> {
+    db_iter: PrefixIterator,
+    _phantom: PhantomData<&'a V>,
+}
+
+impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> Values<'a, V> {
+    pub(crate) fn new(db_iter: PrefixIterator) -> Self {
+        Self { db_iter, _phantom: PhantomData }
+    }
+}
+
+impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator for Values<'a, V> {
+    type Item = Cow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, value) = self.db_iter.next()?;
+
+        // Deserialize the value.
+        let value = bincode::deserialize(&value)
+            .map_err(|e| {
+                error!("JournalDB Values deserialize(value) error: {e}");
+            })
+            .ok()?;
+
+        Some(Cow::Owned(value))
+    }
+}
+
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> JournalMap<K, V> {
+    #[inline]
+    fn create_prefixed_key<Q>(&self, key: &Q) -> Result<Vec<u8>>
+    where
+        K: Borrow<Q>,
+        Q: Serialize + ?Sized,
+    {
+        let mut raw_key = self.context.clone();
+        bincode::serialize_into(&mut raw_key, &key)?;
+        Ok(raw_key)
+    }
+
+    fn get_raw<Q>(&self, key: &Q) -> Result<Option<Vec<u8>>>
+    where
+        K: Borrow<Q>,
+        Q: Serialize + ?Sized,
+    {
+        let raw_key = self.create_prefixed_key(key)?;
+        self.database.get(&raw_key)
+    }
+}
+
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> fmt::Debug for JournalMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JournalMap").field("context", &self.context).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::journal::{internal::tests::temp_dir, MapID, TestMap};
+
+    use serial_test::serial;
+    use tracing_test::traced_test;
+
+    /// Opens a test map.
+    fn open_test_map() -> JournalMap<usize, String> {
+        JournalDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open journal map")
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_insert_and_get_speculative() {
+        crate::helpers::test_helpers::map::check_insert_and_get_speculative(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_remove_and_get_speculative() {
+        crate::helpers::test_helpers::map::check_remove_and_get_speculative(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_contains_key() {
+        crate::helpers::test_helpers::map::check_contains_key(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_check_iterators_match() {
+        crate::helpers::test_helpers::map::check_iterators_match(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_atomic_writes_are_batched() {
+        crate::helpers::test_helpers::map::check_atomic_writes_are_batched(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_atomic_writes_can_be_aborted() {
+        crate::helpers::test_helpers::map::check_atomic_writes_can_be_aborted(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_atomic_checkpoints() {
+        crate::helpers::test_helpers::map::check_atomic_checkpoints(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_pause_atomic_writes() {
+        crate::helpers::test_helpers::map::check_pause_atomic_writes(open_test_map());
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_pause_atomic_writes_are_deferred() {
+        crate::helpers::test_helpers::map::check_pause_atomic_writes_are_deferred(open_test_map());
+    }
+}
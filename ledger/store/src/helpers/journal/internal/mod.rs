@@ -0,0 +1,543 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::helpers::id::*;
+
+#[cfg(feature = "metrics")]
+use crate::helpers::map_metrics;
+use crate::helpers::{ensure_schema_version, schema_version, SchemaStore, MIGRATIONS, SCHEMA_VERSION};
+
+mod map;
+pub use map::*;
+
+mod nested_map;
+pub use nested_map::*;
+
+#[cfg(test)]
+mod tests;
+
+use aleo_std_storage::StorageMode;
+use anyhow::{bail, ensure, Result};
+use once_cell::sync::OnceCell;
+use parking_lot::{Mutex, RwLock};
+use redb::{ReadOnlyTable, TableDefinition};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    borrow::Borrow,
+    fs,
+    marker::PhantomData,
+    mem,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tracing::error;
+
+pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
+
+/// The file name of the database, within the ledger directory.
+pub const DATABASE_FILE: &str = "ledger.redb";
+/// The table holding all of the entries of the database.
+const ENTRIES_TABLE: TableDefinition<&[u8], &[u8]> = TableDefinition::new("entries");
+
+pub trait Database {
+    /// Opens the database.
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_map<
+        S: Clone + Into<StorageMode>,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<JournalMap<K, V>>;
+
+    /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_nested_map<
+        S: Clone + Into<StorageMode>,
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<NestedJournalMap<M, K, V>>;
+}
+
+/// A set of write operations that are committed to the database as a single transaction.
+#[derive(Clone, Debug, Default)]
+pub struct WriteBatch(Vec<(Vec<u8>, Option<Vec<u8>>)>);
+
+impl WriteBatch {
+    /// Queues the insertion of the given key-value pair.
+    pub fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.0.push((key, Some(value)));
+    }
+
+    /// Queues the removal of the given key.
+    pub fn delete(&mut self, key: Vec<u8>) {
+        self.0.push((key, None));
+    }
+
+    /// Returns the number of queued operations.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no queued operations.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Clears the queued operations.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// An instance of a journal database.
+///
+/// The database is a single `redb` file, i.e. an on-disk copy-on-write B-tree; every write batch is
+/// committed as a single transaction, so a crash in the middle of a write leaves the previously
+/// committed entries intact. Reads are served from the file, and never load a whole map into memory.
+#[derive(Clone)]
+pub struct JournalDB {
+    /// The underlying database; its write lock is only acquired to compact the database.
+    database: Arc<RwLock<redb::Database>>,
+    /// The network ID.
+    network_id: u16,
+    /// The storage mode.
+    storage_mode: StorageMode,
+    /// The low-level database transaction that gets executed atomically at the end
+    /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
+    pub(super) atomic_batch: Arc<Mutex<WriteBatch>>,
+    /// The depth of the current atomic write batch; it gets incremented with every call
+    /// to `start_atomic` and decremented with each call to `finish_atomic`.
+    pub(super) atomic_depth: Arc<AtomicUsize>,
+    /// A flag indicating whether the atomic writes are currently paused.
+    pub(super) atomic_writes_paused: Arc<AtomicBool>,
+}
+
+impl Database for JournalDB {
+    /// Opens the database.
+    ///
+    /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
+    /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self> {
+        static DB: OnceCell<JournalDB> = OnceCell::new();
+
+        // Retrieve the database.
+        let database = DB
+            .get_or_try_init(|| {
+                let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage.clone().into());
+                let database = Self::open_at(&primary, network_id, storage.clone().into())?;
+
+                // Ensure the database uses the current schema version, migrating it if needed.
+                ensure_schema_version(&database, network_id, MIGRATIONS, SCHEMA_VERSION)?;
+
+                Ok::<_, anyhow::Error>(database)
+            })?
+            .clone();
+
+        // Ensure the database network ID and storage mode match.
+        match database.network_id == network_id && database.storage_mode == storage.into() {
+            true => Ok(database),
+            false => bail!("Mismatching network ID or storage mode in the database"),
+        }
+    }
+
+    /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_map<
+        S: Clone + Into<StorageMode>,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<JournalMap<K, V>> {
+        // Open the journal database.
+        let database = Self::open(network_id, storage)?;
+        // Return the JournalMap.
+        Ok(database.map_from_db(map_id))
+    }
+
+    /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_nested_map<
+        S: Clone + Into<StorageMode>,
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<NestedJournalMap<M, K, V>> {
+        // Open the journal database.
+        let database = Self::open(network_id, storage)?;
+        // Return the NestedJournalMap.
+        Ok(database.nested_map_from_db(map_id))
+    }
+}
+
+impl SchemaStore for JournalDB {
+    /// Returns the value stored under the given raw key, if any.
+    fn get_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.get(key)
+    }
+
+    /// Returns the raw key-value pairs whose key starts with the given prefix, in key order.
+    fn prefix_entries(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut entries = Vec::new();
+        for entry in self.read_table()?.range::<&[u8]>(prefix..)? {
+            let (key, value) = entry?;
+            if !key.value().starts_with(prefix) {
+                break;
+            }
+            entries.push((key.value().to_vec(), value.value().to_vec()));
+        }
+        Ok(entries)
+    }
+
+    /// Returns `true` if any raw key starts with the given prefix.
+    fn contains_prefix(&self, prefix: &[u8]) -> Result<bool> {
+        match self.read_table()?.range::<&[u8]>(prefix..)?.next() {
+            Some(entry) => Ok(entry?.0.value().starts_with(prefix)),
+            None => Ok(false),
+        }
+    }
+
+    /// Atomically applies the given raw operations, where a `None` value denotes a removal.
+    fn apply_raw(&self, operations: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<()> {
+        self.write(WriteBatch(operations))
+    }
+}
+
+impl JournalDB {
+    /// Opens the database in the given directory, creating it if it does not exist.
+    pub(super) fn open_at(directory: &Path, network_id: u16, storage_mode: StorageMode) -> Result<Self> {
+        // Ensure the directory exists.
+        fs::create_dir_all(directory)?;
+        let path = directory.join(DATABASE_FILE);
+
+        // Open the database.
+        let database = match redb::Database::create(&path) {
+            Ok(database) => database,
+            Err(error) => bail!("Failed to open '{}' - {error}", path.display()),
+        };
+
+        // Ensure the entries table exists, so that it can be opened by read transactions.
+        let transaction = database.begin_write()?;
+        transaction.open_table(ENTRIES_TABLE)?;
+        transaction.commit()?;
+
+        Ok(JournalDB {
+            database: Arc::new(RwLock::new(database)),
+            network_id,
+            storage_mode,
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+        })
+    }
+
+    /// Returns the value for the given key, if it exists.
+    pub fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.read_table()?.get(key)?.map(|value| value.value().to_vec()))
+    }
+
+    /// Inserts the given key-value pair.
+    pub fn put(&self, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        batch.put(key, value);
+        self.write(batch)
+    }
+
+    /// Removes the given key.
+    pub fn delete(&self, key: Vec<u8>) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        batch.delete(key);
+        self.write(batch)
+    }
+
+    /// Commits the given batch to the database as a single transaction.
+    pub fn write(&self, batch: WriteBatch) -> Result<()> {
+        // Return early if there is nothing to write.
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        // Acquire the read lock, so that the database is not compacted during the transaction.
+        let database = self.database.read();
+        let transaction = database.begin_write()?;
+        {
+            let mut table = transaction.open_table(ENTRIES_TABLE)?;
+            for (key, value) in &batch.0 {
+                match value {
+                    Some(value) => table.insert(key.as_slice(), value.as_slice())?,
+                    None => table.remove(key.as_slice())?,
+                };
+            }
+        }
+        transaction.commit()?;
+
+        Ok(())
+    }
+
+    /// Returns an iterator over the committed entries whose key starts with the given prefix, in key order.
+    pub fn prefix_iterator(&self, prefix: &[u8]) -> PrefixIterator {
+        let range = match self.read_table().and_then(|table| Ok(table.range::<&[u8]>(prefix..)?)) {
+            Ok(range) => Some(range),
+            Err(error) => {
+                error!("Failed to iterate over the journal database - {error}");
+                None
+            }
+        };
+        PrefixIterator { range, prefix: prefix.to_vec() }
+    }
+
+    /// Returns the number of committed entries whose key starts with the given prefix.
+    pub fn prefix_len(&self, prefix: &[u8]) -> usize {
+        self.prefix_iterator(prefix).count()
+    }
+
+    /// Returns the schema version of the storage, if it has one, without migrating (or writing to) the storage.
+    ///
+    /// Note: The storage must not be opened by this process, as the database file is locked while it is open.
+    pub fn read_schema_version<S: Into<StorageMode>>(network_id: u16, storage: S) -> Result<Option<u32>> {
+        let storage_mode = storage.into();
+        let path = aleo_std_storage::aleo_ledger_dir(network_id, storage_mode.clone()).join(DATABASE_FILE);
+        // A storage without a database file has no schema version.
+        if !path.exists() {
+            return Ok(None);
+        }
+        // Open the existing database, without creating its entries table.
+        let database = match redb::Database::open(&path) {
+            Ok(database) => database,
+            Err(error) => bail!("Failed to open '{}' - {error}", path.display()),
+        };
+        let database = JournalDB {
+            database: Arc::new(RwLock::new(database)),
+            network_id,
+            storage_mode,
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+        };
+        schema_version(&database, network_id)
+    }
+
+    /// Records the number of entries and bytes of each map of the network, as gauges labelled by the map name.
+    ///
+    /// Note: This reads every entry of the network, so it is not run on writes, and should be called sparingly,
+    /// e.g. periodically by the node.
+    #[cfg(feature = "metrics")]
+    pub fn record_map_metrics(&self) {
+        let mut sizes = map_metrics::MapSizes::default();
+        for (key, value) in self.prefix_iterator(&self.network_id.to_le_bytes()) {
+            sizes.add(&key, value.len());
+        }
+        sizes.record();
+    }
+
+    /// Compacts the database file, releasing the space of the overwritten and removed entries.
+    ///
+    /// Compaction fails if an iterator over the database is still alive.
+    pub fn compact(&self) -> Result<()> {
+        // This operation is only intended to be performed outside of atomic batches.
+        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, "Cannot compact the journal during an atomic batch");
+
+        // Acquire the write lock, so that no writes take place during compaction.
+        self.database.write().compact()?;
+        Ok(())
+    }
+
+    /// Opens the entries table in a new read transaction.
+    fn read_table(&self) -> Result<ReadOnlyTable<&'static [u8], &'static [u8]>> {
+        Ok(self.database.read().begin_read()?.open_table(ENTRIES_TABLE)?)
+    }
+
+    /// Returns a map with the given `map_id` in this database.
+    fn map_from_db<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        &self,
+        map_id: T,
+    ) -> JournalMap<K, V> {
+        JournalMap(Arc::new(InnerJournalMap {
+            database: self.clone(),
+            context: self.create_context(map_id),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }))
+    }
+
+    /// Returns a nested map with the given `map_id` in this database.
+    fn nested_map_from_db<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        &self,
+        map_id: T,
+    ) -> NestedJournalMap<M, K, V> {
+        NestedJournalMap {
+            database: self.clone(),
+            context: self.create_context(map_id),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+
+    /// Combines the network ID and the given `map_id` into a map prefix.
+    fn create_context<T: Into<u16>>(&self, map_id: T) -> Vec<u8> {
+        let mut context = self.network_id.to_le_bytes().to_vec();
+        context.extend_from_slice(&(map_id.into()).to_le_bytes());
+        context
+    }
+
+    /// Pause the execution of atomic writes for the entire database.
+    fn pause_atomic_writes(&self) -> Result<()> {
+        // This operation is only intended to be performed before or after
+        // atomic batches - never in the middle of them.
+        assert_eq!(self.atomic_depth.load(Ordering::SeqCst), 0);
+
+        // Set the flag indicating that the pause is in effect.
+        let already_paused = self.atomic_writes_paused.swap(true, Ordering::SeqCst);
+        // Make sure that we haven't already paused atomic writes (which would
+        // indicate a logic bug).
+        assert!(!already_paused);
+
+        Ok(())
+    }
+
+    /// Unpause the execution of atomic writes for the entire database; this
+    /// executes all the writes that have been queued since they were paused.
+    fn unpause_atomic_writes<const DISCARD_BATCH: bool>(&self) -> Result<()> {
+        // Ensure the call to unpause is only performed before or after an atomic batch scope
+        // - and never in the middle of one (otherwise there is a fundamental logic bug).
+        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, "Atomic depth must be 0 to unpause atomic writes");
+
+        // Ensure the database is paused (otherwise there is a fundamental logic bug).
+        let currently_paused = self.atomic_writes_paused.load(Ordering::SeqCst);
+        ensure!(currently_paused, "Atomic writes must be paused to unpause them");
+
+        // Every atomic storage operation that has accumulated from the moment the
+        // writes have been paused is executed as a single atomic batch.
+        let batch = mem::take(&mut *self.atomic_batch.lock());
+        if !DISCARD_BATCH {
+            #[cfg(feature = "metrics")]
+            map_metrics::record_atomic_batch_size(batch.len());
+            self.write(batch)?;
+        }
+
+        // Unset the flag indicating that the pause is in effect.
+        self.atomic_writes_paused.store(false, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    /// Checks whether the atomic writes are currently paused.
+    fn are_atomic_writes_paused(&self) -> bool {
+        self.atomic_writes_paused.load(Ordering::SeqCst)
+    }
+
+    /// Opens the test database.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        use console::prelude::{Rng, TestRng};
+
+        // Ensure the `temp_dir` is unique.
+        let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
+
+        // Construct the directory for the test database.
+        let primary = match dev {
+            Some(dev) => temp_dir.join(dev.to_string()),
+            None => temp_dir,
+        };
+
+        // Open the test database.
+        Self::open_at(&primary, u16::MAX, StorageMode::from(primary.clone()))
+    }
+
+    /// Opens the test map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<JournalMap<K, V>> {
+        Ok(Self::open_testing(temp_dir, dev)?.map_from_db(map_id))
+    }
+
+    /// Opens the test nested map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_nested_map_testing<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<NestedJournalMap<M, K, V>> {
+        Ok(Self::open_testing(temp_dir, dev)?.nested_map_from_db(map_id))
+    }
+}
+
+/// An iterator over the committed entries whose key starts with a given prefix, in key order.
+///
+/// The entries are read lazily, from the snapshot of the database at the time the iterator was created.
+pub struct PrefixIterator {
+    /// The range of the entries, starting at the prefix; `None` once the iterator is exhausted.
+    range: Option<redb::Range<'static, &'static [u8], &'static [u8]>>,
+    /// The prefix of the keys.
+    prefix: Vec<u8>,
+}
+
+impl Iterator for PrefixIterator {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = match self.range.as_mut()?.next() {
+            Some(Ok((key, value))) if key.value().starts_with(&self.prefix) => {
+                Some((key.value().to_vec(), value.value().to_vec()))
+            }
+            Some(Ok(_)) | None => None,
+            Some(Err(error)) => {
+                error!("Failed to read from the journal database - {error}");
+                None
+            }
+        };
+        // Release the read transaction as soon as the iterator is exhausted.
+        if entry.is_none() {
+            self.range = None;
+        }
+        entry
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::helpers::{Map, MapRead, NestedMap, NestedMapRead};
+
+use serial_test::serial;
+
+pub(crate) fn temp_dir() -> std::path::PathBuf {
+    tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+}
+
+/// Opens the journal database in the given directory.
+fn open_at(directory: &Path) -> JournalDB {
+    JournalDB::open_at(directory, u16::MAX, StorageMode::from(directory.to_path_buf()))
+        .expect("Failed to open the journal")
+}
+
+#[test]
+#[serial]
+fn test_open() {
+    let _storage = JournalDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+}
+
+#[test]
+#[serial]
+fn test_reopen_persists_the_entries() {
+    let directory = temp_dir();
+
+    {
+        let database = open_at(&directory);
+        let map = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+        let nested_map = database.nested_map_from_db::<u32, u32, String, _>(MapID::Test(TestMap::Test2));
+
+        // Insert a few entries directly, and a few in an atomic batch.
+        map.insert(1, "1".to_string()).unwrap();
+        map.insert(2, "2".to_string()).unwrap();
+        map.start_atomic();
+        nested_map.start_atomic();
+        map.insert(3, "3".to_string()).unwrap();
+        map.remove(&1).unwrap();
+        nested_map.insert(7, 8, "9".to_string()).unwrap();
+        map.finish_atomic().unwrap();
+        nested_map.finish_atomic().unwrap();
+    }
+
+    // Reopen the database, and ensure the entries were persisted.
+    let database = open_at(&directory);
+    let map = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+    let nested_map = database.nested_map_from_db::<u32, u32, String, _>(MapID::Test(TestMap::Test2));
+    assert_eq!(map.len_confirmed(), 2);
+    assert!(!map.contains_key_confirmed(&1).unwrap());
+    assert_eq!(map.get_confirmed(&3).unwrap().unwrap().as_str(), "3");
+    assert_eq!(nested_map.get_map_confirmed(&7).unwrap(), vec![(8, "9".to_string())]);
+}
+
+#[test]
+#[serial]
+fn test_unfinished_atomic_batch_is_discarded() {
+    let directory = temp_dir();
+
+    {
+        let database = open_at(&directory);
+        let map = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+        map.insert(1, "1".to_string()).unwrap();
+
+        // Simulate a crash in the middle of an atomic batch.
+        map.start_atomic();
+        map.insert(2, "2".to_string()).unwrap();
+        map.remove(&1).unwrap();
+    }
+
+    // Reopen the database, and ensure only the committed entries were persisted.
+    let database = open_at(&directory);
+    let map = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+    assert_eq!(map.keys_confirmed().map(|key| *key).collect::<Vec<_>>(), vec![1]);
+
+    // Ensure the database remains writable.
+    map.insert(3, "3".to_string()).unwrap();
+    assert_eq!(map.keys_confirmed().map(|key| *key).collect::<Vec<_>>(), vec![1, 3]);
+}
+
+#[test]
+#[serial]
+fn test_iterator_reads_a_snapshot() {
+    let database = JournalDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+    let map = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+    for i in 0..10 {
+        map.insert(i, i.to_string()).unwrap();
+    }
+
+    // Ensure the writes after the creation of an iterator are not visible to it.
+    let mut keys = map.keys_confirmed();
+    assert_eq!(*keys.next().unwrap(), 0);
+    map.remove(&5).unwrap();
+    map.insert(10, "10".to_string()).unwrap();
+    assert_eq!(keys.map(|key| *key).collect::<Vec<_>>(), (1..10).collect::<Vec<_>>());
+
+    // Ensure a new iterator observes the writes.
+    assert_eq!(map.keys_confirmed().map(|key| *key).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 6, 7, 8, 9, 10]);
+}
+
+#[test]
+#[serial]
+fn test_not_a_journal() {
+    let directory = temp_dir();
+    fs::write(directory.join(DATABASE_FILE), b"definitely not a journal").unwrap();
+    assert!(JournalDB::open_at(&directory, u16::MAX, StorageMode::from(directory.clone())).is_err());
+}
+
+#[test]
+#[serial]
+fn test_compact() {
+    let directory = temp_dir();
+
+    {
+        let database = open_at(&directory);
+        let map = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+
+        // Insert a number of large entries, and remove most of them.
+        for i in 0..1000 {
+            map.insert(i, i.to_string().repeat(1000)).unwrap();
+        }
+        for i in 10..1000 {
+            map.remove(&i).unwrap();
+        }
+
+        // Ensure the database cannot be compacted while an iterator is alive.
+        let iterator = map.keys_confirmed();
+        assert!(database.compact().is_err());
+        drop(iterator);
+
+        // Ensure the database cannot be compacted during an atomic batch.
+        map.start_atomic();
+        assert!(database.compact().is_err());
+        map.abort_atomic();
+
+        // Compact the database, and ensure it shrinks.
+        let path = directory.join(DATABASE_FILE);
+        let len_before = fs::metadata(&path).unwrap().len();
+        database.compact().unwrap();
+        let len_after = fs::metadata(&path).unwrap().len();
+        assert!(len_after < len_before);
+
+        // Ensure the database remains writable after compaction.
+        map.insert(1000, "1000".to_string()).unwrap();
+    }
+
+    // Reopen the database, and ensure the entries are intact.
+    let database = open_at(&directory);
+    let map = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+    assert_eq!(map.len_confirmed(), 11);
+    assert!(!map.contains_key_confirmed(&10).unwrap());
+    assert_eq!(map.get_confirmed(&9).unwrap().unwrap().as_str(), "9".repeat(1000));
+    assert_eq!(map.get_confirmed(&1000).unwrap().unwrap().as_str(), "1000");
+}
+
+#[test]
+#[serial]
+fn test_maps_are_isolated() {
+    let database = JournalDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+    let map_1 = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test));
+    let map_2 = database.map_from_db::<u32, String, _>(MapID::Test(TestMap::Test2));
+    let nested_map = database.nested_map_from_db::<u32, u32, String, _>(MapID::Test(TestMap::Test3));
+
+    map_1.insert(1, "1".to_string()).unwrap();
+    map_2.insert(1, "2".to_string()).unwrap();
+    nested_map.insert(1, 1, "3".to_string()).unwrap();
+    nested_map.insert(2, 1, "4".to_string()).unwrap();
+
+    assert_eq!(map_1.len_confirmed(), 1);
+    assert_eq!(map_2.len_confirmed(), 1);
+    assert_eq!(map_1.get_confirmed(&1).unwrap().unwrap().as_str(), "1");
+    assert_eq!(map_2.get_confirmed(&1).unwrap().unwrap().as_str(), "2");
+    assert_eq!(nested_map.len_map_confirmed(&1).unwrap(), 1);
+
+    // Removing a nested map does not affect the other nested maps.
+    nested_map.remove_map(&1).unwrap();
+    assert_eq!(nested_map.len_map_confirmed(&1).unwrap(), 0);
+    assert_eq!(nested_map.len_map_confirmed(&2).unwrap(), 1);
+}
+
+#[test]
+#[serial]
+fn test_schema_version() {
+    let directory = temp_dir();
+
+    {
+        let database = open_at(&directory);
+        // Ensure a new database is initialized with the current schema version.
+        ensure_schema_version(&database, u16::MAX, MIGRATIONS, SCHEMA_VERSION).unwrap();
+        assert_eq!(crate::helpers::schema_version(&database, u16::MAX).unwrap(), Some(SCHEMA_VERSION));
+        // Simulate a database written by a newer release.
+        ensure_schema_version(&database, u16::MAX - 1, MIGRATIONS, SCHEMA_VERSION + 1).unwrap();
+    }
+
+    // Reopen the database, and ensure the schema versions were persisted.
+    let database = open_at(&directory);
+    ensure_schema_version(&database, u16::MAX, MIGRATIONS, SCHEMA_VERSION).unwrap();
+    let error = ensure_schema_version(&database, u16::MAX - 1, MIGRATIONS, SCHEMA_VERSION).unwrap_err();
+    assert!(error.to_string().contains("newer than the supported schema version"));
+}
+
+#[test]
+#[serial]
+fn test_read_schema_version() {
+    let directory = temp_dir();
+
+    // Ensure a storage without a database file has no schema version.
+    assert_eq!(JournalDB::read_schema_version(u16::MAX, directory.clone()).unwrap(), None);
+    {
+        let database = open_at(&directory);
+        ensure_schema_version(&database, u16::MAX, MIGRATIONS, SCHEMA_VERSION).unwrap();
+    }
+
+    // Ensure the schema version is read, and a network without a schema version has none.
+    assert_eq!(JournalDB::read_schema_version(u16::MAX, directory.clone()).unwrap(), Some(SCHEMA_VERSION));
+    assert_eq!(JournalDB::read_schema_version(u16::MAX - 1, directory).unwrap(), None);
+}
@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    helpers::journal::{self, CommitteeMap, Database, JournalMap, MapID, NestedJournalMap, ProgramMap},
+    CommitteeStorage,
+    CommitteeStore,
+    FinalizeStorage,
+};
+use console::{
+    prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
+
+use aleo_std_storage::StorageMode;
+use indexmap::IndexSet;
+
+/// A journal finalize storage.
+#[derive(Clone)]
+pub struct FinalizeJournal<N: Network> {
+    /// The committee store.
+    committee_store: CommitteeStore<N, CommitteeJournal<N>>,
+    /// The program ID map.
+    program_id_map: JournalMap<ProgramID<N>, IndexSet<Identifier<N>>>,
+    /// The key-value map.
+    key_value_map: NestedJournalMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The history map.
+    history_map: NestedJournalMap<Field<N>, u32, Option<Value<N>>>,
+    /// The key undo map.
+    key_undo_map: NestedJournalMap<u32, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>,
+    /// The program undo map.
+    program_undo_map: NestedJournalMap<u32, ProgramID<N>, Option<IndexSet<Identifier<N>>>>,
+    /// The history range map.
+    history_range_map: JournalMap<u8, (u32, u32)>,
+    /// The ratified operations map.
+    ratified_operations_map: JournalMap<u32, Vec<FinalizeOperation<N>>>,
+    /// The ratified operations start map.
+    ratified_operations_start_map: JournalMap<u8, u32>,
+    /// The operation value map.
+    operation_value_map: NestedJournalMap<u32, Field<N>, Value<N>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> FinalizeStorage<N> for FinalizeJournal<N> {
+    type CommitteeStorage = CommitteeJournal<N>;
+    type ProgramIDMap = JournalMap<ProgramID<N>, IndexSet<Identifier<N>>>;
+    type KeyValueMap = NestedJournalMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type HistoryMap = NestedJournalMap<Field<N>, u32, Option<Value<N>>>;
+    type KeyUndoMap = NestedJournalMap<u32, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>;
+    type ProgramUndoMap = NestedJournalMap<u32, ProgramID<N>, Option<IndexSet<Identifier<N>>>>;
+    type HistoryRangeMap = JournalMap<u8, (u32, u32)>;
+    type RatifiedOperationsMap = JournalMap<u32, Vec<FinalizeOperation<N>>>;
+    type RatifiedOperationsStartMap = JournalMap<u8, u32>;
+    type OperationValueMap = NestedJournalMap<u32, Field<N>, Value<N>>;
+
+    /// Initializes the finalize storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the committee store.
+        let committee_store = CommitteeStore::<N, CommitteeJournal<N>>::open(storage.clone())?;
+        // Return the finalize storage.
+        Ok(Self {
+            committee_store,
+            program_id_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
+            key_value_map: journal::JournalDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            history_map: journal::JournalDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::History))?,
+            key_undo_map: journal::JournalDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyUndo))?,
+            program_undo_map: journal::JournalDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramUndo))?,
+            history_range_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::HistoryRange))?,
+            ratified_operations_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::RatifiedOperations))?,
+            ratified_operations_start_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::RatifiedOperationsStart))?,
+            operation_value_map: journal::JournalDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::OperationValue))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        // Initialize the committee store.
+        let committee_store = CommitteeStore::<N, CommitteeJournal<N>>::open_testing(temp_dir.clone(), dev)?;
+        // Return the finalize storage.
+        Ok(Self {
+            committee_store,
+            program_id_map: journal::JournalDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
+            key_value_map: journal::JournalDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            history_map: journal::JournalDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::History))?,
+            key_undo_map: journal::JournalDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyUndo))?,
+            program_undo_map: journal::JournalDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramUndo))?,
+            history_range_map: journal::JournalDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::HistoryRange))?,
+            ratified_operations_map: journal::JournalDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::RatifiedOperations))?,
+            ratified_operations_start_map: journal::JournalDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::RatifiedOperationsStart))?,
+            operation_value_map: journal::JournalDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::OperationValue))?,
+            storage_mode: dev.into(),
+        })
+    }
+
+    /// Returns the committee store.
+    fn committee_store(&self) -> &CommitteeStore<N, Self::CommitteeStorage> {
+        &self.committee_store
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        &self.program_id_map
+    }
+
+    /// Returns the key-value map.
+    fn key_value_map(&self) -> &Self::KeyValueMap {
+        &self.key_value_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the key undo map.
+    fn key_undo_map(&self) -> &Self::KeyUndoMap {
+        &self.key_undo_map
+    }
+
+    /// Returns the program undo map.
+    fn program_undo_map(&self) -> &Self::ProgramUndoMap {
+        &self.program_undo_map
+    }
+
+    /// Returns the history range map.
+    fn history_range_map(&self) -> &Self::HistoryRangeMap {
+        &self.history_range_map
+    }
+
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap {
+        &self.ratified_operations_map
+    }
+
+    /// Returns the ratified operations start map.
+    fn ratified_operations_start_map(&self) -> &Self::RatifiedOperationsStartMap {
+        &self.ratified_operations_start_map
+    }
+
+    /// Returns the operation value map.
+    fn operation_value_map(&self) -> &Self::OperationValueMap {
+        &self.operation_value_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
+
+/// A journal committee storage.
+#[derive(Clone)]
+pub struct CommitteeJournal<N: Network> {
+    /// The current round map.
+    current_round_map: JournalMap<u8, u64>,
+    /// The round to height map.
+    round_to_height_map: JournalMap<u64, u32>,
+    /// The committee map.
+    committee_map: JournalMap<u32, Committee<N>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> CommitteeStorage<N> for CommitteeJournal<N> {
+    type CurrentRoundMap = JournalMap<u8, u64>;
+    type RoundToHeightMap = JournalMap<u64, u32>;
+    type CommitteeMap = JournalMap<u32, Committee<N>>;
+
+    /// Initializes the committee storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            current_round_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Committee(CommitteeMap::CurrentRound))?,
+            round_to_height_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Committee(CommitteeMap::RoundToHeight))?,
+            committee_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Committee(CommitteeMap::Committee))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            current_round_map: journal::JournalDB::open_map_testing(temp_dir.clone(), dev, MapID::Committee(CommitteeMap::CurrentRound))?,
+            round_to_height_map: journal::JournalDB::open_map_testing(temp_dir.clone(), dev, MapID::Committee(CommitteeMap::RoundToHeight))?,
+            committee_map: journal::JournalDB::open_map_testing(temp_dir, dev, MapID::Committee(CommitteeMap::Committee))?,
+            storage_mode: dev.into(),
+        })
+    }
+
+    /// Returns the current round map.
+    fn current_round_map(&self) -> &Self::CurrentRoundMap {
+        &self.current_round_map
+    }
+
+    /// Returns the round to height map.
+    fn round_to_height_map(&self) -> &Self::RoundToHeightMap {
+        &self.round_to_height_map
+    }
+
+    /// Returns the committee map.
+    fn committee_map(&self) -> &Self::CommitteeMap {
+        &self.committee_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::journal::{self, Database, JournalMap, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
+use console::{
+    prelude::*,
+    program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
+    types::{Field, Group},
+};
+
+use aleo_std_storage::StorageMode;
+
+/// A database transition storage.
+#[derive(Clone)]
+pub struct TransitionJournal<N: Network> {
+    /// The transition program IDs and function names.
+    locator_map: JournalMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>,
+    /// The transition input store.
+    input_store: InputStore<N, InputJournal<N>>,
+    /// The transition output store.
+    output_store: OutputStore<N, OutputJournal<N>>,
+    /// The transition public keys.
+    tpk_map: JournalMap<N::TransitionID, Group<N>>,
+    /// The reverse `tpk` map.
+    reverse_tpk_map: JournalMap<Group<N>, N::TransitionID>,
+    /// The transition commitments.
+    tcm_map: JournalMap<N::TransitionID, Field<N>>,
+    /// The reverse `tcm` map.
+    reverse_tcm_map: JournalMap<Field<N>, N::TransitionID>,
+    /// The signer commitments.
+    scm_map: JournalMap<N::TransitionID, Field<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> TransitionStorage<N> for TransitionJournal<N> {
+    type LocatorMap = JournalMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>;
+    type InputStorage = InputJournal<N>;
+    type OutputStorage = OutputJournal<N>;
+    type TPKMap = JournalMap<N::TransitionID, Group<N>>;
+    type ReverseTPKMap = JournalMap<Group<N>, N::TransitionID>;
+    type TCMMap = JournalMap<N::TransitionID, Field<N>>;
+    type ReverseTCMMap = JournalMap<Field<N>, N::TransitionID>;
+    type SCMMap = JournalMap<N::TransitionID, Field<N>>;
+
+    /// Initializes the transition storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            locator_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::Locator))?,
+            input_store: InputStore::open(storage.clone())?,
+            output_store: OutputStore::open(storage.clone())?,
+            tpk_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TPK))?,
+            reverse_tpk_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::ReverseTPK))?,
+            tcm_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TCM))?,
+            reverse_tcm_map: journal::JournalDB::open_map(N::ID, storage.clone(),  MapID::Transition(TransitionMap::ReverseTCM))?,
+            scm_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::SCM))?,
+        })
+    }
+
+    /// Returns the transition program IDs and function names.
+    fn locator_map(&self) -> &Self::LocatorMap {
+        &self.locator_map
+    }
+
+    /// Returns the transition input store.
+    fn input_store(&self) -> &InputStore<N, Self::InputStorage> {
+        &self.input_store
+    }
+
+    /// Returns the transition output store.
+    fn output_store(&self) -> &OutputStore<N, Self::OutputStorage> {
+        &self.output_store
+    }
+
+    /// Returns the transition public keys.
+    fn tpk_map(&self) -> &Self::TPKMap {
+        &self.tpk_map
+    }
+
+    /// Returns the reverse `tpk` map.
+    fn reverse_tpk_map(&self) -> &Self::ReverseTPKMap {
+        &self.reverse_tpk_map
+    }
+
+    /// Returns the transition commitments.
+    fn tcm_map(&self) -> &Self::TCMMap {
+        &self.tcm_map
+    }
+
+    /// Returns the reverse `tcm` map.
+    fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
+        &self.reverse_tcm_map
+    }
+
+    /// Returns the signer commitments.
+    fn scm_map(&self) -> &Self::SCMMap {
+        &self.scm_map
+    }
+}
+
+/// An database transition input storage.
+#[derive(Clone)]
+pub struct InputJournal<N: Network> {
+    /// The mapping of `transition ID` to `input IDs`.
+    id_map: JournalMap<N::TransitionID, Vec<Field<N>>>,
+    /// The mapping of `input ID` to `transition ID`.
+    reverse_id_map: JournalMap<Field<N>, N::TransitionID>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    constant: JournalMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    public: JournalMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `ciphertext hash` to `(optional) ciphertext`.
+    private: JournalMap<Field<N>, Option<Ciphertext<N>>>,
+    /// The mapping of `serial number` to `tag`.
+    record: JournalMap<Field<N>, Field<N>>,
+    /// The mapping of `record tag` to `serial number`.
+    record_tag: JournalMap<Field<N>, Field<N>>,
+    /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
+    external_record: JournalMap<Field<N>, ()>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> InputStorage<N> for InputJournal<N> {
+    type IDMap = JournalMap<N::TransitionID, Vec<Field<N>>>;
+    type ReverseIDMap = JournalMap<Field<N>, N::TransitionID>;
+    type ConstantMap = JournalMap<Field<N>, Option<Plaintext<N>>>;
+    type PublicMap = JournalMap<Field<N>, Option<Plaintext<N>>>;
+    type PrivateMap = JournalMap<Field<N>, Option<Ciphertext<N>>>;
+    type RecordMap = JournalMap<Field<N>, Field<N>>;
+    type RecordTagMap = JournalMap<Field<N>, Field<N>>;
+    type ExternalRecordMap = JournalMap<Field<N>, ()>;
+
+    /// Initializes the transition input storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            id_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::ID))?,
+            reverse_id_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::ReverseID))?,
+            constant: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::Constant))?,
+            public: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::Public))?,
+            private: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::Private))?,
+            record: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::Record))?,
+            record_tag: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::RecordTag))?,
+            external_record: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::ExternalRecord))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the constant map.
+    fn constant_map(&self) -> &Self::ConstantMap {
+        &self.constant
+    }
+
+    /// Returns the public map.
+    fn public_map(&self) -> &Self::PublicMap {
+        &self.public
+    }
+
+    /// Returns the private map.
+    fn private_map(&self) -> &Self::PrivateMap {
+        &self.private
+    }
+
+    /// Returns the record map.
+    fn record_map(&self) -> &Self::RecordMap {
+        &self.record
+    }
+
+    /// Returns the record tag map.
+    fn record_tag_map(&self) -> &Self::RecordTagMap {
+        &self.record_tag
+    }
+
+    /// Returns the external record map.
+    fn external_record_map(&self) -> &Self::ExternalRecordMap {
+        &self.external_record
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
+
+/// A database transition output storage.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct OutputJournal<N: Network> {
+    /// The mapping of `transition ID` to `output IDs`.
+    id_map: JournalMap<N::TransitionID, Vec<Field<N>>>,
+    /// The mapping of `output ID` to `transition ID`.
+    reverse_id_map: JournalMap<Field<N>, N::TransitionID>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    constant: JournalMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    public: JournalMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `ciphertext hash` to `(optional) ciphertext`.
+    private: JournalMap<Field<N>, Option<Ciphertext<N>>>,
+    /// The mapping of `commitment` to `(checksum, (optional) record ciphertext)`.
+    record: JournalMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>,
+    /// The mapping of `record nonce` to `commitment`.
+    record_nonce: JournalMap<Group<N>, Field<N>>,
+    /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
+    external_record: JournalMap<Field<N>, ()>,
+    /// The mapping of `future hash` to `(optional) future`.
+    future: JournalMap<Field<N>, Option<Future<N>>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> OutputStorage<N> for OutputJournal<N> {
+    type IDMap = JournalMap<N::TransitionID, Vec<Field<N>>>;
+    type ReverseIDMap = JournalMap<Field<N>, N::TransitionID>;
+    type ConstantMap = JournalMap<Field<N>, Option<Plaintext<N>>>;
+    type PublicMap = JournalMap<Field<N>, Option<Plaintext<N>>>;
+    type PrivateMap = JournalMap<Field<N>, Option<Ciphertext<N>>>;
+    type RecordMap = JournalMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
+    type RecordNonceMap = JournalMap<Group<N>, Field<N>>;
+    type ExternalRecordMap = JournalMap<Field<N>, ()>;
+    type FutureMap = JournalMap<Field<N>, Option<Future<N>>>;
+
+    /// Initializes the transition output storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            id_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::ID))?,
+            reverse_id_map: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::ReverseID))?,
+            constant: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Constant))?,
+            public: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Public))?,
+            private: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Private))?,
+            record: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Record))?,
+            record_nonce: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::RecordNonce))?,
+            external_record: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::ExternalRecord))?,
+            future: journal::JournalDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Future))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the constant map.
+    fn constant_map(&self) -> &Self::ConstantMap {
+        &self.constant
+    }
+
+    /// Returns the public map.
+    fn public_map(&self) -> &Self::PublicMap {
+        &self.public
+    }
+
+    /// Returns the private map.
+    fn private_map(&self) -> &Self::PrivateMap {
+        &self.private
+    }
+
+    /// Returns the record map.
+    fn record_map(&self) -> &Self::RecordMap {
+        &self.record
+    }
+
+    /// Returns the record nonce map.
+    fn record_nonce_map(&self) -> &Self::RecordNonceMap {
+        &self.record_nonce
+    }
+
+    /// Returns the external record map.
+    fn external_record_map(&self) -> &Self::ExternalRecordMap {
+        &self.external_record
+    }
+
+    /// Returns the future map.
+    fn future_map(&self) -> &Self::FutureMap {
+        &self.future
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{map_name, map_names};
+use metrics::store::*;
+
+use std::{collections::BTreeMap, time::Instant};
+
+/// The number of entries and bytes of a map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct MapSize {
+    /// The number of entries.
+    pub(crate) num_entries: u64,
+    /// The number of bytes of the keys and values.
+    pub(crate) num_bytes: u64,
+}
+
+/// Returns the map ID of the given raw key, i.e. the two bytes following the network ID.
+pub(crate) fn map_id(raw_key: &[u8]) -> Option<u16> {
+    raw_key.get(2..4).map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Returns the label of the map with the given ID, i.e. its name (or its ID, if the map is unknown).
+fn map_label(map_id: Option<u16>) -> String {
+    match map_id {
+        Some(map_id) => map_name(map_id).unwrap_or_else(|| map_id.to_string()),
+        None => "unknown".to_string(),
+    }
+}
+
+/// The size of each map, counted from its entries.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct MapSizes(BTreeMap<Option<u16>, MapSize>);
+
+impl MapSizes {
+    /// Counts the entry with the given raw key and value length.
+    pub(crate) fn add(&mut self, raw_key: &[u8], value_len: usize) {
+        let size = self.0.entry(map_id(raw_key)).or_default();
+        size.num_entries += 1;
+        size.num_bytes += (raw_key.len() + value_len) as u64;
+    }
+
+    /// Records the size of each map, where the maps without entries are recorded as empty.
+    pub(crate) fn record(mut self) {
+        // Record the size of each known map, including the empty ones.
+        for (map_id, name) in map_names() {
+            let size = self.0.remove(&Some(map_id)).unwrap_or_default();
+            metrics::gauge_label(MAP_ENTRIES, MAP_LABEL, name.clone(), size.num_entries as f64);
+            metrics::gauge_label(MAP_BYTES, MAP_LABEL, name, size.num_bytes as f64);
+        }
+        // Record the size of the remaining (unknown) maps.
+        for (map_id, size) in self.0 {
+            let label = map_label(map_id);
+            metrics::gauge_label(MAP_ENTRIES, MAP_LABEL, label.clone(), size.num_entries as f64);
+            metrics::gauge_label(MAP_BYTES, MAP_LABEL, label, size.num_bytes as f64);
+        }
+    }
+}
+
+/// Records the latency of an `insert` into the map with the given context, which started at the given instant.
+pub(crate) fn record_insert_latency(context: &[u8], start: Instant) {
+    metrics::histogram_label(INSERT_LATENCY, MAP_LABEL, map_label(map_id(context)), start.elapsed().as_secs_f64());
+}
+
+/// Records the latency of a `get_confirmed` from the map with the given context, which started at the given instant.
+pub(crate) fn record_get_confirmed_latency(context: &[u8], start: Instant) {
+    metrics::histogram_label(
+        GET_CONFIRMED_LATENCY,
+        MAP_LABEL,
+        map_label(map_id(context)),
+        start.elapsed().as_secs_f64(),
+    );
+}
+
+/// Records the depth of the checkpoint stack of the map with the given context.
+pub(crate) fn record_checkpoint_depth(context: &[u8], depth: usize) {
+    metrics::histogram_label(CHECKPOINT_DEPTH, MAP_LABEL, map_label(map_id(context)), depth as f64);
+}
+
+/// Records the number of operations of an atomic batch that is written to the database.
+pub(crate) fn record_atomic_batch_size(num_operations: usize) {
+    metrics::histogram(ATOMIC_BATCH_SIZE, num_operations as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a raw key of the map with the given ID.
+    fn raw_key(map_id: u16, key: u8) -> Vec<u8> {
+        let mut raw_key = u16::MAX.to_le_bytes().to_vec();
+        raw_key.extend_from_slice(&map_id.to_le_bytes());
+        raw_key.push(key);
+        raw_key
+    }
+
+    #[test]
+    fn test_map_id() {
+        assert_eq!(map_id(&raw_key(7, 1)), Some(7));
+        assert_eq!(map_id(&[0, 0, 1]), None);
+    }
+
+    #[test]
+    fn test_map_label() {
+        assert_eq!(map_label(Some(crate::helpers::BlockMap::Header as u16)), "BlockHeaderMap");
+        assert_eq!(map_label(Some(u16::MAX)), u16::MAX.to_string());
+        assert_eq!(map_label(None), "unknown");
+    }
+
+    #[test]
+    fn test_map_sizes() {
+        let (key_1, key_2, key_3) = (raw_key(1, 1), raw_key(1, 2), raw_key(2, 1));
+
+        let mut sizes = MapSizes::default();
+        sizes.add(&key_1, 10);
+        sizes.add(&key_2, 20);
+        sizes.add(&key_3, 30);
+        sizes.add(&[0], 5);
+
+        assert_eq!(sizes.0.len(), 3);
+        assert_eq!(sizes.0[&Some(1)], MapSize { num_entries: 2, num_bytes: (key_1.len() + key_2.len() + 30) as u64 });
+        assert_eq!(sizes.0[&Some(2)], MapSize { num_entries: 1, num_bytes: (key_3.len() + 30) as u64 });
+        assert_eq!(sizes.0[&None], MapSize { num_entries: 1, num_bytes: 6 });
+    }
+}
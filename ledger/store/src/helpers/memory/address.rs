@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::memory::{MemoryMap, NestedMemoryMap},
+    AddressEntries,
+    AddressStorage,
+};
+use console::{prelude::*, program::ProgramID, types::Address};
+
+use aleo_std_storage::StorageMode;
+
+/// An in-memory address index storage.
+#[derive(Clone)]
+pub struct AddressMemory<N: Network> {
+    /// The transitions map.
+    transitions_map: NestedMemoryMap<Address<N>, N::TransitionID, u32>,
+    /// The accounts map.
+    accounts_map: NestedMemoryMap<Address<N>, N::TransactionID, u32>,
+    /// The programs map.
+    programs_map: NestedMemoryMap<Address<N>, ProgramID<N>, u32>,
+    /// The entries map.
+    entries_map: MemoryMap<u32, AddressEntries<N>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> AddressStorage<N> for AddressMemory<N> {
+    type TransitionsMap = NestedMemoryMap<Address<N>, N::TransitionID, u32>;
+    type AccountsMap = NestedMemoryMap<Address<N>, N::TransactionID, u32>;
+    type ProgramsMap = NestedMemoryMap<Address<N>, ProgramID<N>, u32>;
+    type EntriesMap = MemoryMap<u32, AddressEntries<N>>;
+
+    /// Initializes the address storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            transitions_map: NestedMemoryMap::default(),
+            accounts_map: NestedMemoryMap::default(),
+            programs_map: NestedMemoryMap::default(),
+            entries_map: MemoryMap::default(),
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Returns the transitions map.
+    fn transitions_map(&self) -> &Self::TransitionsMap {
+        &self.transitions_map
+    }
+
+    /// Returns the accounts map.
+    fn accounts_map(&self) -> &Self::AccountsMap {
+        &self.accounts_map
+    }
+
+    /// Returns the programs map.
+    fn programs_map(&self) -> &Self::ProgramsMap {
+        &self.programs_map
+    }
+
+    /// Returns the entries map.
+    fn entries_map(&self) -> &Self::EntriesMap {
+        &self.entries_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
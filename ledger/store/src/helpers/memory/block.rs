@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "address-index")]
+use crate::{helpers::memory::AddressMemory, AddressStore};
 use crate::{
     helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
     BlockStorage,
@@ -66,8 +68,15 @@ pub struct BlockMemory<N: Network> {
         MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The mapping of `PRUNING_DEPTH_KEY` to the pruning depth.
+    pruning_depth_map: MemoryMap<u8, u32>,
+    /// The mapping of pruned `block hash` to `block height`.
+    pruned_map: MemoryMap<N::BlockHash, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
+    /// The address index store.
+    #[cfg(feature = "address-index")]
+    address_store: AddressStore<N, AddressMemory<N>>,
 }
 
 #[rustfmt::skip]
@@ -89,11 +98,18 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type PruningDepthMap = MemoryMap<u8, u32>;
+    type PrunedMap = MemoryMap<N::BlockHash, u32>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
+    #[cfg(feature = "address-index")]
+    type AddressStorage = AddressMemory<N>;
 
     /// Initializes the block storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the address index store.
+        #[cfg(feature = "address-index")]
+        let address_store = AddressStore::<N, AddressMemory<N>>::open(storage.clone())?;
         // Initialize the transition store.
         let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(storage)?;
         // Initialize the transaction store.
@@ -117,7 +133,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            pruning_depth_map: MemoryMap::default(),
+            pruned_map: MemoryMap::default(),
             transaction_store,
+            #[cfg(feature = "address-index")]
+            address_store,
         })
     }
 
@@ -206,8 +226,24 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap {
+        &self.pruning_depth_map
+    }
+
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
+
+    /// Returns the address index store.
+    #[cfg(feature = "address-index")]
+    fn address_store(&self) -> &AddressStore<N, Self::AddressStorage> {
+        &self.address_store
+    }
 }
@@ -452,6 +452,22 @@ mod tests {
         crate::helpers::test_helpers::map::check_atomic_writes_can_be_aborted(map);
     }
 
+    #[test]
+    fn test_atomic_checkpoints() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        crate::helpers::test_helpers::map::check_atomic_checkpoints(map);
+    }
+
+    #[test]
+    fn test_pause_atomic_writes() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        crate::helpers::test_helpers::map::check_pause_atomic_writes(map);
+    }
+
     #[test]
     fn test_checkpoint_and_rewind() {
         // The number of items that will be queued to be inserted into the map.
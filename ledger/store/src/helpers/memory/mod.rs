@@ -15,6 +15,11 @@
 pub mod internal;
 pub use internal::*;
 
+#[cfg(feature = "address-index")]
+mod address;
+#[cfg(feature = "address-index")]
+pub use address::*;
+
 mod block;
 pub use block::*;
 
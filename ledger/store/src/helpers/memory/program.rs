@@ -23,8 +23,10 @@ use crate::{
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
 
 use aleo_std_storage::StorageMode;
 use indexmap::IndexSet;
@@ -38,6 +40,20 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The history map.
+    history_map: NestedMemoryMap<Field<N>, u32, Option<Value<N>>>,
+    /// The key undo map.
+    key_undo_map: NestedMemoryMap<u32, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>,
+    /// The program undo map.
+    program_undo_map: NestedMemoryMap<u32, ProgramID<N>, Option<IndexSet<Identifier<N>>>>,
+    /// The history range map.
+    history_range_map: MemoryMap<u8, (u32, u32)>,
+    /// The ratified operations map.
+    ratified_operations_map: MemoryMap<u32, Vec<FinalizeOperation<N>>>,
+    /// The ratified operations start map.
+    ratified_operations_start_map: MemoryMap<u8, u32>,
+    /// The operation value map.
+    operation_value_map: NestedMemoryMap<u32, Field<N>, Value<N>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +63,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type HistoryMap = NestedMemoryMap<Field<N>, u32, Option<Value<N>>>;
+    type KeyUndoMap = NestedMemoryMap<u32, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>;
+    type ProgramUndoMap = NestedMemoryMap<u32, ProgramID<N>, Option<IndexSet<Identifier<N>>>>;
+    type HistoryRangeMap = MemoryMap<u8, (u32, u32)>;
+    type RatifiedOperationsMap = MemoryMap<u32, Vec<FinalizeOperation<N>>>;
+    type RatifiedOperationsStartMap = MemoryMap<u8, u32>;
+    type OperationValueMap = NestedMemoryMap<u32, Field<N>, Value<N>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +80,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            history_map: NestedMemoryMap::default(),
+            key_undo_map: NestedMemoryMap::default(),
+            program_undo_map: NestedMemoryMap::default(),
+            history_range_map: MemoryMap::default(),
+            ratified_operations_map: MemoryMap::default(),
+            ratified_operations_start_map: MemoryMap::default(),
+            operation_value_map: NestedMemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -82,6 +112,41 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the key undo map.
+    fn key_undo_map(&self) -> &Self::KeyUndoMap {
+        &self.key_undo_map
+    }
+
+    /// Returns the program undo map.
+    fn program_undo_map(&self) -> &Self::ProgramUndoMap {
+        &self.program_undo_map
+    }
+
+    /// Returns the history range map.
+    fn history_range_map(&self) -> &Self::HistoryRangeMap {
+        &self.history_range_map
+    }
+
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap {
+        &self.ratified_operations_map
+    }
+
+    /// Returns the ratified operations start map.
+    fn ratified_operations_start_map(&self) -> &Self::RatifiedOperationsStartMap {
+        &self.ratified_operations_start_map
+    }
+
+    /// Returns the operation value map.
+    fn operation_value_map(&self) -> &Self::OperationValueMap {
+        &self.operation_value_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -12,6 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(any(feature = "rocks", feature = "journal"))]
+mod id;
+#[cfg(any(feature = "rocks", feature = "journal"))]
+pub use id::*;
+
+#[cfg(any(feature = "rocks", feature = "journal"))]
+mod schema;
+#[cfg(any(feature = "rocks", feature = "journal"))]
+pub use schema::*;
+
+#[cfg(feature = "journal")]
+pub mod journal;
+#[cfg(all(feature = "metrics", any(feature = "rocks", feature = "journal")))]
+pub(crate) mod map_metrics;
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::rocksdb::{
+        internal::{self, DataMap, Database, NestedDataMap},
+        AddressMap,
+        MapID,
+    },
+    AddressEntries,
+    AddressStorage,
+};
+use console::{prelude::*, program::ProgramID, types::Address};
+
+use aleo_std_storage::StorageMode;
+
+/// A RocksDB address index storage.
+#[derive(Clone)]
+pub struct AddressDB<N: Network> {
+    /// The transitions map.
+    transitions_map: NestedDataMap<Address<N>, N::TransitionID, u32>,
+    /// The accounts map.
+    accounts_map: NestedDataMap<Address<N>, N::TransactionID, u32>,
+    /// The programs map.
+    programs_map: NestedDataMap<Address<N>, ProgramID<N>, u32>,
+    /// The entries map.
+    entries_map: DataMap<u32, AddressEntries<N>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> AddressStorage<N> for AddressDB<N> {
+    type TransitionsMap = NestedDataMap<Address<N>, N::TransitionID, u32>;
+    type AccountsMap = NestedDataMap<Address<N>, N::TransactionID, u32>;
+    type ProgramsMap = NestedDataMap<Address<N>, ProgramID<N>, u32>;
+    type EntriesMap = DataMap<u32, AddressEntries<N>>;
+
+    /// Initializes the address storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            transitions_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Address(AddressMap::Transitions))?,
+            accounts_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Address(AddressMap::Accounts))?,
+            programs_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Address(AddressMap::Programs))?,
+            entries_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Address(AddressMap::Entries))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Returns the transitions map.
+    fn transitions_map(&self) -> &Self::TransitionsMap {
+        &self.transitions_map
+    }
+
+    /// Returns the accounts map.
+    fn accounts_map(&self) -> &Self::AccountsMap {
+        &self.accounts_map
+    }
+
+    /// Returns the programs map.
+    fn programs_map(&self) -> &Self::ProgramsMap {
+        &self.programs_map
+    }
+
+    /// Returns the entries map.
+    fn entries_map(&self) -> &Self::EntriesMap {
+        &self.entries_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
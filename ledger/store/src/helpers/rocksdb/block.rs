@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "address-index")]
+use crate::{helpers::rocksdb::AddressDB, AddressStore};
 use crate::{
     helpers::rocksdb::{
         internal::{self, DataMap, Database},
@@ -72,8 +74,15 @@ pub struct BlockDB<N: Network> {
         DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The mapping of `PRUNING_DEPTH_KEY` to the pruning depth.
+    pruning_depth_map: DataMap<u8, u32>,
+    /// The mapping of pruned `block hash` to `block height`.
+    pruned_map: DataMap<N::BlockHash, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
+    /// The address index store.
+    #[cfg(feature = "address-index")]
+    address_store: AddressStore<N, AddressDB<N>>,
 }
 
 #[rustfmt::skip]
@@ -95,11 +104,18 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type PruningDepthMap = DataMap<u8, u32>;
+    type PrunedMap = DataMap<N::BlockHash, u32>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
+    #[cfg(feature = "address-index")]
+    type AddressStorage = AddressDB<N>;
 
     /// Initializes the block storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the address index store.
+        #[cfg(feature = "address-index")]
+        let address_store = AddressStore::<N, AddressDB<N>>::open(storage.clone())?;
         // Initialize the transition store.
         let transition_store = TransitionStore::<N, TransitionDB<N>>::open(storage.clone())?;
         // Initialize the transaction store.
@@ -122,8 +138,12 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
-            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            pruning_depth_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::PruningDepth))?,
+            pruned_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::Pruned))?,
             transaction_store,
+            #[cfg(feature = "address-index")]
+            address_store,
         })
     }
 
@@ -212,8 +232,24 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap {
+        &self.pruning_depth_map
+    }
+
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
+
+    /// Returns the address index store.
+    #[cfg(feature = "address-index")]
+    fn address_store(&self) -> &AddressStore<N, Self::AddressStorage> {
+        &self.address_store
+    }
 }
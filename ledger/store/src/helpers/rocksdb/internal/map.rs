@@ -56,6 +56,8 @@ impl<
     /// Inserts the given key-value pair into the map.
     ///
     fn insert(&self, key: K, value: V) -> Result<()> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key-value pair to the batch.
@@ -71,6 +73,9 @@ impl<
             }
         }
 
+        #[cfg(feature = "metrics")]
+        map_metrics::record_insert_latency(&self.context, start);
+
         Ok(())
     }
 
@@ -129,7 +134,10 @@ impl<
     ///
     fn atomic_checkpoint(&self) {
         // Push the current length of the atomic batch to the checkpoint stack.
-        self.checkpoints.lock().push(self.atomic_batch.lock().len());
+        let mut checkpoints = self.checkpoints.lock();
+        checkpoints.push(self.atomic_batch.lock().len());
+        #[cfg(feature = "metrics")]
+        map_metrics::record_checkpoint_depth(&self.context, checkpoints.len());
     }
 
     ///
@@ -225,6 +233,8 @@ impl<
         if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            #[cfg(feature = "metrics")]
+            map_metrics::record_atomic_batch_size(batch.len());
             // Execute all the operations atomically.
             self.database.rocksdb.write(batch)?;
             // Ensure that the database atomic batch is empty.
@@ -334,11 +344,16 @@ impl<
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
-        match self.get_raw(key) {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let value = match self.get_raw(key) {
             Ok(Some(bytes)) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
             Ok(None) => Ok(None),
             Err(e) => Err(e),
-        }
+        };
+        #[cfg(feature = "metrics")]
+        map_metrics::record_get_confirmed_latency(&self.context, start);
+        value
     }
 
     ///
@@ -810,6 +825,39 @@ mod tests {
         crate::helpers::test_helpers::map::check_atomic_writes_can_be_aborted(map);
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_atomic_checkpoints() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+
+        crate::helpers::test_helpers::map::check_atomic_checkpoints(map);
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_pause_atomic_writes() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+
+        crate::helpers::test_helpers::map::check_pause_atomic_writes(map);
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_pause_atomic_writes_are_deferred() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+
+        crate::helpers::test_helpers::map::check_pause_atomic_writes_are_deferred(map);
+    }
+
     #[test]
     fn test_checkpoint_and_rewind() {
         // The number of items that will be queued to be inserted into the map.
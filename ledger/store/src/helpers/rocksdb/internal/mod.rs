@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod id;
-pub use id::*;
+pub use crate::helpers::id::*;
+
+#[cfg(feature = "metrics")]
+use crate::helpers::map_metrics;
+use crate::helpers::{ensure_schema_version, schema_version, SchemaStore, MIGRATIONS, SCHEMA_VERSION};
 
 mod map;
 pub use map::*;
@@ -21,6 +24,9 @@ pub use map::*;
 mod nested_map;
 pub use nested_map::*;
 
+mod snapshot;
+pub use snapshot::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -129,14 +135,19 @@ impl Database for RocksDB {
                     Arc::new(rocksdb::DB::open(&options, primary)?)
                 };
 
-                Ok::<_, anyhow::Error>(RocksDB {
+                let database = RocksDB {
                     rocksdb,
                     network_id,
                     storage_mode: storage.clone().into(),
                     atomic_batch: Default::default(),
                     atomic_depth: Default::default(),
                     atomic_writes_paused: Default::default(),
-                })
+                };
+
+                // Ensure the database uses the current schema version, migrating it if needed.
+                ensure_schema_version(&database, network_id, MIGRATIONS, SCHEMA_VERSION)?;
+
+                Ok::<_, anyhow::Error>(database)
             })?
             .clone();
 
@@ -205,6 +216,51 @@ impl Database for RocksDB {
     }
 }
 
+impl SchemaStore for RocksDB {
+    /// Returns the value stored under the given raw key, if any.
+    fn get_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.rocksdb.get(key)?)
+    }
+
+    /// Returns the raw key-value pairs whose key starts with the given prefix, in key order.
+    fn prefix_entries(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        // Note: The prefix may be shorter than `PREFIX_LEN`, so a total order seek is required.
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_total_order_seek(true);
+        self.rocksdb
+            .iterator_opt(rocksdb::IteratorMode::From(prefix, rocksdb::Direction::Forward), options)
+            .map_while(|entry| match entry {
+                Ok((key, value)) => key.starts_with(prefix).then(|| Ok((key.to_vec(), value.to_vec()))),
+                Err(error) => Some(Err(error.into())),
+            })
+            .collect()
+    }
+
+    /// Returns `true` if any raw key starts with the given prefix.
+    fn contains_prefix(&self, prefix: &[u8]) -> Result<bool> {
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_total_order_seek(true);
+        let mut iterator =
+            self.rocksdb.iterator_opt(rocksdb::IteratorMode::From(prefix, rocksdb::Direction::Forward), options);
+        match iterator.next() {
+            Some(entry) => Ok(entry?.0.starts_with(prefix)),
+            None => Ok(false),
+        }
+    }
+
+    /// Atomically applies the given raw operations, where a `None` value denotes a removal.
+    fn apply_raw(&self, operations: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<()> {
+        let mut batch = rocksdb::WriteBatch::default();
+        for (key, value) in operations {
+            match value {
+                Some(value) => batch.put(key, value),
+                None => batch.delete(key),
+            }
+        }
+        Ok(self.rocksdb.write(batch)?)
+    }
+}
+
 impl RocksDB {
     /// Pause the execution of atomic writes for the entire database.
     fn pause_atomic_writes(&self) -> Result<()> {
@@ -242,6 +298,8 @@ impl RocksDB {
         // writes have been paused becomes executed as a single atomic batch.
         let batch = mem::take(&mut *self.atomic_batch.lock());
         if !DISCARD_BATCH {
+            #[cfg(feature = "metrics")]
+            map_metrics::record_atomic_batch_size(batch.len());
             self.rocksdb.write(batch)?;
         }
 
@@ -256,6 +314,55 @@ impl RocksDB {
         self.atomic_writes_paused.load(Ordering::SeqCst)
     }
 
+    /// Returns the schema version of the storage, if it has one, without migrating (or writing to) the storage.
+    ///
+    /// Note: The storage is opened as a separate, read-only instance, so it must not be opened by this process.
+    pub fn read_schema_version<S: Into<StorageMode>>(network_id: u16, storage: S) -> Result<Option<u32>> {
+        let storage_mode = storage.into();
+        let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage_mode.clone());
+        // Open the database as read-only.
+        let mut options = rocksdb::Options::default();
+        options.set_prefix_extractor(rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN));
+        let database = RocksDB {
+            rocksdb: Arc::new(rocksdb::DB::open_for_read_only(&options, primary, false)?),
+            network_id,
+            storage_mode,
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+        };
+        schema_version(&database, network_id)
+    }
+
+    /// Records the number of entries and bytes of each map of the network, as gauges labelled by the map name.
+    ///
+    /// Note: This reads every entry of the network, so it is not run on writes, and should be called sparingly,
+    /// e.g. periodically by the node.
+    #[cfg(feature = "metrics")]
+    pub fn record_map_metrics(&self) -> Result<()> {
+        let network_prefix = self.network_id.to_le_bytes();
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_total_order_seek(true);
+        let mut iterator = self.rocksdb.raw_iterator_opt(options);
+        iterator.seek(network_prefix);
+
+        let mut sizes = map_metrics::MapSizes::default();
+        while iterator.valid() {
+            let (Some(key), Some(value)) = (iterator.key(), iterator.value()) else { break };
+            // Stop once the entries of the network have been counted.
+            if !key.starts_with(&network_prefix) {
+                break;
+            }
+            sizes.add(key, value.len());
+            iterator.next();
+        }
+        // Ensure the iteration did not fail.
+        iterator.status()?;
+
+        sizes.record();
+        Ok(())
+    }
+
     /// Opens the test database.
     #[cfg(any(test, feature = "test"))]
     pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
@@ -372,60 +479,3 @@ impl RocksDB {
         })
     }
 }
-
-// impl RocksDB {
-//     /// Imports a file with the given path to reconstruct storage.
-//     fn import<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-//         let file = File::open(path)?;
-//         let mut reader = BufReader::new(file);
-//
-//         let len = reader.seek(SeekFrom::End(0))?;
-//         reader.rewind()?;
-//
-//         let mut buf = vec![0u8; 16 * 1024];
-//
-//         while reader.stream_position()? < len {
-//             reader.read_exact(&mut buf[..4])?;
-//             let key_len = u32::from_le_bytes(buf[..4].try_into().unwrap()) as usize;
-//
-//             if key_len + 4 > buf.len() {
-//                 buf.resize(key_len + 4, 0);
-//             }
-//
-//             reader.read_exact(&mut buf[..key_len + 4])?;
-//             let value_len = u32::from_le_bytes(buf[key_len..][..4].try_into().unwrap()) as usize;
-//
-//             if key_len + value_len > buf.len() {
-//                 buf.resize(key_len + value_len, 0);
-//             }
-//
-//             reader.read_exact(&mut buf[key_len..][..value_len])?;
-//
-//             self.rocksdb.put(&buf[..key_len], &buf[key_len..][..value_len])?;
-//         }
-//
-//         Ok(())
-//     }
-//
-//     /// Exports the current state of storage to a single file at the specified location.
-//     fn export<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-//         let file = File::create(path)?;
-//         let mut writer = BufWriter::new(file);
-//
-//         let mut iterator = self.rocksdb.raw_iterator();
-//         iterator.seek_to_first();
-//
-//         while iterator.valid() {
-//             if let (Some(key), Some(value)) = (iterator.key(), iterator.value()) {
-//                 writer.write_all(&(key.len() as u32).to_le_bytes())?;
-//                 writer.write_all(key)?;
-//
-//                 writer.write_all(&(value.len() as u32).to_le_bytes())?;
-//                 writer.write_all(value)?;
-//             }
-//             iterator.next();
-//         }
-//
-//         Ok(())
-//     }
-// }
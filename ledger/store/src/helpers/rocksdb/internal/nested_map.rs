@@ -82,7 +82,7 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
     }
 }
 #[inline]
-fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
+pub(crate) fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
     // Retrieve the map length.
     let map_len = u32::from_bytes_le(
         map_key.get(PREFIX_LEN..PREFIX_LEN + 4).ok_or_else(|| anyhow!("NestedMap map_len index out of range"))?,
@@ -111,6 +111,8 @@ impl<
     /// Inserts the given map-key-value pair.
     ///
     fn insert(&self, map: M, key: K, value: V) -> Result<()> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-key-value pair to the batch.
@@ -123,6 +125,8 @@ impl<
                 self.database.put(raw_key, raw_value)?;
             }
         }
+        #[cfg(feature = "metrics")]
+        map_metrics::record_insert_latency(&self.context, start);
         Ok(())
     }
 
@@ -223,7 +227,10 @@ impl<
     ///
     fn atomic_checkpoint(&self) {
         // Push the current length of the atomic batch to the checkpoint stack.
-        self.checkpoints.lock().push(self.atomic_batch.lock().len());
+        let mut checkpoints = self.checkpoints.lock();
+        checkpoints.push(self.atomic_batch.lock().len());
+        #[cfg(feature = "metrics")]
+        map_metrics::record_checkpoint_depth(&self.context, checkpoints.len());
     }
 
     ///
@@ -331,6 +338,8 @@ impl<
         if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            #[cfg(feature = "metrics")]
+            map_metrics::record_atomic_batch_size(batch.len());
             // Execute all the operations atomically.
             self.database.rocksdb.write(batch)?;
             // Ensure that the database atomic batch is empty.
@@ -514,11 +523,16 @@ impl<
     /// Returns the value for the given map and key, if it exists.
     ///
     fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>> {
-        match self.get_map_key_raw(map, key) {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let value = match self.get_map_key_raw(map, key) {
             Ok(Some(bytes)) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
             Ok(None) => Ok(None),
             Err(e) => Err(e),
-        }
+        };
+        #[cfg(feature = "metrics")]
+        map_metrics::record_get_confirmed_latency(&self.context, start);
+        value
     }
 
     ///
@@ -1098,6 +1112,18 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_atomic_writes_can_be_aborted(map);
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_atomic_checkpoints() {
+        // Initialize a map.
+        let map: NestedDataMap<usize, usize, String> =
+            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+                .expect("Failed to open data map");
+
+        crate::helpers::test_helpers::nested_map::check_atomic_checkpoints(map);
+    }
+
     #[test]
     fn test_checkpoint_and_rewind() {
         // The number of items that will be queued to be inserted into the map.
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{RocksDB, PREFIX_LEN};
+
+use anyhow::{bail, ensure, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::atomic::Ordering,
+};
+
+/// The version of the snapshot file format.
+pub const SNAPSHOT_VERSION: u8 = 1;
+
+/// The number of entries that are written to the database in a single batch, when importing a snapshot.
+const IMPORT_BATCH_SIZE: usize = 10_000;
+
+/// The digest of the entries of a single map in a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapDigest {
+    /// The number of entries in the map.
+    pub num_entries: u64,
+    /// The SHA-256 digest of the entries in the map (in key order), in hexadecimal.
+    pub digest: String,
+}
+
+/// A helper to compute the digest of each map in a snapshot.
+#[derive(Default)]
+struct MapDigests(IndexMap<u16, (u64, Sha256)>);
+
+impl MapDigests {
+    /// Updates the digest of the map that the given entry belongs to.
+    fn update(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        // Ensure the key contains the network ID and map ID.
+        ensure!(key.len() >= PREFIX_LEN, "Found a malformed key in the snapshot");
+        // Retrieve the map ID.
+        let map_id = u16::from_le_bytes([key[2], key[3]]);
+        // Update the digest of the map.
+        let (num_entries, hasher) = self.0.entry(map_id).or_insert_with(|| (0, Sha256::new()));
+        *num_entries += 1;
+        hasher.update(u32::try_from(key.len())?.to_le_bytes());
+        hasher.update(key);
+        hasher.update(u32::try_from(value.len())?.to_le_bytes());
+        hasher.update(value);
+        Ok(())
+    }
+
+    /// Returns the digest of each map, ordered by map ID.
+    fn finish(self) -> IndexMap<u16, MapDigest> {
+        let mut digests = self
+            .0
+            .into_iter()
+            .map(|(map_id, (num_entries, hasher))| {
+                let digest = hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect();
+                (map_id, MapDigest { num_entries, digest })
+            })
+            .collect::<IndexMap<_, _>>();
+        digests.sort_keys();
+        digests
+    }
+}
+
+impl RocksDB {
+    /// Exports the entries of the network to a snapshot file at the given path,
+    /// and returns the digest of each map in the snapshot.
+    ///
+    /// The entries are read from a point-in-time view of the database, so concurrent writes are not included.
+    /// Each exported entry is also passed to `visit`, so that the caller can derive state from the same view.
+    pub fn export_snapshot<P: AsRef<Path>>(
+        &self,
+        path: P,
+        mut visit: impl FnMut(&[u8], &[u8]) -> Result<()>,
+    ) -> Result<IndexMap<u16, MapDigest>> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        // Write the header.
+        writer.write_all(&[SNAPSHOT_VERSION])?;
+        writer.write_all(&self.network_id.to_le_bytes())?;
+
+        // Initialize the digests.
+        let mut digests = MapDigests::default();
+
+        // Retrieve a point-in-time view of the database.
+        let snapshot = self.rocksdb.snapshot();
+        // Iterate over all of the maps of the network, in key order.
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_total_order_seek(true);
+        let mut iterator = snapshot.raw_iterator_opt(options);
+        let network_prefix = self.network_id.to_le_bytes();
+        iterator.seek(network_prefix);
+
+        while iterator.valid() {
+            let (Some(key), Some(value)) = (iterator.key(), iterator.value()) else { break };
+            // Stop once the entries of the network have been exported.
+            if !key.starts_with(&network_prefix) {
+                break;
+            }
+            // Write the entry.
+            write_entry(&mut writer, key, value)?;
+            // Update the digests.
+            digests.update(key, value)?;
+            // Visit the entry.
+            visit(key, value)?;
+            iterator.next();
+        }
+        // Ensure the iteration did not fail.
+        iterator.status()?;
+
+        writer.flush()?;
+        Ok(digests.finish())
+    }
+
+    /// Returns the digest of each map in the snapshot file at the given path, for the given network ID.
+    pub fn snapshot_digests<P: AsRef<Path>>(network_id: u16, path: P) -> Result<IndexMap<u16, MapDigest>> {
+        // Open the snapshot.
+        let mut reader = open_snapshot(network_id, path)?;
+        // Compute the digests.
+        let mut digests = MapDigests::default();
+        while let Some((key, value)) = read_entry(&mut reader)? {
+            ensure!(key.starts_with(&network_id.to_le_bytes()), "Found an entry for another network in the snapshot");
+            digests.update(&key, &value)?;
+        }
+        Ok(digests.finish())
+    }
+
+    /// Imports the snapshot file at the given path into the database, after ensuring that it matches
+    /// the given digest of each map. The database must not contain any entries for the network.
+    ///
+    /// If the import fails, all of the entries of the network are removed from the database.
+    pub fn import_snapshot<P: AsRef<Path>>(&self, path: P, expected_digests: &IndexMap<u16, MapDigest>) -> Result<()> {
+        // Ensure the import is not performed during an atomic batch.
+        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, "Cannot import a snapshot during an atomic batch");
+        ensure!(!self.are_atomic_writes_paused(), "Cannot import a snapshot while atomic writes are paused");
+        // Ensure the database does not contain any entries for the network.
+        ensure!(!self.contains_network_entries(), "Cannot import a snapshot into a non-empty database");
+
+        // Ensure the snapshot matches the expected digests, prior to writing to the database.
+        let digests = Self::snapshot_digests(self.network_id, &path)?;
+        for (map_id, expected) in expected_digests {
+            match digests.get(map_id) {
+                Some(digest) if digest == expected => (),
+                _ => bail!("The snapshot does not match the expected digest for map {map_id}"),
+            }
+        }
+        ensure!(digests.len() == expected_digests.len(), "The snapshot contains maps without an expected digest");
+
+        // Write the entries to the database.
+        let result = (|| -> Result<()> {
+            let mut reader = open_snapshot(self.network_id, &path)?;
+            let mut batch = rocksdb::WriteBatch::default();
+            while let Some((key, value)) = read_entry(&mut reader)? {
+                batch.put(key, value);
+                if batch.len() >= IMPORT_BATCH_SIZE {
+                    self.rocksdb.write(std::mem::take(&mut batch))?;
+                }
+            }
+            self.rocksdb.write(batch)?;
+            Ok(())
+        })();
+
+        // If the import failed, remove the partially-imported entries.
+        if let Err(error) = result {
+            self.remove_network_entries()?;
+            bail!("Failed to import the snapshot - {error}");
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the database contains any entries for the network.
+    fn contains_network_entries(&self) -> bool {
+        let network_prefix = self.network_id.to_le_bytes();
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_total_order_seek(true);
+        let mut iterator = self.rocksdb.raw_iterator_opt(options);
+        iterator.seek(network_prefix);
+        iterator.valid() && iterator.key().map_or(false, |key| key.starts_with(&network_prefix))
+    }
+
+    /// Removes all of the entries for the network from the database.
+    pub fn remove_network_entries(&self) -> Result<()> {
+        let network_prefix = self.network_id.to_le_bytes();
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_total_order_seek(true);
+        let mut iterator = self.rocksdb.raw_iterator_opt(options);
+        iterator.seek(network_prefix);
+
+        let mut batch = rocksdb::WriteBatch::default();
+        while iterator.valid() {
+            let Some(key) = iterator.key() else { break };
+            if !key.starts_with(&network_prefix) {
+                break;
+            }
+            batch.delete(key);
+            iterator.next();
+        }
+        iterator.status()?;
+        self.rocksdb.write(batch)?;
+        Ok(())
+    }
+}
+
+/// Opens the snapshot file at the given path, and ensures its header is valid for the given network ID.
+fn open_snapshot<P: AsRef<Path>>(network_id: u16, path: P) -> Result<BufReader<File>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    // Read the header.
+    let mut header = [0u8; 3];
+    reader.read_exact(&mut header)?;
+    // Ensure the version and network ID are correct.
+    ensure!(header[0] == SNAPSHOT_VERSION, "Unsupported snapshot version {} (expected {SNAPSHOT_VERSION})", header[0]);
+    let snapshot_network_id = u16::from_le_bytes([header[1], header[2]]);
+    ensure!(snapshot_network_id == network_id, "The snapshot is for network {snapshot_network_id}, not {network_id}");
+    Ok(reader)
+}
+
+/// Writes the given entry to the snapshot.
+fn write_entry<W: Write>(writer: &mut W, key: &[u8], value: &[u8]) -> Result<()> {
+    writer.write_all(&u32::try_from(key.len())?.to_le_bytes())?;
+    writer.write_all(key)?;
+    writer.write_all(&u32::try_from(value.len())?.to_le_bytes())?;
+    writer.write_all(value)?;
+    Ok(())
+}
+
+/// Reads the next entry from the snapshot, or returns `None` if the snapshot has been read to completion.
+fn read_entry<R: BufRead>(reader: &mut R) -> Result<Option<(Vec<u8>, Vec<u8>)>> {
+    // Check if the snapshot has been read to completion.
+    if reader.fill_buf()?.is_empty() {
+        return Ok(None);
+    }
+    // Read the key.
+    let mut length = [0u8; 4];
+    reader.read_exact(&mut length)?;
+    let mut key = vec![0u8; u32::from_le_bytes(length) as usize];
+    reader.read_exact(&mut key)?;
+    // Read the value.
+    reader.read_exact(&mut length)?;
+    let mut value = vec![0u8; u32::from_le_bytes(length) as usize];
+    reader.read_exact(&mut value)?;
+    Ok(Some((key, value)))
+}
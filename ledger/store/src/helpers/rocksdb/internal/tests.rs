@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::helpers::{
-    rocksdb::{MapID, RocksDB, TestMap as TestMapID},
+    rocksdb::{DataMap, InnerDataMap, MapID, RocksDB, TestMap as TestMapID},
     Map,
     MapRead,
 };
@@ -24,6 +24,7 @@ use console::{
 };
 
 use serial_test::serial;
+use std::sync::Arc;
 
 pub(crate) fn temp_dir() -> std::path::PathBuf {
     tempfile::tempdir().expect("Failed to open temporary directory").into_path()
@@ -127,29 +128,60 @@ fn test_insert_and_values() {
     assert_eq!(None, values.next());
 }
 
-// #[test]
-// #[serial]
-// fn test_export_import() {
-//     let file = temp_file();
-//     {
-//         let mut map = RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-//
-//         for i in 0..100 {
-//             map.insert(i, i.to_string()).expect("Failed to insert");
-//         }
-//
-//         storage.export(&file).expect("Failed to export storage");
-//     }
-//
-//     let storage = RocksDB::open_temporary(temp_dir(), None).expect("Failed to open storage");
-//     storage.import(&file).expect("Failed to import storage");
-//
-//     let map = storage.open_map::<u32, String>(MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-//
-//     for i in 0..100 {
-//         assert_eq!(map.get(&i).expect("Failed to get").map(|v| v.to_string()), Some(i.to_string()));
-//     }
-// }
+#[test]
+#[serial]
+fn test_export_import_snapshot() {
+    let file = temp_dir().join("snapshot.bin");
+
+    // Export a snapshot of the source database.
+    let map =
+        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+    for i in 0..100u32 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
+    }
+    let mut num_visited = 0;
+    let digests = map
+        .database
+        .export_snapshot(&file, |_, _| {
+            num_visited += 1;
+            Ok(())
+        })
+        .expect("Failed to export the snapshot");
+    assert_eq!(num_visited, 100);
+    assert_eq!(digests.len(), 1);
+    assert_eq!(digests[&u16::from(MapID::Test(TestMapID::Test))].num_entries, 100);
+    assert_eq!(RocksDB::snapshot_digests(map.database.network_id, &file).unwrap(), digests);
+
+    // Ensure a snapshot with mismatched digests is rejected.
+    let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+    let mut wrong_digests = digests.clone();
+    wrong_digests.values_mut().for_each(|digest| digest.num_entries += 1);
+    assert!(database.import_snapshot(&file, &wrong_digests).is_err());
+    assert!(!database.contains_network_entries());
+
+    // Import the snapshot into the target database.
+    database.import_snapshot(&file, &digests).expect("Failed to import the snapshot");
+    // Ensure a snapshot cannot be imported into a non-empty database.
+    assert!(database.import_snapshot(&file, &digests).is_err());
+
+    // Ensure the entries were imported.
+    let mut context = database.network_id.to_le_bytes().to_vec();
+    context.extend_from_slice(&u16::from(MapID::Test(TestMapID::Test)).to_le_bytes());
+    let imported: DataMap<u32, String> = DataMap(Arc::new(InnerDataMap {
+        database: database.clone(),
+        context,
+        batch_in_progress: Default::default(),
+        atomic_batch: Default::default(),
+        checkpoints: Default::default(),
+    }));
+    for i in 0..100u32 {
+        assert_eq!(imported.get_confirmed(&i).expect("Failed to get").map(|v| v.to_string()), Some(i.to_string()));
+    }
+
+    // Ensure the entries can be removed.
+    database.remove_network_entries().expect("Failed to remove the entries");
+    assert!(imported.get_confirmed(&0).unwrap().is_none());
+}
 
 #[test]
 #[serial]
@@ -23,8 +23,10 @@ use crate::{
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
 
 use aleo_std_storage::StorageMode;
 use indexmap::IndexSet;
@@ -38,6 +40,20 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The history map.
+    history_map: NestedDataMap<Field<N>, u32, Option<Value<N>>>,
+    /// The key undo map.
+    key_undo_map: NestedDataMap<u32, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>,
+    /// The program undo map.
+    program_undo_map: NestedDataMap<u32, ProgramID<N>, Option<IndexSet<Identifier<N>>>>,
+    /// The history range map.
+    history_range_map: DataMap<u8, (u32, u32)>,
+    /// The ratified operations map.
+    ratified_operations_map: DataMap<u32, Vec<FinalizeOperation<N>>>,
+    /// The ratified operations start map.
+    ratified_operations_start_map: DataMap<u8, u32>,
+    /// The operation value map.
+    operation_value_map: NestedDataMap<u32, Field<N>, Value<N>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +63,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type HistoryMap = NestedDataMap<Field<N>, u32, Option<Value<N>>>;
+    type KeyUndoMap = NestedDataMap<u32, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>;
+    type ProgramUndoMap = NestedDataMap<u32, ProgramID<N>, Option<IndexSet<Identifier<N>>>>;
+    type HistoryRangeMap = DataMap<u8, (u32, u32)>;
+    type RatifiedOperationsMap = DataMap<u32, Vec<FinalizeOperation<N>>>;
+    type RatifiedOperationsStartMap = DataMap<u8, u32>;
+    type OperationValueMap = NestedDataMap<u32, Field<N>, Value<N>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +80,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            history_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::History))?,
+            key_undo_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyUndo))?,
+            program_undo_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramUndo))?,
+            history_range_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::HistoryRange))?,
+            ratified_operations_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::RatifiedOperations))?,
+            ratified_operations_start_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::RatifiedOperationsStart))?,
+            operation_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::OperationValue))?,
             storage_mode: storage.into(),
         })
     }
@@ -70,7 +100,14 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         Ok(Self {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
-            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::History))?,
+            key_undo_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyUndo))?,
+            program_undo_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramUndo))?,
+            history_range_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::HistoryRange))?,
+            ratified_operations_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::RatifiedOperations))?,
+            ratified_operations_start_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::RatifiedOperationsStart))?,
+            operation_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::OperationValue))?,
             storage_mode: dev.into(),
         })
     }
@@ -90,6 +127,41 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the key undo map.
+    fn key_undo_map(&self) -> &Self::KeyUndoMap {
+        &self.key_undo_map
+    }
+
+    /// Returns the program undo map.
+    fn program_undo_map(&self) -> &Self::ProgramUndoMap {
+        &self.program_undo_map
+    }
+
+    /// Returns the history range map.
+    fn history_range_map(&self) -> &Self::HistoryRangeMap {
+        &self.history_range_map
+    }
+
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap {
+        &self.ratified_operations_map
+    }
+
+    /// Returns the ratified operations start map.
+    fn ratified_operations_start_map(&self) -> &Self::RatifiedOperationsStartMap {
+        &self.ratified_operations_start_map
+    }
+
+    /// Returns the operation value map.
+    fn operation_value_map(&self) -> &Self::OperationValueMap {
+        &self.operation_value_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -0,0 +1,354 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::rocksdb::{
+        get_map_and_key,
+        BlockMap,
+        MapDigest,
+        MapID,
+        ProgramMap,
+        RocksDB,
+        PREFIX_LEN,
+        SNAPSHOT_VERSION,
+    },
+    to_entry_checksums,
+    BlockStorage,
+    BlockStore,
+    FinalizeStorage,
+    FinalizeStore,
+};
+use console::{
+    prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+
+use aleo_std_storage::StorageMode;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// The file name of the snapshot manifest, within a snapshot directory.
+pub const SNAPSHOT_MANIFEST_FILE: &str = "manifest.json";
+/// The file name of the snapshot entries, within a snapshot directory.
+pub const SNAPSHOT_DATA_FILE: &str = "snapshot.bin";
+
+/// The manifest of a snapshot, which commits to the state of the ledger at the snapshot height.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SnapshotManifest<N: Network> {
+    /// The version of the snapshot format.
+    version: u8,
+    /// The network ID.
+    network_id: u16,
+    /// The block height of the snapshot.
+    height: u32,
+    /// The block hash at the snapshot height.
+    block_hash: N::BlockHash,
+    /// The state root at the snapshot height.
+    state_root: N::StateRoot,
+    /// The confirmed checksum of the finalize store at the snapshot height.
+    finalize_checksum: Field<N>,
+    /// The digest of each map in the snapshot, keyed by map ID.
+    map_digests: IndexMap<u16, MapDigest>,
+}
+
+impl<N: Network> SnapshotManifest<N> {
+    /// Returns the version of the snapshot format.
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the network ID.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    /// Returns the block height of the snapshot.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block hash at the snapshot height.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the state root at the snapshot height.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the confirmed checksum of the finalize store at the snapshot height.
+    pub const fn finalize_checksum(&self) -> Field<N> {
+        self.finalize_checksum
+    }
+
+    /// Returns the digest of each map in the snapshot, keyed by map ID.
+    pub const fn map_digests(&self) -> &IndexMap<u16, MapDigest> {
+        &self.map_digests
+    }
+}
+
+impl<N: Network> SnapshotManifest<N> {
+    /// Returns the checkpoint of the ledger state that the manifest commits to.
+    pub const fn checkpoint(&self) -> SnapshotCheckpoint<N> {
+        SnapshotCheckpoint::new(self.height, self.block_hash, self.state_root, self.finalize_checksum)
+    }
+
+    /// Ensures the manifest commits to the ledger state of the given checkpoint.
+    pub fn check_checkpoint(&self, checkpoint: &SnapshotCheckpoint<N>) -> Result<()> {
+        ensure!(
+            self.height == checkpoint.height && self.block_hash == checkpoint.block_hash,
+            "The snapshot (block {} '{}') does not match the checkpoint (block {} '{}')",
+            self.height,
+            self.block_hash,
+            checkpoint.height,
+            checkpoint.block_hash
+        );
+        ensure!(
+            self.state_root == checkpoint.state_root,
+            "The snapshot state root '{}' does not match the checkpoint state root '{}'",
+            self.state_root,
+            checkpoint.state_root
+        );
+        ensure!(
+            self.finalize_checksum == checkpoint.finalize_checksum,
+            "The snapshot finalize checksum '{}' does not match the checkpoint finalize checksum '{}'",
+            self.finalize_checksum,
+            checkpoint.finalize_checksum
+        );
+        Ok(())
+    }
+
+    /// Ensures the given stores match the manifest.
+    pub fn check<B: BlockStorage<N>, P: FinalizeStorage<N>>(
+        &self,
+        block_store: &BlockStore<N, B>,
+        finalize_store: &FinalizeStore<N, P>,
+    ) -> Result<()> {
+        // Ensure the version and network ID match.
+        ensure!(self.version == SNAPSHOT_VERSION, "Unsupported snapshot version {}", self.version);
+        ensure!(self.network_id == N::ID, "The snapshot is for network {}, not {}", self.network_id, N::ID);
+        // Ensure the latest height and block hash match.
+        let height = block_store.heights().max().map(|height| *height);
+        ensure!(height == Some(self.height), "The snapshot height {} does not match the ledger", self.height);
+        ensure!(
+            block_store.get_block_hash(self.height)? == Some(self.block_hash),
+            "The snapshot block hash '{}' does not match the ledger",
+            self.block_hash
+        );
+        // Ensure the state root matches, both as stored and as recomputed from the block tree.
+        ensure!(
+            block_store.get_state_root(self.height)? == Some(self.state_root),
+            "The snapshot state root '{}' does not match the stored state root",
+            self.state_root
+        );
+        ensure!(
+            block_store.current_state_root() == self.state_root,
+            "The snapshot state root '{}' does not match the block tree",
+            self.state_root
+        );
+        // Ensure the finalize checksum matches.
+        ensure!(
+            finalize_store.get_checksum_confirmed()? == self.finalize_checksum,
+            "The snapshot finalize checksum '{}' does not match the finalize store",
+            self.finalize_checksum
+        );
+        Ok(())
+    }
+
+    /// Reads the manifest from the given snapshot directory.
+    pub fn read<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let manifest = fs::read_to_string(directory.as_ref().join(SNAPSHOT_MANIFEST_FILE))?;
+        Ok(serde_json::from_str(&manifest)?)
+    }
+
+    /// Writes the manifest to the given snapshot directory.
+    fn write<P: AsRef<Path>>(&self, directory: P) -> Result<()> {
+        let manifest = serde_json::to_string_pretty(self)?;
+        Ok(fs::write(directory.as_ref().join(SNAPSHOT_MANIFEST_FILE), manifest)?)
+    }
+}
+
+/// A trusted checkpoint of the ledger state at a block height, which a snapshot must match to be loaded.
+///
+/// The state root commits to the block hashes up to the height, and the finalize checksum commits to the
+/// finalize state at the height. As the manifest of a snapshot is not trusted, the checkpoint must be obtained
+/// from a trusted source, e.g. from `SnapshotManifest::checkpoint` on the node that exported the snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SnapshotCheckpoint<N: Network> {
+    /// The block height of the checkpoint.
+    height: u32,
+    /// The block hash at the checkpoint height.
+    block_hash: N::BlockHash,
+    /// The state root at the checkpoint height.
+    state_root: N::StateRoot,
+    /// The confirmed checksum of the finalize store at the checkpoint height.
+    finalize_checksum: Field<N>,
+}
+
+impl<N: Network> SnapshotCheckpoint<N> {
+    /// Initializes a new checkpoint of the ledger state at the given height.
+    pub const fn new(
+        height: u32,
+        block_hash: N::BlockHash,
+        state_root: N::StateRoot,
+        finalize_checksum: Field<N>,
+    ) -> Self {
+        Self { height, block_hash, state_root, finalize_checksum }
+    }
+
+    /// Returns the block height of the checkpoint.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block hash at the checkpoint height.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the state root at the checkpoint height.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the confirmed checksum of the finalize store at the checkpoint height.
+    pub const fn finalize_checksum(&self) -> Field<N> {
+        self.finalize_checksum
+    }
+}
+
+/// A helper to derive the manifest fields from the entries of a snapshot, as they are exported.
+struct SnapshotState<N: Network> {
+    /// The latest block height and hash, from the block ID map.
+    latest_block: Option<(u32, N::BlockHash)>,
+    /// The latest block height and state root, from the block state root map.
+    latest_state_root: Option<(u32, N::StateRoot)>,
+    /// The mapping checksum and entry checksum of each confirmed finalize entry.
+    entry_checksums: BTreeMap<Field<N>, Vec<bool>>,
+}
+
+impl<N: Network> SnapshotState<N> {
+    /// Initializes an empty snapshot state.
+    fn new() -> Self {
+        Self { latest_block: None, latest_state_root: None, entry_checksums: Default::default() }
+    }
+
+    /// Updates the state with the given raw entry of the snapshot.
+    fn update(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        // Retrieve the map ID.
+        let map_id = u16::from_le_bytes([key[2], key[3]]);
+        if map_id == u16::from(MapID::Block(BlockMap::ID)) {
+            // Keep the block hash of the latest height.
+            let height: u32 = bincode::deserialize(&key[PREFIX_LEN..])?;
+            if self.latest_block.map_or(true, |(latest, _)| height > latest) {
+                self.latest_block = Some((height, bincode::deserialize(value)?));
+            }
+        } else if map_id == u16::from(MapID::Block(BlockMap::StateRoot)) {
+            // Keep the state root of the latest height.
+            let height: u32 = bincode::deserialize(&key[PREFIX_LEN..])?;
+            if self.latest_state_root.map_or(true, |(latest, _)| height > latest) {
+                self.latest_state_root = Some((height, bincode::deserialize(value)?));
+            }
+        } else if map_id == u16::from(MapID::Program(ProgramMap::KeyValueID)) {
+            // Compute the checksums of the finalize entry.
+            let (mapping, key) = get_map_and_key(key)?;
+            let mapping: (ProgramID<N>, Identifier<N>) = bincode::deserialize(mapping)?;
+            let key: Plaintext<N> = bincode::deserialize(key)?;
+            let value: Value<N> = bincode::deserialize(value)?;
+            let (mapping_checksum, entry_checksum) = to_entry_checksums(mapping, &key, &value)?;
+            self.entry_checksums.insert(mapping_checksum, entry_checksum);
+        }
+        Ok(())
+    }
+
+    /// Returns the manifest for the exported entries, with the given map digests.
+    fn into_manifest(self, map_digests: IndexMap<u16, MapDigest>) -> Result<SnapshotManifest<N>> {
+        // Retrieve the latest height and block hash.
+        let Some((height, block_hash)) = self.latest_block else {
+            bail!("Cannot create a snapshot of an empty ledger")
+        };
+        // Retrieve the state root at the latest height.
+        let state_root = match self.latest_state_root {
+            Some((state_root_height, state_root)) if state_root_height == height => state_root,
+            _ => bail!("Missing the state root for block {height} in the snapshot"),
+        };
+        // Compute the finalize checksum as `Hash( all mapping checksums )`, as in `get_checksum_confirmed`.
+        let finalize_checksum = N::hash_bhp1024(&self.entry_checksums.into_values().flatten().collect::<Vec<_>>())?;
+        Ok(SnapshotManifest {
+            version: SNAPSHOT_VERSION,
+            network_id: N::ID,
+            height,
+            block_hash,
+            state_root,
+            finalize_checksum,
+            map_digests,
+        })
+    }
+}
+
+/// Exports a snapshot of the storage at its latest height into the given (new) directory,
+/// and returns the manifest of the snapshot.
+///
+/// The manifest is derived from the exported entries, so it matches the snapshot even if the ledger
+/// advances during the export.
+pub fn export_snapshot<N: Network, S: Clone + Into<StorageMode>, P: AsRef<Path>>(
+    storage: S,
+    directory: P,
+) -> Result<SnapshotManifest<N>> {
+    let directory = directory.as_ref();
+    // Ensure the directory does not exist.
+    ensure!(!directory.exists(), "The snapshot directory '{}' already exists", directory.display());
+    fs::create_dir_all(directory)?;
+
+    // Export the entries of the database, and derive the manifest from them.
+    let database = RocksDB::open(N::ID, storage)?;
+    let mut state = SnapshotState::<N>::new();
+    let map_digests =
+        database.export_snapshot(directory.join(SNAPSHOT_DATA_FILE), |key, value| state.update(key, value))?;
+    let manifest = state.into_manifest(map_digests)?;
+
+    // Write the manifest.
+    manifest.write(directory)?;
+    Ok(manifest)
+}
+
+/// Imports the snapshot in the given directory into the (empty) storage, after ensuring its entries
+/// match the digests in its manifest, and returns the manifest of the snapshot.
+///
+/// Note: The caller must open the stores and `check` them against the returned manifest,
+/// and should call `remove_snapshot` on failure.
+pub fn import_snapshot<N: Network, S: Clone + Into<StorageMode>, P: AsRef<Path>>(
+    storage: S,
+    directory: P,
+) -> Result<SnapshotManifest<N>> {
+    // Read the manifest.
+    let manifest = SnapshotManifest::<N>::read(&directory)?;
+    // Ensure the version and network ID match.
+    ensure!(manifest.version == SNAPSHOT_VERSION, "Unsupported snapshot version {}", manifest.version);
+    ensure!(manifest.network_id == N::ID, "The snapshot is for network {}, not {}", manifest.network_id, N::ID);
+    // Import the entries of the snapshot.
+    let database = RocksDB::open(N::ID, storage)?;
+    database.import_snapshot(directory.as_ref().join(SNAPSHOT_DATA_FILE), &manifest.map_digests)?;
+    Ok(manifest)
+}
+
+/// Removes all of the entries for the network from the storage, i.e. an imported snapshot that failed its checks.
+pub fn remove_snapshot<N: Network, S: Clone + Into<StorageMode>>(storage: S) -> Result<()> {
+    RocksDB::open(N::ID, storage)?.remove_network_entries()
+}
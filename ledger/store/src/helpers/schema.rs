@@ -0,0 +1,411 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MapID, ProgramMap, SchemaMap};
+use crate::RATIFIED_OPERATIONS_START_KEY;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use tracing::info;
+
+/// The version of the persistent storage schema, i.e. the layout of the `MapID` prefixes and the serialization
+/// of the keys and values of every map.
+// Note: this version MUST be incremented whenever the schema changes, and a migration
+// from the previous version MUST be registered in `MIGRATIONS`.
+pub const SCHEMA_VERSION: u32 = 6;
+
+/// The registered migrations, each transforming the storage from its `from` version to the next one.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 0,
+        description: "Record the schema version of a storage created before schema versioning",
+        migrate: |_| Ok(()),
+    },
+    Migration {
+        from: 1,
+        // Note: The ratified finalize operations of a block are only known while it is finalized,
+        // so they are unavailable for the blocks finalized prior to this migration (see the migration from 5).
+        description: "Add the map of the ratified finalize operations of each block",
+        migrate: |_| Ok(()),
+    },
+    Migration {
+        from: 2,
+        // Note: The finalize history recorded prior to this migration is not used, as its range is unknown.
+        description: "Add the range of the blocks with a recorded finalize history",
+        migrate: |_| Ok(()),
+    },
+    Migration {
+        from: 3,
+        // Note: Pruning was not available prior to this migration, so no block is pruned, nor is a depth set.
+        description: "Add the pruning depth, and the set of the pruned blocks",
+        migrate: |_| Ok(()),
+    },
+    Migration {
+        from: 4,
+        // Note: The values of the blocks finalized prior to this migration are decoded from the finalize history.
+        description: "Add the map of the values written by the finalize operations of each block",
+        migrate: |_| Ok(()),
+    },
+    Migration {
+        from: 5,
+        description: "Record the first block with stored ratified finalize operations",
+        migrate: |migrator| {
+            // Determine the first block height with stored ratified finalize operations, if any.
+            let mut start = None;
+            for (key, _) in migrator.entries(MapID::Program(ProgramMap::RatifiedOperations))? {
+                let height = bincode::deserialize::<u32>(&key)?;
+                start = Some(start.map_or(height, |start: u32| start.min(height)));
+            }
+            // Record the first block height.
+            if let Some(start) = start {
+                migrator.insert(
+                    MapID::Program(ProgramMap::RatifiedOperationsStart),
+                    &bincode::serialize(&RATIFIED_OPERATIONS_START_KEY)?,
+                    bincode::serialize(&start)?,
+                );
+            }
+            Ok(())
+        },
+    },
+];
+
+/// A migration of the persistent storage from one schema version to the next.
+#[derive(Clone, Copy)]
+pub struct Migration {
+    /// The schema version the migration applies to.
+    pub from: u32,
+    /// A short description of the migration.
+    pub description: &'static str,
+    /// The function queueing the operations of the migration.
+    pub migrate: fn(&mut Migrator) -> Result<()>,
+}
+
+/// A raw view of a persistent storage, used to record its schema version and migrate it.
+pub trait SchemaStore {
+    /// Returns the value stored under the given raw key, if any.
+    fn get_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// Returns the raw key-value pairs whose key starts with the given prefix, in key order.
+    fn prefix_entries(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
+
+    /// Returns `true` if any raw key starts with the given prefix.
+    fn contains_prefix(&self, prefix: &[u8]) -> Result<bool>;
+
+    /// Atomically applies the given raw operations, where a `None` value denotes a removal.
+    fn apply_raw(&self, operations: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<()>;
+}
+
+/// The context of a migration, which queues the operations that are applied atomically,
+/// alongside the new schema version, once the migration succeeds.
+pub struct Migrator<'a> {
+    /// The storage being migrated.
+    store: &'a dyn SchemaStore,
+    /// The network ID of the storage.
+    network_id: u16,
+    /// The queued operations.
+    operations: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+}
+
+impl<'a> Migrator<'a> {
+    /// Returns the prefix of the given map.
+    fn map_prefix(&self, map_id: MapID) -> Vec<u8> {
+        let mut prefix = self.network_id.to_le_bytes().to_vec();
+        prefix.extend_from_slice(&u16::from(map_id).to_le_bytes());
+        prefix
+    }
+
+    /// Returns the serialized key-value pairs of the given map, as they were prior to the migration.
+    pub fn entries(&self, map_id: MapID) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let prefix = self.map_prefix(map_id);
+        Ok(self
+            .store
+            .prefix_entries(&prefix)?
+            .into_iter()
+            .map(|(key, value)| (key[prefix.len()..].to_vec(), value))
+            .collect())
+    }
+
+    /// Queues the insertion of the given serialized key-value pair into the given map.
+    pub fn insert(&mut self, map_id: MapID, key: &[u8], value: Vec<u8>) {
+        let mut raw_key = self.map_prefix(map_id);
+        raw_key.extend_from_slice(key);
+        self.operations.push((raw_key, Some(value)));
+    }
+
+    /// Queues the removal of the given serialized key from the given map.
+    pub fn remove(&mut self, map_id: MapID, key: &[u8]) {
+        let mut raw_key = self.map_prefix(map_id);
+        raw_key.extend_from_slice(key);
+        self.operations.push((raw_key, None));
+    }
+}
+
+/// Returns the raw key of the schema version record of the given network.
+fn schema_version_key(network_id: u16) -> Vec<u8> {
+    let mut key = network_id.to_le_bytes().to_vec();
+    key.extend_from_slice(&u16::from(MapID::Schema(SchemaMap::Version)).to_le_bytes());
+    key
+}
+
+/// Returns the schema version recorded in the given storage, if any.
+pub fn schema_version(store: &dyn SchemaStore, network_id: u16) -> Result<Option<u32>> {
+    match store.get_raw(&schema_version_key(network_id))? {
+        Some(bytes) => {
+            Ok(Some(bincode::deserialize(&bytes).map_err(|e| anyhow!("Malformed storage schema version - {e}"))?))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Ensures the given storage uses the `target` schema version, migrating it using the given migrations if needed.
+///
+/// A storage without entries is initialized with the `target` version, while a populated storage without a
+/// schema version record is considered to be at version 0. Each migration is applied in its own atomic write,
+/// alongside its new schema version, so that an interrupted migration resumes from the last completed version.
+/// A storage that uses a newer schema version, or that cannot be migrated, is refused with an error.
+pub fn ensure_schema_version(
+    store: &dyn SchemaStore,
+    network_id: u16,
+    migrations: &[Migration],
+    target: u32,
+) -> Result<()> {
+    let key = schema_version_key(network_id);
+
+    // Determine the schema version of the storage.
+    let mut version = match schema_version(store, network_id)? {
+        Some(version) => version,
+        // Initialize the schema version of a new storage.
+        None if !store.contains_prefix(&network_id.to_le_bytes())? => {
+            return store.apply_raw(vec![(key, Some(bincode::serialize(&target)?))]);
+        }
+        // A populated storage without a schema version predates schema versioning.
+        None => 0,
+    };
+
+    // Ensure the storage does not use a newer schema version.
+    ensure!(
+        version <= target,
+        "The ledger storage uses schema version {version}, which is newer than the supported schema version \
+         {target} - upgrade to a newer release to open it"
+    );
+
+    // Migrate the storage, one schema version at a time.
+    while version < target {
+        let Some(migration) = migrations.iter().find(|migration| migration.from == version) else {
+            bail!(
+                "The ledger storage uses schema version {version}, which cannot be migrated to the supported \
+                 schema version {target} - the ledger must be resynced"
+            )
+        };
+        info!(
+            "Migrating the ledger storage from schema version {version} to {} ({})",
+            version + 1,
+            migration.description
+        );
+
+        // Queue the operations of the migration.
+        let mut migrator = Migrator { store, network_id, operations: Vec::new() };
+        (migration.migrate)(&mut migrator)
+            .map_err(|e| anyhow!("Failed to migrate the ledger storage from schema version {version} - {e}"))?;
+
+        // Apply the operations, alongside the new schema version.
+        version += 1;
+        migrator.operations.push((key.clone(), Some(bincode::serialize(&version)?)));
+        store.apply_raw(migrator.operations)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::TestMap;
+
+    use parking_lot::RwLock;
+    use std::collections::BTreeMap;
+
+    const NETWORK_ID: u16 = 7;
+
+    /// A raw in-memory store.
+    #[derive(Default)]
+    struct RawStore(RwLock<BTreeMap<Vec<u8>, Vec<u8>>>);
+
+    impl SchemaStore for RawStore {
+        fn get_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+            Ok(self.0.read().get(key).cloned())
+        }
+
+        fn prefix_entries(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+            Ok(self
+                .0
+                .read()
+                .range(prefix.to_vec()..)
+                .take_while(|(key, _)| key.starts_with(prefix))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect())
+        }
+
+        fn contains_prefix(&self, prefix: &[u8]) -> Result<bool> {
+            Ok(self.0.read().range(prefix.to_vec()..).next().is_some_and(|(key, _)| key.starts_with(prefix)))
+        }
+
+        fn apply_raw(&self, operations: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<()> {
+            let mut entries = self.0.write();
+            for (key, value) in operations {
+                match value {
+                    Some(value) => entries.insert(key, value),
+                    None => entries.remove(&key),
+                };
+            }
+            Ok(())
+        }
+    }
+
+    /// Inserts the given entry into the test map, using the serialization of the given schema version.
+    fn insert_test_entry(store: &RawStore, key: u32, value: u64, version: u32) {
+        let mut raw_key = NETWORK_ID.to_le_bytes().to_vec();
+        raw_key.extend_from_slice(&u16::from(MapID::Test(TestMap::Test)).to_le_bytes());
+        raw_key.extend_from_slice(&bincode::serialize(&key).unwrap());
+        let raw_value = match version {
+            1 => bincode::serialize(&u32::try_from(value).unwrap()).unwrap(),
+            _ => bincode::serialize(&value).unwrap(),
+        };
+        store.apply_raw(vec![(raw_key, Some(raw_value))]).unwrap();
+    }
+
+    /// A migration widening the values of the test map from `u32` to `u64`.
+    const WIDEN_TEST_VALUES: Migration = Migration {
+        from: 1,
+        description: "Widen the test values",
+        migrate: |migrator| {
+            for (key, value) in migrator.entries(MapID::Test(TestMap::Test))? {
+                let value = bincode::deserialize::<u32>(&value)?;
+                migrator.insert(MapID::Test(TestMap::Test), &key, bincode::serialize(&u64::from(value))?);
+            }
+            Ok(())
+        },
+    };
+
+    #[test]
+    fn test_new_storage_is_versioned() {
+        let store = RawStore::default();
+        assert_eq!(schema_version(&store, NETWORK_ID).unwrap(), None);
+
+        // Ensure a new storage is initialized with the target version.
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, SCHEMA_VERSION).unwrap();
+        assert_eq!(schema_version(&store, NETWORK_ID).unwrap(), Some(SCHEMA_VERSION));
+        // Ensure the version of another network is unaffected.
+        assert_eq!(schema_version(&store, NETWORK_ID + 1).unwrap(), None);
+
+        // Ensure the storage can be reopened.
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, SCHEMA_VERSION).unwrap();
+    }
+
+    #[test]
+    fn test_unversioned_storage_is_migrated() {
+        let store = RawStore::default();
+        insert_test_entry(&store, 0, 1, 1);
+
+        // Ensure a populated storage without a version record is migrated from version 0.
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, SCHEMA_VERSION).unwrap();
+        assert_eq!(schema_version(&store, NETWORK_ID).unwrap(), Some(SCHEMA_VERSION));
+        assert_eq!(store.prefix_entries(&NETWORK_ID.to_le_bytes()).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_migrate_ratified_operations_start() {
+        let store = RawStore::default();
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, 5).unwrap();
+
+        // Store the (empty) ratified finalize operations of the blocks from 3, as if the earlier blocks predate them.
+        let mut migrator = Migrator { store: &store, network_id: NETWORK_ID, operations: Vec::new() };
+        for height in [4u32, 3, 5] {
+            let operations: Vec<()> = Vec::new();
+            migrator.insert(
+                MapID::Program(ProgramMap::RatifiedOperations),
+                &bincode::serialize(&height).unwrap(),
+                bincode::serialize(&operations).unwrap(),
+            );
+        }
+        store.apply_raw(migrator.operations).unwrap();
+
+        // Ensure the migration records the first block height with stored ratified finalize operations.
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, 6).unwrap();
+        let migrator = Migrator { store: &store, network_id: NETWORK_ID, operations: Vec::new() };
+        let entries = migrator.entries(MapID::Program(ProgramMap::RatifiedOperationsStart)).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(bincode::deserialize::<u8>(&entries[0].0).unwrap(), RATIFIED_OPERATIONS_START_KEY);
+        assert_eq!(bincode::deserialize::<u32>(&entries[0].1).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_migrate_storage() {
+        let store = RawStore::default();
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, 1).unwrap();
+        for i in 0..5 {
+            insert_test_entry(&store, i, u64::from(i) * 10, 1);
+        }
+
+        // Migrate the storage to version 2.
+        let migrations = [MIGRATIONS[0], WIDEN_TEST_VALUES];
+        ensure_schema_version(&store, NETWORK_ID, &migrations, 2).unwrap();
+        assert_eq!(schema_version(&store, NETWORK_ID).unwrap(), Some(2));
+
+        // Ensure the values were widened.
+        let migrator = Migrator { store: &store, network_id: NETWORK_ID, operations: Vec::new() };
+        let entries = migrator.entries(MapID::Test(TestMap::Test)).unwrap();
+        assert_eq!(entries.len(), 5);
+        for (i, (key, value)) in (0u32..).zip(entries) {
+            assert_eq!(bincode::deserialize::<u32>(&key).unwrap(), i);
+            assert_eq!(bincode::deserialize::<u64>(&value).unwrap(), u64::from(i) * 10);
+        }
+    }
+
+    #[test]
+    fn test_refuse_mismatched_storage() {
+        let store = RawStore::default();
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, SCHEMA_VERSION).unwrap();
+
+        // Ensure a storage with a newer schema version is refused.
+        let error = ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, SCHEMA_VERSION - 1).unwrap_err();
+        assert!(error.to_string().contains(&format!("newer than the supported schema version {}", SCHEMA_VERSION - 1)));
+
+        // Ensure a storage that cannot be migrated is refused.
+        let error = ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, SCHEMA_VERSION + 1).unwrap_err();
+        assert!(error.to_string().contains("cannot be migrated"));
+        assert_eq!(schema_version(&store, NETWORK_ID).unwrap(), Some(SCHEMA_VERSION));
+    }
+
+    #[test]
+    fn test_failed_migration_is_not_applied() {
+        let store = RawStore::default();
+        ensure_schema_version(&store, NETWORK_ID, MIGRATIONS, 1).unwrap();
+        insert_test_entry(&store, 0, 1, 1);
+
+        // Attempt a migration that fails after queueing an operation.
+        let failing = Migration {
+            from: 1,
+            description: "Fail",
+            migrate: |migrator| {
+                migrator.remove(MapID::Test(TestMap::Test), &bincode::serialize(&0u32)?);
+                bail!("Failed on purpose")
+            },
+        };
+        assert!(ensure_schema_version(&store, NETWORK_ID, &[failing], 2).is_err());
+
+        // Ensure neither the queued operation nor the new version were applied.
+        assert_eq!(schema_version(&store, NETWORK_ID).unwrap(), Some(1));
+        assert_eq!(store.prefix_entries(&NETWORK_ID.to_le_bytes()).unwrap().len(), 2);
+    }
+}
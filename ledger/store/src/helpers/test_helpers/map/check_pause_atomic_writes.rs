@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::Map;
+
+use std::borrow::Cow;
+
+pub fn check_pause_atomic_writes(map: impl for<'a> Map<'a, usize, String>) {
+    // The number of items that will be inserted into the map.
+    const NUM_ITEMS: usize = 10;
+
+    // Sanity check.
+    super::ensure_map_is_empty(&map);
+
+    // Pause the atomic writes.
+    map.pause_atomic_writes().unwrap();
+
+    // Perform NUM_ITEMS separate atomic write batches.
+    for i in 0..NUM_ITEMS {
+        // Start an atomic write batch.
+        map.start_atomic();
+        // Queue the insertion of a single item.
+        map.insert(i, i.to_string()).unwrap();
+        // Ensure that the item can be found with a speculative get.
+        assert_eq!(map.get_speculative(&i).unwrap(), Some(Cow::Owned(i.to_string())));
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+        // Ensure that the atomic batch is no longer in progress.
+        assert!(!map.is_atomic_in_progress());
+    }
+
+    // Unpause the atomic writes, executing the queued ones.
+    map.unpause_atomic_writes::<false>().unwrap();
+
+    // Check that the items are present in the map now.
+    for i in 0..NUM_ITEMS {
+        assert_eq!(map.get_confirmed(&i).unwrap(), Some(Cow::Borrowed(&i.to_string())));
+    }
+    assert!(map.iter_pending().next().is_none());
+
+    // Ensure that atomic write batches behave as usual once unpaused.
+    map.start_atomic();
+    map.remove(&0).unwrap();
+    map.finish_atomic().unwrap();
+    assert!(!map.contains_key_confirmed(&0).unwrap());
+    assert_eq!(map.iter_confirmed().count(), NUM_ITEMS - 1);
+}
+
+/// Checks that the atomic writes performed while paused are deferred until unpausing, and can be discarded.
+/// Note: This only applies to the persistent backends, as the in-memory backend does not queue writes.
+pub fn check_pause_atomic_writes_are_deferred(map: impl for<'a> Map<'a, usize, String>) {
+    // The number of items that will be inserted into the map.
+    const NUM_ITEMS: usize = 10;
+
+    // Sanity check.
+    super::ensure_map_is_empty(&map);
+
+    // Pause the atomic writes.
+    map.pause_atomic_writes().unwrap();
+
+    // Perform NUM_ITEMS separate atomic write batches.
+    for i in 0..NUM_ITEMS {
+        map.start_atomic();
+        map.insert(i, i.to_string()).unwrap();
+        map.finish_atomic().unwrap();
+    }
+
+    // The map should still contain no items.
+    assert!(map.iter_confirmed().next().is_none());
+
+    // Unpause the atomic writes, discarding the queued ones.
+    map.unpause_atomic_writes::<true>().unwrap();
+
+    // The map should still contain no items.
+    assert!(map.iter_confirmed().next().is_none());
+
+    // Pause the atomic writes again, and perform a single atomic write batch.
+    map.pause_atomic_writes().unwrap();
+    map.start_atomic();
+    for i in 0..NUM_ITEMS {
+        map.insert(i, i.to_string()).unwrap();
+    }
+    map.finish_atomic().unwrap();
+
+    // The map should still contain no items.
+    assert!(map.iter_confirmed().next().is_none());
+
+    // Unpause the atomic writes, executing the queued ones.
+    map.unpause_atomic_writes::<false>().unwrap();
+
+    // The map should contain NUM_ITEMS items now.
+    assert_eq!(map.iter_confirmed().count(), NUM_ITEMS);
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod check_atomic_checkpoints;
+pub use check_atomic_checkpoints::*;
+
 mod check_atomic_writes_are_batched;
 pub use check_atomic_writes_are_batched::*;
 
@@ -27,6 +30,9 @@ pub use check_insert_and_get_speculative::*;
 mod check_iterators_match;
 pub use check_iterators_match::*;
 
+mod check_pause_atomic_writes;
+pub use check_pause_atomic_writes::*;
+
 mod check_remove_and_get_speculative;
 pub use check_remove_and_get_speculative::*;
 
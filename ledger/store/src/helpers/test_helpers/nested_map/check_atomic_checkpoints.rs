@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::NestedMap;
+
+pub fn check_atomic_checkpoints(map: impl for<'a> NestedMap<'a, usize, usize, String>) {
+    // The number of items that will be queued to be inserted into the map.
+    const NUM_ITEMS: usize = 10;
+
+    // Sanity check.
+    super::ensure_map_is_empty(&map);
+
+    // Start an atomic write batch.
+    map.start_atomic();
+
+    // Queue (since a batch is in progress) NUM_ITEMS / 2 insertions into two maps.
+    for i in 0..NUM_ITEMS / 2 {
+        map.insert(0, i, i.to_string()).unwrap();
+        map.insert(1, i, i.to_string()).unwrap();
+    }
+
+    // Perform a checkpoint.
+    map.atomic_checkpoint();
+
+    // Queue the removal of one map, and an update of an item in the other one.
+    map.remove_map(&0).unwrap();
+    map.insert(1, 0, "updated".to_string()).unwrap();
+    assert_eq!(map.get_value_pending(&0, &0), Some(None));
+    assert_eq!(map.get_value_pending(&1, &0), Some(Some("updated".to_string())));
+
+    // Rewind to the checkpoint.
+    map.atomic_rewind();
+
+    // Ensure the operations before the checkpoint are still pending, and the later ones are gone.
+    assert_eq!(map.iter_pending().count(), NUM_ITEMS);
+    assert_eq!(map.get_value_pending(&0, &0), Some(Some("0".to_string())));
+    assert_eq!(map.get_value_pending(&1, &0), Some(Some("0".to_string())));
+    // The map should still contain no items.
+    assert!(map.iter_confirmed().next().is_none());
+
+    // Perform another checkpoint, queue a removal, and clear the checkpoint instead of rewinding.
+    map.atomic_checkpoint();
+    map.remove_key(&1, &1).unwrap();
+    map.clear_latest_checkpoint();
+    assert_eq!(map.get_value_pending(&1, &1), Some(None));
+
+    // Finish the current atomic write batch.
+    map.finish_atomic().unwrap();
+
+    // The maps should contain the items from before the first checkpoint, except the removed one.
+    assert_eq!(map.get_map_confirmed(&0).unwrap().len(), NUM_ITEMS / 2);
+    assert_eq!(map.get_map_confirmed(&1).unwrap().len(), NUM_ITEMS / 2 - 1);
+    assert!(!map.contains_key_confirmed(&1, &1).unwrap());
+    assert!(map.iter_pending().next().is_none());
+
+    // Start another atomic write batch, and rewind it without any checkpoint.
+    map.start_atomic();
+    map.remove_map(&0).unwrap();
+    map.atomic_rewind();
+    assert!(map.iter_pending().next().is_none());
+    map.finish_atomic().unwrap();
+
+    // Ensure the rewound removal was never committed.
+    assert_eq!(map.get_map_confirmed(&0).unwrap().len(), NUM_ITEMS / 2);
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod check_atomic_checkpoints;
+pub use check_atomic_checkpoints::*;
+
 mod check_atomic_writes_are_batched;
 pub use check_atomic_writes_are_batched::*;
 
@@ -17,6 +17,11 @@
 
 pub mod helpers;
 
+#[cfg(feature = "address-index")]
+mod address;
+#[cfg(feature = "address-index")]
+pub use address::*;
+
 mod block;
 pub use block::*;
 
@@ -163,15 +163,49 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Stores the given `(height, committee)` pair into empty storage, as its first committee.
+    /// Afterwards, the storage resumes from the given `height`, which is not required to be the genesis height.
+    fn initialize(&self, height: u32, committee: Committee<N>) -> Result<()> {
+        // Ensure the storage is empty.
+        ensure!(self.current_round().is_err(), "Cannot initialize a committee storage that is not empty");
+        // Retrieve the round.
+        let round = committee.starting_round();
+        // Ensure the round is at least the height.
+        ensure!(round >= height as u64, "Round must be at least the height");
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Store the round.
+            self.current_round_map().insert(ROUND_KEY, round)?;
+            // Store the round's height.
+            self.round_to_height_map().insert(round, height)?;
+            // Store the committee.
+            self.committee_map().insert(height, committee)?;
+            Ok(())
+        })
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     fn remove(&self, height: u32) -> Result<()> {
+        // Note: The storage is read speculatively, so that the committees of multiple heights
+        // can be removed (in descending order) within a single atomic batch.
+
+        // Prepare a closure to retrieve the height for the given round.
+        let get_height_for_round = |round: u64| -> Result<Option<u32>> {
+            Ok(self.round_to_height_map().get_speculative(&round)?.map(|height| cow_to_copied!(height)))
+        };
         // Retrieve the current round.
-        let current_round = self.current_round()?;
+        let current_round = match self.current_round_map().get_speculative(&ROUND_KEY)? {
+            Some(round) => cow_to_copied!(round),
+            None => bail!("Current round not found in committee storage"),
+        };
         // Retrieve the current height.
-        let current_height = self.current_height()?;
+        let Some(current_height) = get_height_for_round(current_round)? else {
+            bail!("Current height not found in committee storage");
+        };
         // Retrieve the committee for the given height.
-        let Some(committee) = self.get_committee(height)? else {
+        let Some(committee) = self.committee_map().get_speculative(&height)? else {
             bail!("Committee not found for height {height} in committee storage");
         };
         // Retrieve the round for the given height.
@@ -183,7 +217,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         // Find the earliest round to be removed (inclusive).
         let mut earliest_round = committee_round;
-        while earliest_round > 0 && self.get_height_for_round(earliest_round)? == Some(height) {
+        while earliest_round > 0 && get_height_for_round(earliest_round)? == Some(height) {
             earliest_round = earliest_round.saturating_sub(1);
         }
         let is_multiple = earliest_round != committee_round;
@@ -193,7 +227,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         // Find the latest round to be removed (exclusive).
         let mut latest_round = committee_round;
-        while self.get_height_for_round(latest_round)? == Some(height) {
+        while get_height_for_round(latest_round)? == Some(height) {
             latest_round = latest_round.saturating_add(1);
         }
 
@@ -202,7 +236,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         if is_latest_committee {
             while next_current_round > 0 {
                 // If the next current height is less than the current height, then we have found the next current round.
-                if let Some(next_current_height) = self.get_height_for_round(next_current_round)? {
+                if let Some(next_current_height) = get_height_for_round(next_current_round)? {
                     if next_current_height < current_height {
                         break;
                     }
@@ -369,6 +403,12 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
         self.storage.insert(next_height, committee)
     }
 
+    /// Stores the given `(height, committee)` pair into empty storage, as its first committee.
+    /// Afterwards, the storage resumes from the given `height`, which is not required to be the genesis height.
+    pub fn initialize(&self, height: u32, committee: Committee<N>) -> Result<()> {
+        self.storage.initialize(height, committee)
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     pub fn remove(&self, height: u32) -> Result<()> {
@@ -536,6 +576,80 @@ mod tests {
         assert_eq!(store.get_committee_for_round(5).unwrap(), None);
     }
 
+    #[test]
+    fn test_remove_multiple_in_atomic_batch() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_0 = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let committee_1 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(9, rng);
+
+        // Initialize a new committee store, and insert the committees.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+        store.insert(0, committee_0.clone()).unwrap();
+        store.insert(1, committee_1).unwrap();
+        store.insert(2, committee_2).unwrap();
+        assert_eq!(store.current_round().unwrap(), 9);
+        assert_eq!(store.current_height().unwrap(), 2);
+
+        // Remove the latest two committees, in descending order, within a single atomic batch.
+        store.start_atomic();
+        store.remove(2).unwrap();
+        store.remove(1).unwrap();
+        store.finish_atomic().unwrap();
+
+        // Ensure the store matches its state after the first committee.
+        assert_eq!(store.current_round().unwrap(), 4);
+        assert_eq!(store.current_height().unwrap(), 0);
+        assert_eq!(store.current_committee().unwrap(), committee_0);
+        for round in 0..5 {
+            assert_eq!(store.get_height_for_round(round).unwrap(), Some(0));
+        }
+        for round in 5..10 {
+            assert_eq!(store.get_height_for_round(round).unwrap(), None);
+        }
+        assert_eq!(store.get_committee(1).unwrap(), None);
+        assert_eq!(store.get_committee(2).unwrap(), None);
+    }
+
+    #[test]
+    fn test_initialize() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_0 = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let committee_5 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_9 = ledger_committee::test_helpers::sample_committee_for_round(9, rng);
+
+        // Initialize a new committee store, at a non-genesis height.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+        assert!(store.initialize(6, committee_5.clone()).is_err());
+        store.initialize(3, committee_5.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 5);
+        assert_eq!(store.current_height().unwrap(), 3);
+        assert_eq!(store.current_committee().unwrap(), committee_5);
+
+        // Ensure the store cannot be initialized twice.
+        assert!(store.initialize(3, committee_5.clone()).is_err());
+        assert!(store.initialize(0, committee_0).is_err());
+
+        // Ensure the store resumes from the initialized height.
+        assert!(store.insert(5, committee_9.clone()).is_err());
+        store.insert(4, committee_9.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 9);
+        assert_eq!(store.current_height().unwrap(), 4);
+        assert_eq!(store.current_committee().unwrap(), committee_9);
+        for round in 5..9 {
+            assert_eq!(store.get_height_for_round(round).unwrap(), Some(3));
+        }
+
+        // Ensure the store can revert to the initialized height.
+        store.remove(4).unwrap();
+        assert_eq!(store.current_height().unwrap(), 3);
+        assert_eq!(store.current_committee().unwrap(), committee_5);
+    }
+
     #[test]
     fn test_remove_hole() {
         let rng = &mut TestRng::default();
@@ -21,19 +21,40 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Value},
+    program::{
+        FinalizeIDTree,
+        FinalizeOperationsTree,
+        Identifier,
+        MappingOperationPath,
+        Plaintext,
+        ProgramID,
+        Value,
+        FINALIZE_ID_DEPTH,
+    },
     types::Field,
 };
+use ledger_block::Transactions;
 use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
-use core::marker::PhantomData;
-use indexmap::IndexSet;
+use core::{marker::PhantomData, ops::RangeInclusive};
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// The key of the recorded history range in the history range map.
+const HISTORY_RANGE_KEY: u8 = 0;
+
+/// The key of the first block height with stored ratified finalize operations in the ratified operations start map.
+pub(crate) const RATIFIED_OPERATIONS_START_KEY: u8 = 0;
+
+/// The default number of latest blocks whose finalize history is retained.
+pub const DEFAULT_HISTORY_DEPTH: u32 = 1024;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
-fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
+pub(crate) fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
     // Construct the preimage.
     let mut preimage = Vec::new();
     program_id.write_bits_le(&mut preimage);
@@ -44,7 +65,7 @@ fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifie
 }
 
 /// Returns the key ID for the given `program ID`, `mapping name`, and `key`.
-fn to_key_id<N: Network>(
+pub(crate) fn to_key_id<N: Network>(
     program_id: &ProgramID<N>,
     mapping_name: &Identifier<N>,
     key: &Plaintext<N>,
@@ -60,6 +81,23 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// A finalize operation, along with its decoded mapping, key, and value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedFinalizeOperation<N: Network> {
+    /// The finalize operation.
+    pub operation: FinalizeOperation<N>,
+    /// The program ID of the mapping.
+    pub program_id: ProgramID<N>,
+    /// The name of the mapping.
+    pub mapping_name: Identifier<N>,
+    /// The key, if the operation inserts, updates, or removes a key-value.
+    pub key: Option<Plaintext<N>>,
+    /// The value, if the operation inserts or updates a key-value.
+    /// Note: The value is recorded during finalize. For blocks finalized before the values were recorded,
+    /// only the last value of each key in the block is decoded.
+    pub value: Option<Value<N>>,
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -77,6 +115,20 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `key ID` to `[(block height, previous value)]`.
+    type HistoryMap: for<'a> NestedMap<'a, Field<N>, u32, Option<Value<N>>>;
+    /// The mapping of `block height` to `[(key ID, (program ID, mapping name, key))]`.
+    type KeyUndoMap: for<'a> NestedMap<'a, u32, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>;
+    /// The mapping of `block height` to `[(program ID, previous mapping names)]`.
+    type ProgramUndoMap: for<'a> NestedMap<'a, u32, ProgramID<N>, Option<IndexSet<Identifier<N>>>>;
+    /// The mapping of `HISTORY_RANGE_KEY` to `(first block height, last block height)` with a recorded history.
+    type HistoryRangeMap: for<'a> Map<'a, u8, (u32, u32)>;
+    /// The mapping of `block height` to `[ratified finalize operation]`.
+    type RatifiedOperationsMap: for<'a> Map<'a, u32, Vec<FinalizeOperation<N>>>;
+    /// The mapping of `RATIFIED_OPERATIONS_START_KEY` to the first block height with ratified finalize operations.
+    type RatifiedOperationsStartMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `block height` to `[(value ID, value)]`.
+    type OperationValueMap: for<'a> NestedMap<'a, u32, Field<N>, Value<N>>;
 
     /// Initializes the program state storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -91,6 +143,20 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap;
+    /// Returns the key undo map.
+    fn key_undo_map(&self) -> &Self::KeyUndoMap;
+    /// Returns the program undo map.
+    fn program_undo_map(&self) -> &Self::ProgramUndoMap;
+    /// Returns the history range map.
+    fn history_range_map(&self) -> &Self::HistoryRangeMap;
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap;
+    /// Returns the ratified operations start map.
+    fn ratified_operations_start_map(&self) -> &Self::RatifiedOperationsStartMap;
+    /// Returns the operation value map.
+    fn operation_value_map(&self) -> &Self::OperationValueMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -100,6 +166,13 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.history_map().start_atomic();
+        self.key_undo_map().start_atomic();
+        self.program_undo_map().start_atomic();
+        self.history_range_map().start_atomic();
+        self.ratified_operations_map().start_atomic();
+        self.ratified_operations_start_map().start_atomic();
+        self.operation_value_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -107,6 +180,13 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.history_map().is_atomic_in_progress()
+            || self.key_undo_map().is_atomic_in_progress()
+            || self.program_undo_map().is_atomic_in_progress()
+            || self.history_range_map().is_atomic_in_progress()
+            || self.ratified_operations_map().is_atomic_in_progress()
+            || self.ratified_operations_start_map().is_atomic_in_progress()
+            || self.operation_value_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +194,13 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.history_map().atomic_checkpoint();
+        self.key_undo_map().atomic_checkpoint();
+        self.program_undo_map().atomic_checkpoint();
+        self.history_range_map().atomic_checkpoint();
+        self.ratified_operations_map().atomic_checkpoint();
+        self.ratified_operations_start_map().atomic_checkpoint();
+        self.operation_value_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -121,6 +208,13 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.history_map().clear_latest_checkpoint();
+        self.key_undo_map().clear_latest_checkpoint();
+        self.program_undo_map().clear_latest_checkpoint();
+        self.history_range_map().clear_latest_checkpoint();
+        self.ratified_operations_map().clear_latest_checkpoint();
+        self.ratified_operations_start_map().clear_latest_checkpoint();
+        self.operation_value_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +222,13 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.history_map().atomic_rewind();
+        self.key_undo_map().atomic_rewind();
+        self.program_undo_map().atomic_rewind();
+        self.history_range_map().atomic_rewind();
+        self.ratified_operations_map().atomic_rewind();
+        self.ratified_operations_start_map().atomic_rewind();
+        self.operation_value_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -135,13 +236,27 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.history_map().abort_atomic();
+        self.key_undo_map().abort_atomic();
+        self.program_undo_map().abort_atomic();
+        self.history_range_map().abort_atomic();
+        self.ratified_operations_map().abort_atomic();
+        self.ratified_operations_start_map().abort_atomic();
+        self.operation_value_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.history_map().finish_atomic()?;
+        self.key_undo_map().finish_atomic()?;
+        self.program_undo_map().finish_atomic()?;
+        self.history_range_map().finish_atomic()?;
+        self.ratified_operations_map().finish_atomic()?;
+        self.ratified_operations_start_map().finish_atomic()?;
+        self.operation_value_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -355,6 +470,274 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Records the current value of the given `key` as its value prior to the block at the given `height`.
+    /// Only the first update to a key within a block is recorded, as it holds the value prior to the block.
+    fn record_key_history(
+        &self,
+        height: u32,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<()> {
+        // Compute the key ID.
+        let key_id = to_key_id(&program_id, &mapping_name, key)?;
+        // If the key was already updated in this block, then its previous value is already recorded.
+        if self.key_undo_map().contains_key_speculative(&height, &key_id)? {
+            return Ok(());
+        }
+        // Retrieve the previous value.
+        let previous_value = self.get_value_speculative(program_id, mapping_name, key)?;
+
+        atomic_batch_scope!(self, {
+            // Store the previous value of the key.
+            self.history_map().insert(key_id, height, previous_value)?;
+            // Store the key, to be able to undo the update.
+            self.key_undo_map().insert(height, key_id, (program_id, mapping_name, key.clone()))?;
+
+            Ok(())
+        })
+    }
+
+    /// Records the current mapping names of the given `program ID` as its mapping names prior to the block at the given `height`.
+    /// Only the first update to a program within a block is recorded, as it holds the mapping names prior to the block.
+    fn record_program_history(&self, height: u32, program_id: &ProgramID<N>) -> Result<()> {
+        // If the program was already updated in this block, then its previous mapping names are already recorded.
+        if self.program_undo_map().contains_key_speculative(&height, program_id)? {
+            return Ok(());
+        }
+        // Retrieve the previous mapping names.
+        let previous_mapping_names = self.get_mapping_names_speculative(program_id)?;
+
+        atomic_batch_scope!(self, {
+            // Store the previous mapping names of the program.
+            self.program_undo_map().insert(height, *program_id, previous_mapping_names)?;
+
+            Ok(())
+        })
+    }
+
+    /// Records the current values of the keys in the given mapping as their values prior to the block at the given `height`.
+    fn record_mapping_history(&self, height: u32, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<()> {
+        // If the mapping does not exist, then there are no values to record.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            return Ok(());
+        }
+        atomic_batch_scope!(self, {
+            // Record the previous value of each key.
+            for (key, _) in self.get_mapping_speculative(program_id, mapping_name)? {
+                self.record_key_history(height, program_id, mapping_name, &key)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Records the value written by the given finalize `operation` in the block at the given `height`,
+    /// so that the operation can be decoded even if the key is updated again later in the block.
+    fn record_operation_value(&self, height: u32, operation: &FinalizeOperation<N>, value: Value<N>) -> Result<()> {
+        match operation {
+            FinalizeOperation::InsertKeyValue(_, _, value_id) | FinalizeOperation::UpdateKeyValue(_, _, value_id) => {
+                self.operation_value_map().insert(height, *value_id, value)
+            }
+            _ => bail!("Cannot record a value for the finalize operation '{operation:?}'"),
+        }
+    }
+
+    /// Stores the ratified finalize operations of the block at the given `height`.
+    /// If no ratified finalize operations are stored yet, the given `height` is recorded as the first one with them.
+    fn insert_ratified_operations(&self, height: u32, operations: &[FinalizeOperation<N>]) -> Result<()> {
+        atomic_batch_scope!(self, {
+            if self.ratified_operations_start_map().get_speculative(&RATIFIED_OPERATIONS_START_KEY)?.is_none() {
+                self.ratified_operations_start_map().insert(RATIFIED_OPERATIONS_START_KEY, height)?;
+            }
+            self.ratified_operations_map().insert(height, operations.to_vec())
+        })
+    }
+
+    /// Returns the speculative range of block heights with a recorded history, if any.
+    fn get_history_range_speculative(&self) -> Result<Option<(u32, u32)>> {
+        Ok(self.history_range_map().get_speculative(&HISTORY_RANGE_KEY)?.map(|range| cow_to_copied!(range)))
+    }
+
+    /// Returns the confirmed range of block heights with a recorded history, if any.
+    fn get_history_range_confirmed(&self) -> Result<Option<(u32, u32)>> {
+        Ok(self.history_range_map().get_confirmed(&HISTORY_RANGE_KEY)?.map(|range| cow_to_copied!(range)))
+    }
+
+    /// Marks the history of the block at the given `height` as recorded.
+    /// If the history of the previous block is not recorded, the recorded range restarts at the given `height`.
+    fn insert_history_height(&self, height: u32) -> Result<()> {
+        let range = match self.get_history_range_speculative()? {
+            Some((first, last)) if last.checked_add(1) == Some(height) => (first, height),
+            _ => (height, height),
+        };
+        self.history_range_map().insert(HISTORY_RANGE_KEY, range)
+    }
+
+    /// Discards the recorded history of the blocks up to (and including) the given `height`.
+    /// Afterwards, the finalize state can no longer be reverted, or queried, prior to the block at `height + 1`.
+    fn prune_history(&self, height: u32) -> Result<()> {
+        // Retrieve the range of block heights with a recorded history.
+        let Some((first, last)) = self.get_history_range_speculative()? else {
+            return Ok(());
+        };
+        // Ensure the history of the latest block is retained.
+        ensure!(height < last, "Cannot prune the finalize history of the latest recorded block {last}");
+        // If the history of the blocks is already discarded, there is nothing to prune.
+        if height < first {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            for pruned_height in first..=height {
+                // Remove the previous values of the keys updated in the block.
+                for (key_id, _) in self.key_undo_map().get_map_speculative(&pruned_height)? {
+                    self.history_map().remove_key(&key_id, &pruned_height)?;
+                }
+                // Remove the undo entries of the block.
+                self.key_undo_map().remove_map(&pruned_height)?;
+                self.program_undo_map().remove_map(&pruned_height)?;
+                // Remove the values written in the block.
+                self.operation_value_map().remove_map(&pruned_height)?;
+            }
+            // Update the range of block heights with a recorded history.
+            self.history_range_map().insert(HISTORY_RANGE_KEY, (height + 1, last))?;
+
+            Ok(())
+        })
+    }
+
+    /// Reverts the finalize state to its state prior to the block at the given `height`,
+    /// using the previous values recorded for the block.
+    /// Note: The given `height` must be the latest finalized block height.
+    fn rollback(&self, height: u32) -> Result<()> {
+        // Ensure the history of the block is recorded, as the latest recorded block.
+        let first = match self.get_history_range_speculative()? {
+            Some((first, last)) if (first..=last).contains(&height) => {
+                ensure!(height == last, "Cannot revert block {height}, as the latest recorded block is {last}");
+                first
+            }
+            _ => bail!("Cannot revert block {height}, as its finalize history is not recorded"),
+        };
+        // Retrieve the keys updated in the block.
+        let key_entries = self.key_undo_map().get_map_speculative(&height)?;
+        // Retrieve the programs updated in the block.
+        let program_entries = self.program_undo_map().get_map_speculative(&height)?;
+        // Determine whether the block stored a committee.
+        let has_committee = self.committee_store().get_committee(height)?.is_some();
+
+        atomic_batch_scope!(self, {
+            // Restore the previous mapping names of each program.
+            for (program_id, previous_mapping_names) in program_entries {
+                match previous_mapping_names {
+                    Some(mapping_names) => self.program_id_map().insert(program_id, mapping_names)?,
+                    None => self.program_id_map().remove(&program_id)?,
+                }
+            }
+            // Restore the previous value of each key.
+            for (key_id, (program_id, mapping_name, key)) in key_entries {
+                // Retrieve the previous value.
+                let previous_value = match self.history_map().get_value_speculative(&key_id, &height)? {
+                    Some(previous_value) => cow_to_cloned!(previous_value),
+                    None => bail!(
+                        "Missing the previous value of '{program_id}/{mapping_name}' key '{key}' at block {height}"
+                    ),
+                };
+                match previous_value {
+                    Some(value) => self.key_value_map().insert((program_id, mapping_name), key, value)?,
+                    None => self.key_value_map().remove_key(&(program_id, mapping_name), &key)?,
+                }
+                // Remove the previous value from the history.
+                self.history_map().remove_key(&key_id, &height)?;
+            }
+            // Remove the undo entries of the block.
+            self.key_undo_map().remove_map(&height)?;
+            self.program_undo_map().remove_map(&height)?;
+            // Remove the values written in the block.
+            self.operation_value_map().remove_map(&height)?;
+            // Remove the block from the range of block heights with a recorded history.
+            match height == first {
+                true => self.history_range_map().remove(&HISTORY_RANGE_KEY)?,
+                false => self.history_range_map().insert(HISTORY_RANGE_KEY, (first, height - 1))?,
+            }
+            // Remove the ratified finalize operations of the block.
+            self.ratified_operations_map().remove(&height)?;
+            if self.get_ratified_operations_start_speculative()? == Some(height) {
+                self.ratified_operations_start_map().remove(&RATIFIED_OPERATIONS_START_KEY)?;
+            }
+            // Remove the committee of the block.
+            if has_committee {
+                self.committee_store().remove(height)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Writes the finalize state as of the block at the given `height` into the given empty storage.
+    ///
+    /// The current state, along with the recorded history of the blocks after the given `height`, is copied
+    /// into the given storage, in which those blocks are then reverted. Of the committees, only the committee
+    /// of the given `height` is copied, and the history of the blocks up to the given `height` is not copied.
+    fn export_state<F: FinalizeStorage<N>>(&self, height: u32, target: &F) -> Result<()> {
+        // Retrieve the latest block height.
+        let latest_height = self.committee_store().current_height()?;
+        // Ensure the given height is not greater than the latest block height.
+        ensure!(height <= latest_height, "Cannot export block {height}, as the latest block is {latest_height}");
+        // Ensure the history of the blocks after the given height is recorded.
+        if height < latest_height {
+            match self.get_history_range_confirmed()? {
+                Some((first, last)) if first <= height + 1 && last == latest_height => (),
+                _ => bail!("Cannot export block {height}, as the finalize history after it is not recorded"),
+            }
+        }
+        // Retrieve the committee of the given height.
+        let Some(committee) = self.committee_store().get_committee(height)? else {
+            bail!("Cannot export block {height}, as its committee is missing");
+        };
+        // Ensure the given storage is empty.
+        ensure!(target.program_id_map().is_empty_confirmed(), "Cannot export into a non-empty finalize storage");
+
+        atomic_batch_scope!(target, {
+            // Copy the current mappings.
+            for (program_id, mapping_names) in self.program_id_map().iter_confirmed() {
+                let program_id = cow_to_copied!(program_id);
+                for mapping_name in mapping_names.iter() {
+                    for (key, value) in self.key_value_map().get_map_confirmed(&(program_id, *mapping_name))? {
+                        target.key_value_map().insert((program_id, *mapping_name), key, value)?;
+                    }
+                }
+                target.program_id_map().insert(program_id, cow_to_cloned!(mapping_names))?;
+            }
+            // Copy the recorded history of the blocks after the given height.
+            for block_height in height + 1..=latest_height {
+                for (key_id, entry) in self.key_undo_map().get_map_confirmed(&block_height)? {
+                    let Some(previous_value) = self.history_map().get_value_confirmed(&key_id, &block_height)? else {
+                        bail!("Missing the previous value of key '{key_id}' at block {block_height}");
+                    };
+                    target.history_map().insert(key_id, block_height, cow_to_cloned!(previous_value))?;
+                    target.key_undo_map().insert(block_height, key_id, entry)?;
+                }
+                for (program_id, previous_mapping_names) in self.program_undo_map().get_map_confirmed(&block_height)? {
+                    target.program_undo_map().insert(block_height, program_id, previous_mapping_names)?;
+                }
+            }
+            if height < latest_height {
+                target.history_range_map().insert(HISTORY_RANGE_KEY, (height + 1, latest_height))?;
+            }
+            // Copy the committee of the given height.
+            target.committee_store().initialize(height, committee)?;
+
+            Ok(())
+        })?;
+
+        // Revert the blocks after the given height, in descending order.
+        for block_height in (height + 1..=latest_height).rev() {
+            target.rollback(block_height)?;
+        }
+        Ok(())
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -454,34 +837,260 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the block at the given `height`.
+    fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the history of the blocks after the given height is recorded.
+        match self.get_history_range_confirmed()? {
+            Some((first, _)) => ensure!(
+                height.saturating_add(1) >= first,
+                "Cannot retrieve the value as of block {height}, as the finalize history is recorded from block {first}"
+            ),
+            None => bail!("Cannot retrieve the value as of block {height}, as the finalize history is not recorded"),
+        }
+        // Compute the key ID.
+        let key_id = to_key_id(&program_id, &mapping_name, key)?;
+        // Retrieve the earliest update to the key after the given height.
+        let next_update = self
+            .history_map()
+            .get_map_confirmed(&key_id)?
+            .into_iter()
+            .filter(|(update_height, _)| *update_height > height)
+            .min_by_key(|(update_height, _)| *update_height);
+        match next_update {
+            // If the key was updated after the given height, its value prior to that update is the value at the height.
+            Some((_, previous_value)) => Ok(previous_value),
+            // Otherwise, the key has kept its value since the given height.
+            None => self.get_value_confirmed(program_id, mapping_name, key),
+        }
+    }
+
+    /// Decodes the given finalize operations of the block at the given `height` into their mappings, keys, and values.
+    /// Note: The block must be finalized, and it must have been finalized while its history was being recorded.
+    fn decode_finalize_operations(
+        &self,
+        height: u32,
+        operations: &[FinalizeOperation<N>],
+    ) -> Result<Vec<DecodedFinalizeOperation<N>>> {
+        // Retrieve the keys updated in the block.
+        let keys: IndexMap<_, _> = self.key_undo_map().get_map_confirmed(&height)?.into_iter().collect();
+
+        // Retrieve the mappings of the updated keys.
+        let mut mappings = IndexMap::new();
+        for (program_id, mapping_name, _) in keys.values() {
+            mappings.insert(to_mapping_id(program_id, mapping_name)?, (*program_id, *mapping_name));
+        }
+        // Retrieve the mappings of the programs updated in the block, including the mappings prior to the block.
+        for (program_id, previous_mapping_names) in self.program_undo_map().get_map_confirmed(&height)? {
+            let mapping_names = self.get_mapping_names_confirmed(&program_id)?.unwrap_or_default();
+            for mapping_name in mapping_names.into_iter().chain(previous_mapping_names.unwrap_or_default()) {
+                mappings.insert(to_mapping_id(&program_id, &mapping_name)?, (program_id, mapping_name));
+            }
+        }
+        // If a mapping is not found, retrieve the mappings of all programs.
+        let contains_all_mappings = operations.iter().all(|operation| {
+            let mapping_id = match operation {
+                FinalizeOperation::InitializeMapping(mapping_id)
+                | FinalizeOperation::InsertKeyValue(mapping_id, ..)
+                | FinalizeOperation::UpdateKeyValue(mapping_id, ..)
+                | FinalizeOperation::RemoveKeyValue(mapping_id, ..)
+                | FinalizeOperation::ReplaceMapping(mapping_id)
+                | FinalizeOperation::RemoveMapping(mapping_id) => mapping_id,
+            };
+            mappings.contains_key(mapping_id)
+        });
+        if !contains_all_mappings {
+            for (program_id, mapping_names) in self.program_id_map().iter_confirmed() {
+                for mapping_name in mapping_names.iter() {
+                    mappings.insert(to_mapping_id(&program_id, mapping_name)?, (*program_id, *mapping_name));
+                }
+            }
+        }
+
+        // Decode the finalize operations.
+        operations
+            .iter()
+            .map(|operation| {
+                // Retrieve the mapping ID, key ID, and value ID of the operation.
+                let (mapping_id, key_id, value_id) = match operation {
+                    FinalizeOperation::InitializeMapping(mapping_id)
+                    | FinalizeOperation::ReplaceMapping(mapping_id)
+                    | FinalizeOperation::RemoveMapping(mapping_id) => (mapping_id, None, None),
+                    FinalizeOperation::InsertKeyValue(mapping_id, key_id, value_id)
+                    | FinalizeOperation::UpdateKeyValue(mapping_id, key_id, value_id) => {
+                        (mapping_id, Some(key_id), Some(value_id))
+                    }
+                    FinalizeOperation::RemoveKeyValue(mapping_id, key_id) => (mapping_id, Some(key_id), None),
+                };
+                // Decode the mapping.
+                let Some((program_id, mapping_name)) = mappings.get(mapping_id).copied() else {
+                    bail!("Unknown mapping ID '{mapping_id}' in the finalize operations of block {height}")
+                };
+                // Decode the key.
+                let key = match key_id {
+                    Some(key_id) => match keys.get(key_id) {
+                        Some((_, _, key)) => Some(key.clone()),
+                        None => bail!("Unknown key ID '{key_id}' in the finalize operations of block {height}"),
+                    },
+                    None => None,
+                };
+                // Decode the value, which is the value written by the operation, as recorded during finalize.
+                // If it is not recorded, it is the value of the key as of the block, if its value ID matches.
+                let value = match (key_id, &key, value_id) {
+                    (Some(key_id), Some(key), Some(value_id)) => {
+                        match self.operation_value_map().get_value_confirmed(&height, value_id)? {
+                            Some(value) => Some(cow_to_cloned!(value)),
+                            None => match self.get_value_at_height(program_id, mapping_name, key, height)? {
+                                Some(value) => {
+                                    // Compute the value ID.
+                                    let candidate_id = N::hash_bhp1024(
+                                        &(*key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le(),
+                                    )?;
+                                    (candidate_id == *value_id).then_some(value)
+                                }
+                                None => None,
+                            },
+                        }
+                    }
+                    _ => None,
+                };
+                Ok(DecodedFinalizeOperation { operation: *operation, program_id, mapping_name, key, value })
+            })
+            .collect()
+    }
+
+    /// Returns the speculative first block height with stored ratified finalize operations, if any.
+    fn get_ratified_operations_start_speculative(&self) -> Result<Option<u32>> {
+        match self.ratified_operations_start_map().get_speculative(&RATIFIED_OPERATIONS_START_KEY)? {
+            Some(height) => Ok(Some(cow_to_copied!(height))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the first block height with stored ratified finalize operations, if any.
+    /// Note: The blocks finalized before the ratified finalize operations were stored do not have them.
+    fn get_ratified_operations_start(&self) -> Result<Option<u32>> {
+        match self.ratified_operations_start_map().get_confirmed(&RATIFIED_OPERATIONS_START_KEY)? {
+            Some(height) => Ok(Some(cow_to_copied!(height))),
+            None => Ok(None),
+        }
+    }
+
+    /// Ensures the ratified finalize operations of the block at the given `height` are stored.
+    /// Note: The blocks finalized before the ratified finalize operations were stored do not have them.
+    fn ensure_ratified_operations_available(&self, height: u32) -> Result<()> {
+        match self.get_ratified_operations_start()? {
+            Some(start) => ensure!(
+                height >= start,
+                "The ratified finalize operations are unavailable before block {start} (requested block {height})"
+            ),
+            None => bail!("The ratified finalize operations are unavailable, as none are stored yet"),
+        }
+        Ok(())
+    }
+
+    /// Returns the ratified finalize operations of the block at the given `height`.
+    fn get_ratified_operations(&self, height: u32) -> Result<Option<Vec<FinalizeOperation<N>>>> {
+        match self.ratified_operations_map().get_confirmed(&height)? {
+            Some(operations) => Ok(Some(cow_to_cloned!(operations))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the mapping operation path for the given `program ID`, `mapping name`, and `key`, at block `height`.
+    /// The path proves the inclusion of the operation that wrote the value of the key as of the block, in the block.
+    /// Note: The given `transactions` must be the transactions of the block.
+    fn get_mapping_operation_path(
+        &self,
+        height: u32,
+        transactions: &Transactions<N>,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingOperationPath<N>> {
+        // Ensure the ratified finalize operations of the block are stored.
+        self.ensure_ratified_operations_available(height)?;
+        // Retrieve the ratified finalize operations of the block.
+        let Some(ratified_operations) = self.get_ratified_operations(height)? else {
+            bail!("Missing the ratified finalize operations of block {height}")
+        };
+        // Retrieve the value of the key as of the block.
+        let Some(value) = self.get_value_at_height(program_id, mapping_name, key, height)? else {
+            bail!("Key '{key}' does not exist in '{program_id}/{mapping_name}' as of block {height}")
+        };
+
+        // Compute the mapping ID, key ID, and value ID.
+        let mapping_id = to_mapping_id(&program_id, &mapping_name)?;
+        let key_id = to_key_id(&program_id, &mapping_name, key)?;
+        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+
+        // Prepare the finalize operations of each finalize ID, composed of:
+        // | transaction_0 finalize operations, ..., transaction_n finalize operations | ratified finalize operations |
+        let operations = transactions
+            .iter()
+            .map(|transaction| transaction.finalize_operations().as_slice())
+            .chain(std::iter::once(ratified_operations.as_slice()))
+            .collect::<Vec<_>>();
+
+        // Find the finalize operation that wrote the value of the key.
+        let Some((finalize_index, operation_index, variant)) =
+            operations.iter().enumerate().find_map(|(finalize_index, operations)| {
+                operations.iter().enumerate().find_map(|(operation_index, operation)| match operation {
+                    FinalizeOperation::InsertKeyValue(m, k, v) if (*m, *k, *v) == (mapping_id, key_id, value_id) => {
+                        Some((finalize_index, operation_index, 1u8))
+                    }
+                    FinalizeOperation::UpdateKeyValue(m, k, v) if (*m, *k, *v) == (mapping_id, key_id, value_id) => {
+                        Some((finalize_index, operation_index, 2u8))
+                    }
+                    _ => None,
+                })
+            })
+        else {
+            bail!("Key '{key}' in '{program_id}/{mapping_name}' was not written in block {height}")
+        };
+
+        // Compute the Merkle path for the finalize operation.
+        let leaves = operations[finalize_index].iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
+        let finalize_id_tree: FinalizeIDTree<N> = N::merkle_tree_bhp(&leaves)?;
+        let finalize_id = *finalize_id_tree.root();
+        let finalize_id_path = finalize_id_tree.prove(operation_index, &leaves[operation_index])?;
+
+        // Compute the Merkle path for the finalize ID.
+        let leaves = operations
+            .iter()
+            .map(|operations| -> Result<Vec<bool>> {
+                let leaves = operations.iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
+                Ok(N::merkle_tree_bhp::<FINALIZE_ID_DEPTH>(&leaves)?.root().to_bits_le())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let finalize_operations_tree: FinalizeOperationsTree<N> = N::merkle_tree_bhp(&leaves)?;
+        let finalize_operations_path = finalize_operations_tree.prove(finalize_index, &leaves[finalize_index])?;
+
+        // Return the mapping operation path.
+        Ok(MappingOperationPath::from(
+            program_id,
+            mapping_name,
+            key.clone(),
+            value,
+            variant,
+            finalize_id_path,
+            finalize_id,
+            finalize_operations_path,
+        ))
+    }
+
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
         let preimage: std::collections::BTreeMap<_, _> = self
             .key_value_map()
             .iter_confirmed()
-            .map(|(m, k, v)| {
-                let m = cow_to_copied!(m);
-                let k = cow_to_cloned!(k);
-                let v = cow_to_cloned!(v);
-
-                let mut preimage = Vec::new();
-                m.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-                k.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-
-                // Compute the mapping checksum as `Hash( m || k )`.
-                let mapping_checksum = N::hash_bhp1024(&preimage)?;
-
-                v.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-
-                // Compute the entry checksum as `Hash( m || k || v )`.
-                let entry_checksum = N::hash_bhp1024(&preimage)?;
-                // Return the mapping checksum and entry checksum.
-                Ok::<_, Error>((mapping_checksum, entry_checksum.to_bits_le()))
-            })
+            .map(|(m, k, v)| to_entry_checksums(cow_to_copied!(m), &k, &v))
             .try_collect()?;
         // Compute the checksum as `Hash( all mapping checksums )`.
         N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
@@ -528,6 +1137,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The block height at which the previous values of updated keys are recorded, if any.
+    history_height: Arc<RwLock<Option<u32>>>,
+    /// The number of latest blocks whose finalize history is retained.
+    history_depth: Arc<RwLock<u32>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -547,7 +1160,12 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self {
+            storage,
+            history_height: Default::default(),
+            history_depth: Arc::new(RwLock::new(DEFAULT_HISTORY_DEPTH)),
+            _phantom: PhantomData,
+        })
     }
 
     /// Starts an atomic batch write operation.
@@ -596,6 +1214,74 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn committee_store(&self) -> &CommitteeStore<N, P::CommitteeStorage> {
         self.storage.committee_store()
     }
+
+    /// Returns the block height at which the previous values of updated keys are recorded, if any.
+    pub fn history_height(&self) -> Option<u32> {
+        *self.history_height.read()
+    }
+
+    /// Sets the block height at which the previous values of updated keys are recorded,
+    /// or stops recording them if `None`.
+    ///
+    /// While a height is set, every update to the finalize state records the value it replaces,
+    /// which enables `FinalizeStore::get_value_at_height` and `FinalizeStore::rollback` for the block.
+    pub fn set_history_height(&self, height: Option<u32>) {
+        *self.history_height.write() = height;
+    }
+
+    /// Returns the number of latest blocks whose finalize history is retained.
+    pub fn history_depth(&self) -> u32 {
+        *self.history_depth.read()
+    }
+
+    /// Sets the number of latest blocks whose finalize history is retained (`DEFAULT_HISTORY_DEPTH` by default).
+    ///
+    /// The VM discards the history of the blocks beyond the history depth (or the pruning depth, if lower)
+    /// as each block is finalized, which bounds the depth of `VM::rollback_to` and of the historical queries.
+    pub fn set_history_depth(&self, depth: u32) -> Result<()> {
+        // Ensure the history of the latest block is always retained.
+        ensure!(depth > 0, "The finalize history depth must be at least 1");
+        *self.history_depth.write() = depth;
+        Ok(())
+    }
+
+    /// Returns the range of block heights whose history is recorded, if any.
+    ///
+    /// The finalize state can be reverted to its state prior to any block in the range,
+    /// and queried as of any block from the block preceding the range.
+    pub fn history_range(&self) -> Result<Option<RangeInclusive<u32>>> {
+        Ok(self.storage.get_history_range_confirmed()?.map(|(first, last)| first..=last))
+    }
+
+    /// Marks the history of the block at the given `height` as recorded.
+    /// Note: This must be called once the block is finalized, while its history was being recorded.
+    pub fn insert_history_height(&self, height: u32) -> Result<()> {
+        self.storage.insert_history_height(height)
+    }
+
+    /// Discards the recorded history of the blocks up to (and including) the given `height`,
+    /// which bounds the storage used by the history to the blocks that may still be reverted.
+    pub fn prune_history(&self, height: u32) -> Result<()> {
+        self.storage.prune_history(height)
+    }
+
+    /// Reverts the finalize state to its state prior to the block at the given `height`.
+    /// Note: The given `height` must be the latest finalized block height,
+    /// and the block must have been finalized while its history was being recorded.
+    pub fn rollback(&self, height: u32) -> Result<()> {
+        self.storage.rollback(height)
+    }
+
+    /// Stores the ratified finalize operations of the block at the given `height`.
+    pub fn insert_ratified_operations(&self, height: u32, operations: &[FinalizeOperation<N>]) -> Result<()> {
+        self.storage.insert_ratified_operations(height, operations)
+    }
+
+    /// Writes the finalize state as of the block at the given `height` into the given empty finalize store.
+    /// Note: The history of the blocks after the given `height` must be recorded.
+    pub fn export_state<F: FinalizeStorage<N>>(&self, height: u32, target: &FinalizeStore<N, F>) -> Result<()> {
+        self.storage.export_state(height, &target.storage)
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<N, P> {
@@ -634,7 +1320,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.insert_key_value(program_id, mapping_name, key, value)
+        match self.history_height() {
+            // Record the previous value of the key, alongside the operation.
+            Some(height) => atomic_batch_scope!(self, {
+                self.storage.record_key_history(height, program_id, mapping_name, &key)?;
+                let operation = self.storage.insert_key_value(program_id, mapping_name, key, value.clone())?;
+                // Record the value written by the operation.
+                self.storage.record_operation_value(height, &operation, value)?;
+                Ok(operation)
+            }),
+            None => self.storage.insert_key_value(program_id, mapping_name, key, value),
+        }
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -648,7 +1344,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.update_key_value(program_id, mapping_name, key, value)
+        match self.history_height() {
+            // Record the previous value of the key, alongside the operation.
+            Some(height) => atomic_batch_scope!(self, {
+                self.storage.record_key_history(height, program_id, mapping_name, &key)?;
+                let operation = self.storage.update_key_value(program_id, mapping_name, key, value.clone())?;
+                // Record the value written by the operation.
+                self.storage.record_operation_value(height, &operation, value)?;
+                Ok(operation)
+            }),
+            None => self.storage.update_key_value(program_id, mapping_name, key, value),
+        }
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
@@ -658,7 +1364,14 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>> {
-        self.storage.remove_key_value(program_id, mapping_name, key)
+        match self.history_height() {
+            // Record the previous value of the key, alongside the operation.
+            Some(height) => atomic_batch_scope!(self, {
+                self.storage.record_key_history(height, program_id, mapping_name, key)?;
+                self.storage.remove_key_value(program_id, mapping_name, key)
+            }),
+            None => self.storage.remove_key_value(program_id, mapping_name, key),
+        }
     }
 }
 
@@ -670,7 +1383,14 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.initialize_mapping(program_id, mapping_name)
+        match self.history_height() {
+            // Record the previous mapping names of the program, alongside the operation.
+            Some(height) => atomic_batch_scope!(self, {
+                self.storage.record_program_history(height, &program_id)?;
+                self.storage.initialize_mapping(program_id, mapping_name)
+            }),
+            None => self.storage.initialize_mapping(program_id, mapping_name),
+        }
     }
 
     /// Replaces the mapping for the given `program ID` and `mapping name` from storage,
@@ -681,7 +1401,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: Identifier<N>,
         entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.replace_mapping(program_id, mapping_name, entries)
+        match self.history_height() {
+            // Record the previous values of the existing keys and of the new keys, alongside the operation.
+            Some(height) => atomic_batch_scope!(self, {
+                self.storage.record_mapping_history(height, program_id, mapping_name)?;
+                for (key, _) in entries.iter() {
+                    self.storage.record_key_history(height, program_id, mapping_name, key)?;
+                }
+                self.storage.replace_mapping(program_id, mapping_name, entries)
+            }),
+            None => self.storage.replace_mapping(program_id, mapping_name, entries),
+        }
     }
 
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
@@ -691,13 +1421,31 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.remove_mapping(program_id, mapping_name)
+        match self.history_height() {
+            // Record the previous mapping names and the previous values of the keys, alongside the operation.
+            Some(height) => atomic_batch_scope!(self, {
+                self.storage.record_program_history(height, &program_id)?;
+                self.storage.record_mapping_history(height, program_id, mapping_name)?;
+                self.storage.remove_mapping(program_id, mapping_name)
+            }),
+            None => self.storage.remove_mapping(program_id, mapping_name),
+        }
     }
 
     /// Removes the program for the given `program ID` from storage,
     /// along with all associated mappings and key-value pairs in storage.
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
-        self.storage.remove_program(program_id)
+        match self.history_height() {
+            // Record the previous mapping names and the previous values of the keys, alongside the operation.
+            Some(height) => atomic_batch_scope!(self, {
+                self.storage.record_program_history(height, program_id)?;
+                for mapping_name in self.storage.get_mapping_names_speculative(program_id)?.unwrap_or_default() {
+                    self.storage.record_mapping_history(height, *program_id, mapping_name)?;
+                }
+                self.storage.remove_program(program_id)
+            }),
+            None => self.storage.remove_program(program_id),
+        }
     }
 }
 
@@ -762,12 +1510,89 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the block at the given `height`.
+    /// Note: Only the updates made while the history was being recorded can be looked past.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height(program_id, mapping_name, key, height)
+    }
+
+    /// Decodes the given finalize operations of the block at the given `height` into their mappings, keys, and values.
+    /// Note: The block must be finalized, and it must have been finalized while its history was being recorded.
+    pub fn decode_finalize_operations(
+        &self,
+        height: u32,
+        operations: &[FinalizeOperation<N>],
+    ) -> Result<Vec<DecodedFinalizeOperation<N>>> {
+        self.storage.decode_finalize_operations(height, operations)
+    }
+
+    /// Returns the first block height with stored ratified finalize operations, if any.
+    pub fn get_ratified_operations_start(&self) -> Result<Option<u32>> {
+        self.storage.get_ratified_operations_start()
+    }
+
+    /// Ensures the ratified finalize operations of the block at the given `height` are stored.
+    pub fn ensure_ratified_operations_available(&self, height: u32) -> Result<()> {
+        self.storage.ensure_ratified_operations_available(height)
+    }
+
+    /// Returns the ratified finalize operations of the block at the given `height`.
+    pub fn get_ratified_operations(&self, height: u32) -> Result<Option<Vec<FinalizeOperation<N>>>> {
+        self.storage.get_ratified_operations(height)
+    }
+
+    /// Returns the mapping operation path for the given `program ID`, `mapping name`, and `key`, at block `height`.
+    /// The path proves the inclusion of the operation that wrote the value of the key as of the block, in the block.
+    /// Note: The given `transactions` must be the transactions of the block,
+    /// and the block must have been finalized while its history was being recorded.
+    pub fn get_mapping_operation_path(
+        &self,
+        height: u32,
+        transactions: &Transactions<N>,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingOperationPath<N>> {
+        self.storage.get_mapping_operation_path(height, transactions, program_id, mapping_name, key)
+    }
+
     /// Returns the confirmed checksum of the finalize store.
     pub fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         self.storage.get_checksum_confirmed()
     }
 }
 
+/// Returns the mapping checksum and the (bits of the) entry checksum of the given confirmed entry,
+/// from which the confirmed checksum of the finalize storage is computed.
+pub(crate) fn to_entry_checksums<N: Network>(
+    mapping: (ProgramID<N>, Identifier<N>),
+    key: &Plaintext<N>,
+    value: &Value<N>,
+) -> Result<(Field<N>, Vec<bool>)> {
+    let mut preimage = Vec::new();
+    mapping.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator.
+    key.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator.
+
+    // Compute the mapping checksum as `Hash( m || k )`.
+    let mapping_checksum = N::hash_bhp1024(&preimage)?;
+
+    value.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator.
+
+    // Compute the entry checksum as `Hash( m || k || v )`.
+    let entry_checksum = N::hash_bhp1024(&preimage)?;
+    // Return the mapping checksum and entry checksum.
+    Ok((mapping_checksum, entry_checksum.to_bits_le()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1208,6 +2033,239 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_history_and_rollback() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Prepare the keys and values.
+        let key_1 = Plaintext::from_str("1field").unwrap();
+        let key_2 = Plaintext::from_str("2field").unwrap();
+        let key_3 = Plaintext::from_str("3field").unwrap();
+        let value = |amount: u64| Value::from_str(&format!("{amount}u64")).unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Block 0: Initialize the mapping, and insert a key.
+        finalize_store.set_history_height(Some(0));
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, key_1.clone(), value(1)).unwrap();
+        finalize_store.insert_history_height(0).unwrap();
+
+        // Block 1: Update the key twice, and insert a new key.
+        finalize_store.set_history_height(Some(1));
+        finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value(2)).unwrap();
+        finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value(3)).unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, key_2.clone(), value(10)).unwrap();
+        finalize_store.insert_history_height(1).unwrap();
+
+        // Block 2: Remove the first key, and replace the mapping.
+        finalize_store.set_history_height(Some(2));
+        finalize_store.remove_key_value(program_id, mapping_name, &key_1).unwrap();
+        finalize_store
+            .replace_mapping(program_id, mapping_name, vec![(key_2.clone(), value(20)), (key_3.clone(), value(30))])
+            .unwrap();
+        finalize_store.insert_history_height(2).unwrap();
+        finalize_store.set_history_height(None);
+        assert_eq!(finalize_store.history_range().unwrap(), Some(0..=2));
+
+        // Ensure the values at each height are correct.
+        let value_at = |key: &Plaintext<CurrentNetwork>, height: u32| {
+            finalize_store.get_value_at_height(program_id, mapping_name, key, height).unwrap()
+        };
+        assert_eq!(value_at(&key_1, 0), Some(value(1)));
+        assert_eq!(value_at(&key_1, 1), Some(value(3)));
+        assert_eq!(value_at(&key_1, 2), None);
+        assert_eq!(value_at(&key_2, 0), None);
+        assert_eq!(value_at(&key_2, 1), Some(value(10)));
+        assert_eq!(value_at(&key_2, 2), Some(value(20)));
+        assert_eq!(value_at(&key_3, 1), None);
+        assert_eq!(value_at(&key_3, 2), Some(value(30)));
+
+        // Rollback block 2, and ensure the state matches the state after block 1.
+        finalize_store.rollback(2).unwrap();
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_1).unwrap(), Some(value(3)));
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_2).unwrap(), Some(value(10)));
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_3).unwrap(), None);
+        assert_eq!(value_at(&key_1, 0), Some(value(1)));
+        assert_eq!(value_at(&key_3, 2), None);
+
+        // Rollback block 1, and ensure the state matches the state after block 0.
+        finalize_store.rollback(1).unwrap();
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_1).unwrap(), Some(value(1)));
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_2).unwrap(), None);
+
+        // Rollback block 0, and ensure the mapping no longer exists.
+        finalize_store.rollback(0).unwrap();
+        assert!(!finalize_store.contains_program_confirmed(&program_id).unwrap());
+        assert!(!finalize_store.contains_key_confirmed(program_id, mapping_name, &key_1).unwrap());
+        assert_eq!(finalize_store.history_range().unwrap(), None);
+
+        // Ensure a block without a recorded history cannot be reverted.
+        assert!(finalize_store.rollback(0).is_err());
+    }
+
+    #[test]
+    fn test_export_state() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key_1 = Plaintext::from_str("1field").unwrap();
+        let key_2 = Plaintext::from_str("2field").unwrap();
+        let value = |amount: u64| Value::from_str(&format!("{amount}u64")).unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Finalizes a block at the given height, with the given updates.
+        let mut finalize_block = |height: u32, update: &dyn Fn()| {
+            finalize_store.set_history_height(Some(height));
+            update();
+            let committee = ledger_committee::test_helpers::sample_committee_for_round(height as u64 * 2, rng);
+            finalize_store.committee_store().insert(height, committee).unwrap();
+            finalize_store.insert_history_height(height).unwrap();
+            finalize_store.set_history_height(None);
+        };
+
+        // Block 0: Initialize the mapping, and insert a key.
+        finalize_block(0, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_1.clone(), value(1)).unwrap();
+        });
+        // Block 1: Update the key, and insert a new key.
+        finalize_block(1, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value(2)).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_2.clone(), value(10)).unwrap();
+        });
+        // Block 2: Remove the mapping.
+        finalize_block(2, &|| {
+            finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        });
+
+        // Ensure the state as of each block is exported.
+        for height in 0..=2 {
+            let target = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+            finalize_store.export_state(height, &target).unwrap();
+            // Ensure the exported values match the values at the given height.
+            for key in [&key_1, &key_2] {
+                assert_eq!(
+                    target.get_value_confirmed(program_id, mapping_name, key).unwrap(),
+                    finalize_store.get_value_at_height(program_id, mapping_name, key, height).unwrap()
+                );
+            }
+            assert_eq!(target.contains_mapping_confirmed(&program_id, &mapping_name).unwrap(), height < 2);
+            // Ensure the exported committee matches the committee at the given height.
+            assert_eq!(target.committee_store().current_height().unwrap(), height);
+            assert_eq!(
+                target.committee_store().current_committee().unwrap(),
+                finalize_store.committee_store().get_committee(height).unwrap().unwrap()
+            );
+            // Ensure the exported history is consumed by reverting the later blocks.
+            assert_eq!(target.history_range().unwrap(), None);
+            // Ensure the state cannot be exported into a non-empty store.
+            if height < 2 {
+                assert!(finalize_store.export_state(height, &target).is_err());
+            }
+        }
+
+        // Ensure the state cannot be exported after the latest block.
+        let target = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+        assert!(finalize_store.export_state(3, &target).is_err());
+
+        // Ensure the state cannot be exported once the history after it is pruned.
+        finalize_store.prune_history(1).unwrap();
+        assert!(finalize_store.export_state(0, &target).is_err());
+        finalize_store.export_state(1, &target).unwrap();
+        assert_eq!(target.get_value_confirmed(program_id, mapping_name, &key_1).unwrap(), Some(value(2)));
+    }
+
+    #[test]
+    fn test_prune_history() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("1field").unwrap();
+        let value = |amount: u64| Value::from_str(&format!("{amount}u64")).unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Block 0: Initialize the mapping.
+        finalize_store.set_history_height(Some(0));
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.insert_history_height(0).unwrap();
+        // Blocks 1 to 4: Update the key.
+        for height in 1..5 {
+            finalize_store.set_history_height(Some(height));
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(height.into())).unwrap();
+            finalize_store.insert_history_height(height).unwrap();
+        }
+        finalize_store.set_history_height(None);
+
+        // Ensure the latest block is retained.
+        assert!(finalize_store.prune_history(4).is_err());
+        // Prune the history of the blocks up to block 2.
+        finalize_store.prune_history(2).unwrap();
+        assert_eq!(finalize_store.history_range().unwrap(), Some(3..=4));
+        // Ensure pruning an already pruned block has no effect.
+        finalize_store.prune_history(1).unwrap();
+        assert_eq!(finalize_store.history_range().unwrap(), Some(3..=4));
+
+        // Ensure the pruned history entries are removed.
+        let key_id = to_key_id(&program_id, &mapping_name, &key).unwrap();
+        let heights = finalize_store.storage.history_map().get_map_confirmed(&key_id).unwrap();
+        assert_eq!(heights.into_iter().map(|(height, _)| height).collect::<Vec<_>>(), vec![3, 4]);
+
+        // Ensure the values are retrievable from the block preceding the recorded range, and not before it.
+        let value_at = |height| finalize_store.get_value_at_height(program_id, mapping_name, &key, height);
+        assert_eq!(value_at(4).unwrap(), Some(value(4)));
+        assert_eq!(value_at(2).unwrap(), Some(value(2)));
+        assert!(value_at(1).is_err());
+
+        // Ensure the recorded blocks can be reverted, and the pruned blocks cannot.
+        assert!(finalize_store.rollback(3).is_err());
+        finalize_store.rollback(4).unwrap();
+        finalize_store.rollback(3).unwrap();
+        assert!(finalize_store.rollback(2).is_err());
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(), Some(value(2)));
+        assert_eq!(finalize_store.history_range().unwrap(), None);
+    }
+
+    #[test]
+    fn test_ratified_operations_start() {
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        assert_eq!(finalize_store.get_ratified_operations_start().unwrap(), None);
+        assert!(finalize_store.ensure_ratified_operations_available(0).is_err());
+
+        // Blocks 3 and 4: Store the ratified finalize operations, as if the earlier blocks predate them.
+        for height in 3..5 {
+            finalize_store.insert_ratified_operations(height, &[]).unwrap();
+            finalize_store.insert_history_height(height).unwrap();
+        }
+
+        // Ensure the ratified finalize operations are only available from block 3.
+        assert_eq!(finalize_store.get_ratified_operations_start().unwrap(), Some(3));
+        finalize_store.ensure_ratified_operations_available(3).unwrap();
+        finalize_store.ensure_ratified_operations_available(4).unwrap();
+        let error = finalize_store.ensure_ratified_operations_available(2).unwrap_err();
+        assert!(error.to_string().contains("unavailable before block 3"));
+
+        // Ensure the first block is retained until it is reverted.
+        finalize_store.rollback(4).unwrap();
+        assert_eq!(finalize_store.get_ratified_operations_start().unwrap(), Some(3));
+        finalize_store.rollback(3).unwrap();
+        assert_eq!(finalize_store.get_ratified_operations_start().unwrap(), None);
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
@@ -194,6 +194,37 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the execution proof and the transition values for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs and fee boolean.
+        let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
+        };
+        // Retrieve the global state root.
+        let global_state_root = match self.inclusion_map().get_confirmed(transaction_id)? {
+            Some(inclusion) => cow_to_cloned!(inclusion).0,
+            None => bail!("Failed to get the global state root for the transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the transitions.
+            for transition_id in transition_ids {
+                self.transition_store().prune(&transition_id)?;
+            }
+
+            // Prune the proof.
+            self.inclusion_map().insert(*transaction_id, (global_state_root, None))?;
+
+            // Prune the fee.
+            if has_fee {
+                self.fee_store().prune(transaction_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -210,6 +241,23 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the transition IDs for the given `transaction ID`, including the fee transition ID.
+    fn get_transition_ids(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<N::TransitionID>>> {
+        // Retrieve the transition IDs and fee boolean.
+        let (mut transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => return Ok(None),
+        };
+        // Retrieve the fee transition ID.
+        if has_fee {
+            match self.fee_store().get_transition_id(transaction_id)? {
+                Some(fee_transition_id) => transition_ids.push(fee_transition_id),
+                None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
+            }
+        }
+        Ok(Some(transition_ids))
+    }
+
     /// Returns the execution for the given `transaction ID`.
     fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         // Retrieve the transition IDs.
@@ -319,6 +367,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the execution proof and the transition values for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -370,6 +423,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     pub fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         self.storage.get_execution(transaction_id)
     }
+
+    /// Returns the transition IDs for the given `transaction ID`, including the fee transition ID.
+    pub fn get_transition_ids(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<N::TransitionID>>> {
+        self.storage.get_transition_ids(transaction_id)
+    }
 }
 
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
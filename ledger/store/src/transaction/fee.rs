@@ -137,6 +137,24 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the fee proof and the fee transition values for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the fee transition ID and global state root.
+        let (transition_id, global_state_root, _) = match self.fee_map().get_confirmed(transaction_id)? {
+            Some(fee) => cow_to_cloned!(fee),
+            None => bail!("Failed to locate the fee transition ID for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the fee proof.
+            self.fee_map().insert(*transaction_id, (transition_id, global_state_root, None))?;
+            // Prune the fee transition.
+            self.transition_store().prune(&transition_id)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -148,6 +166,14 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the fee transition ID for the given `transaction ID`.
+    fn get_transition_id(&self, transaction_id: &N::TransactionID) -> Result<Option<N::TransitionID>> {
+        match self.fee_map().get_confirmed(transaction_id)? {
+            Some(fee) => Ok(Some(fee.0)),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the fee for the given `transaction ID`.
     fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         // Retrieve the fee transition ID.
@@ -196,6 +222,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the fee proof and the fee transition values for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, F::TransitionStorage> {
         self.storage.transition_store()
@@ -247,6 +278,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the fee transition ID for the given `transaction ID`.
+    pub fn get_transition_id(&self, transaction_id: &N::TransactionID) -> Result<Option<N::TransitionID>> {
+        self.storage.get_transition_id(transaction_id)
+    }
 }
 
 impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
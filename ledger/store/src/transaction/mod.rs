@@ -194,6 +194,28 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the proofs and transition values for the given `transaction ID`.
+    /// Note: Deployments are retained, as they are needed to execute their programs.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            match transaction_type {
+                // Prune the fee of the deployment transaction.
+                TransactionType::Deploy => self.fee_store().prune(transaction_id)?,
+                // Prune the execution transaction.
+                TransactionType::Execute => self.execution_store().prune(transaction_id)?,
+                // Prune the fee transaction.
+                TransactionType::Fee => self.fee_store().prune(transaction_id)?,
+            }
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -262,6 +284,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the proofs and transition values for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the deployment store.
     pub fn deployment_store(&self) -> &DeploymentStore<N, T::DeploymentStorage> {
         self.storage.deployment_store()
@@ -355,6 +382,24 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         }
     }
 
+    /// Returns the transition IDs for the given `transaction ID`, including the fee transition ID.
+    pub fn get_transition_ids(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<N::TransitionID>>> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.transaction_ids.get_confirmed(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => return Ok(None),
+        };
+        // Retrieve the transition IDs.
+        match transaction_type {
+            // Return the fee transition ID of the deployment or fee transaction.
+            TransactionType::Deploy | TransactionType::Fee => {
+                Ok(self.storage.fee_store().get_transition_id(transaction_id)?.map(|transition_id| vec![transition_id]))
+            }
+            // Return the execution and fee transition IDs.
+            TransactionType::Execute => self.storage.execution_store().get_transition_ids(transaction_id),
+        }
+    }
+
     /// Returns the edition for the given `transaction ID`.
     pub fn get_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
         // Retrieve the transaction type.
@@ -217,6 +217,33 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the input values for the given `transition ID`, retaining the input IDs and records.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs.
+        let input_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the input values.
+            for input_id in input_ids {
+                if self.constant_map().contains_key_confirmed(&input_id)? {
+                    self.constant_map().insert(input_id, None)?;
+                }
+                if self.public_map().contains_key_confirmed(&input_id)? {
+                    self.public_map().insert(input_id, None)?;
+                }
+                if self.private_map().contains_key_confirmed(&input_id)? {
+                    self.private_map().insert(input_id, None)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(input_id)? {
@@ -344,6 +371,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
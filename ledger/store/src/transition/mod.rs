@@ -225,6 +225,18 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the input and output values for the given `transition ID`.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Prune the inputs.
+            self.input_store().prune(transition_id)?;
+            // Prune the outputs.
+            self.output_store().prune(transition_id)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -333,6 +345,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input and output values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -432,6 +449,12 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.outputs.get_outputs(transition_id)
     }
 
+    /// Returns the record outputs for the given `transition ID`, as a tuple of `(commitment, record)`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_records(&self, transition_id: &N::TransitionID) -> Result<Vec<(Field<N>, Record<N, Ciphertext<N>>)>> {
+        self.outputs.get_records(transition_id)
+    }
+
     /// Returns the record for the given `commitment`.
     ///
     /// If the record exists, `Ok(Some(record))` is returned.
@@ -234,6 +234,37 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the output values for the given `transition ID`, retaining the output IDs and records.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the output values.
+            // Note: The records are retained, as they are needed to find and spend unspent records.
+            for output_id in output_ids {
+                if self.constant_map().contains_key_confirmed(&output_id)? {
+                    self.constant_map().insert(output_id, None)?;
+                }
+                if self.public_map().contains_key_confirmed(&output_id)? {
+                    self.public_map().insert(output_id, None)?;
+                }
+                if self.private_map().contains_key_confirmed(&output_id)? {
+                    self.private_map().insert(output_id, None)?;
+                }
+                if self.future_map().contains_key_confirmed(&output_id)? {
+                    self.future_map().insert(output_id, None)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(output_id)? {
@@ -302,6 +333,22 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             None => Ok(vec![]),
         }
     }
+
+    /// Returns the record outputs for the given `transition ID`, as a tuple of `(commitment, record)`.
+    /// Note: The records are retained when the transition is pruned, so they can be read for any transition.
+    #[allow(clippy::type_complexity)]
+    fn get_records(&self, transition_id: &N::TransitionID) -> Result<Vec<(Field<N>, Record<N, Ciphertext<N>>)>> {
+        let mut records = Vec::new();
+        // Retrieve the record for each output ID that is a commitment.
+        for output_id in self.get_ids(transition_id)? {
+            match self.record_map().get_confirmed(&output_id)? {
+                Some(Cow::Borrowed((_, Some(record)))) => records.push((output_id, record.clone())),
+                Some(Cow::Owned((_, Some(record)))) => records.push((output_id, record)),
+                Some(Cow::Borrowed((_, None))) | Some(Cow::Owned((_, None))) | None => (),
+            }
+        }
+        Ok(records)
+    }
 }
 
 /// The transition output store.
@@ -367,6 +414,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the output values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -419,6 +471,12 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.get(transition_id)
     }
 
+    /// Returns the record outputs for the given `transition ID`, as a tuple of `(commitment, record)`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_records(&self, transition_id: &N::TransitionID) -> Result<Vec<(Field<N>, Record<N, Ciphertext<N>>)>> {
+        self.storage.get_records(transition_id)
+    }
+
     /// Returns the record for the given `commitment`.
     ///
     /// If the record exists, `Ok(Some(record))` is returned.
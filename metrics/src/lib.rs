@@ -15,16 +15,32 @@
 #![forbid(unsafe_code)]
 
 const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+const HISTOGRAM_NAMES: [&str; 1] = [store::ATOMIC_BATCH_SIZE];
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod store {
+    /// The label key identifying the map (by its name) of a storage metric.
+    pub const MAP_LABEL: &str = "map";
+
+    pub const MAP_ENTRIES: &str = "snarkvm_ledger_store_map_entries";
+    pub const MAP_BYTES: &str = "snarkvm_ledger_store_map_bytes";
+    pub const INSERT_LATENCY: &str = "snarkvm_ledger_store_insert_latency_secs";
+    pub const GET_CONFIRMED_LATENCY: &str = "snarkvm_ledger_store_get_confirmed_latency_secs";
+    pub const CHECKPOINT_DEPTH: &str = "snarkvm_ledger_store_checkpoint_depth";
+    pub const ATOMIC_BATCH_SIZE: &str = "snarkvm_ledger_store_atomic_batch_size";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
+    for name in HISTOGRAM_NAMES {
+        register_histogram(name);
+    }
 }
 
 /******** Counter ********/
@@ -86,6 +102,11 @@ pub fn decrement_gauge<V: Into<f64>>(name: &'static str, value: V) {
     gauge.decrement(value.into());
 }
 
+/// Updates a gauge with the given name and label to the given value.
+pub fn gauge_label<V: Into<f64>>(name: &'static str, label_key: &'static str, label_value: String, value: V) {
+    ::metrics::gauge!(name, label_key => label_value).set(value.into());
+}
+
 /******** Histogram ********/
 
 /// Registers a histogram with the given name.
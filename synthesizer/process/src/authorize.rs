@@ -105,6 +105,94 @@ impl<N: Network> Process<N> {
         // Return the authorization.
         Ok(authorization)
     }
+
+    /// Returns the signing payload for a call to the program function for the given inputs.
+    #[inline]
+    pub fn signing_payload(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<SigningPayload<N>> {
+        // Construct the signing payload.
+        self.get_stack(program_id)?.signing_payload(function_name, inputs)
+    }
+
+    /// Returns the signing payload for the private fee, given the credits record, the fee amount (in microcredits),
+    /// and the deployment or execution ID.
+    #[inline]
+    pub fn fee_private_signing_payload(
+        &self,
+        credits: Record<N, Plaintext<N>>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+    ) -> Result<SigningPayload<N>> {
+        // Ensure the fee has the correct program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Ensure the fee has the correct function.
+        let function_name = Identifier::from_str("fee_private")?;
+
+        // Ensure the record contains a sufficient balance to pay the fee.
+        ensure_record_microcredits_is_sufficient(
+            &credits,
+            base_fee_in_microcredits.saturating_add(priority_fee_in_microcredits),
+        )?;
+
+        // Construct the inputs.
+        let inputs = [
+            Value::Record(credits),
+            Value::from(Literal::U64(U64::<N>::new(base_fee_in_microcredits))),
+            Value::from(Literal::U64(U64::<N>::new(priority_fee_in_microcredits))),
+            Value::from(Literal::Field(deployment_or_execution_id)),
+        ]
+        .into_iter();
+
+        // Construct the signing payload.
+        self.get_stack(program_id)?.signing_payload(function_name, inputs)
+    }
+
+    /// Returns the signing payload for the public fee, given the fee amount (in microcredits)
+    /// and the deployment or execution ID.
+    #[inline]
+    pub fn fee_public_signing_payload(
+        &self,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+    ) -> Result<SigningPayload<N>> {
+        // Ensure the fee has the correct program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Ensure the fee has the correct function.
+        let function_name = Identifier::from_str("fee_public")?;
+
+        // Construct the inputs.
+        let inputs = [
+            Value::from(Literal::U64(U64::<N>::new(base_fee_in_microcredits))),
+            Value::from(Literal::U64(U64::<N>::new(priority_fee_in_microcredits))),
+            Value::from(Literal::Field(deployment_or_execution_id)),
+        ]
+        .into_iter();
+
+        // Construct the signing payload.
+        self.get_stack(program_id)?.signing_payload(function_name, inputs)
+    }
+
+    /// Authorizes a call to the program function for the given signed request.
+    /// See `Request::from_signing_payload` to assemble a request from an externally-signed payload.
+    ///
+    /// # Errors
+    /// This method fails if the function makes an external call, as each nested request must be signed
+    /// by the private key. Only functions without calls can be authorized from an external signature.
+    #[inline]
+    pub fn authorize_request<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        request: Request<N>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Authorize the request.
+        self.get_stack(*request.program_id())?.authorize_request::<A, R>(request, rng)
+    }
 }
 
 /// Ensures the record contains a sufficient balance to pay the fee.
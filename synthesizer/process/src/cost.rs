@@ -16,11 +16,13 @@ use crate::{Process, Stack, StackProgramTypes};
 
 use console::{
     prelude::*,
-    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+    program::{FinalizeType, Identifier, LiteralType, Locator, PlaintextType},
 };
-use ledger_block::{Deployment, Execution};
+use ledger_block::{Deployment, Execution, Input, Transition};
 use synthesizer_program::{CastType, Command, Finalize, Instruction, Operand, StackProgram};
 
+use indexmap::IndexMap;
+
 /// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, synthesis cost, namespace cost)).
 pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64, u64))> {
     // Determine the number of bytes in the deployment.
@@ -60,11 +62,9 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     // Compute the storage cost in microcredits.
     let storage_cost = execution.size_in_bytes()?;
 
-    // Get the root transition.
-    let transition = execution.peek()?;
-
-    // Get the finalize cost for the root transition.
-    let finalize_cost = process.get_stack(transition.program_id())?.get_finalize_cost(transition.function_name())?;
+    // Get the finalize cost for the root transition of each call tree.
+    // Note: An execution contains a single call tree, unless it is a bundle.
+    let finalize_cost = finalize_cost_of_call_trees(process, &execution.transitions().collect::<Vec<_>>())?;
 
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
@@ -74,6 +74,132 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     Ok((total_cost, (storage_cost, finalize_cost)))
 }
 
+/// The number of bytes in a compressed commitment, i.e. a compressed affine point on the BLS12-377 G1 curve.
+const COMMITMENT_SIZE_IN_BYTES: u64 = 48;
+/// The number of bytes in a scalar field element of BLS12-377, i.e. an evaluation or a prover sum.
+const EVALUATION_SIZE_IN_BYTES: u64 = 32;
+/// The number of bytes in a serialized length or batch size, both of which are encoded as a `u64`.
+const LENGTH_SIZE_IN_BYTES: u64 = 8;
+/// The number of bytes in the tag of a serialized `Option`.
+const OPTION_TAG_SIZE_IN_BYTES: u64 = 1;
+/// The number of points at which the polynomial commitment is opened (`alpha`, `beta`, and `gamma`).
+/// Note: Only the opening at `beta` is hiding, so exactly one opening includes the evaluation of the random polynomial.
+const NUM_OPENINGS: u64 = 3;
+
+/// The number of bytes in a proof that are independent of the batch sizes (469 bytes). This covers:
+///  - the proof version (1 byte),
+///  - the length of the batch sizes,
+///  - the optional mask polynomial commitment,
+///  - the `h_0`, `g_1`, `h_1`, and `h_2` commitments,
+///  - the `g_1` evaluation,
+///  - the length of the batch evaluation proof,
+///  - the openings of the batch evaluation proof, each with a commitment and an optional random evaluation.
+const PROOF_BASE_SIZE_IN_BYTES: u64 = 1
+    + LENGTH_SIZE_IN_BYTES
+    + (OPTION_TAG_SIZE_IN_BYTES + COMMITMENT_SIZE_IN_BYTES)
+    + 4 * COMMITMENT_SIZE_IN_BYTES
+    + EVALUATION_SIZE_IN_BYTES
+    + LENGTH_SIZE_IN_BYTES
+    + NUM_OPENINGS * (COMMITMENT_SIZE_IN_BYTES + OPTION_TAG_SIZE_IN_BYTES)
+    + EVALUATION_SIZE_IN_BYTES;
+/// The number of bytes in a proof for each circuit in the batch (344 bytes). This covers:
+///  - the batch size of the circuit,
+///  - the `g_a`, `g_b`, and `g_c` commitments and their evaluations,
+///  - the fourth prover message, i.e. the `sum_a`, `sum_b`, and `sum_c` of the circuit.
+const PROOF_PER_CIRCUIT_SIZE_IN_BYTES: u64 =
+    LENGTH_SIZE_IN_BYTES + 3 * COMMITMENT_SIZE_IN_BYTES + 3 * EVALUATION_SIZE_IN_BYTES + 3 * EVALUATION_SIZE_IN_BYTES;
+/// The number of bytes in a proof for each instance in the batch (144 bytes). This covers:
+///  - the witness commitment of the instance,
+///  - the third prover message, i.e. the `sum_a`, `sum_b`, and `sum_c` of the instance.
+const PROOF_PER_INSTANCE_SIZE_IN_BYTES: u64 = COMMITMENT_SIZE_IN_BYTES + 3 * EVALUATION_SIZE_IN_BYTES;
+
+/// Returns the size in bytes of an execution containing the given transitions, once it has been proven.
+///
+/// Note: The size of a proof is determined entirely by the number of circuits and instances in its batch.
+/// Each function in the execution is one circuit with one instance per transition, and each record input
+/// contributes one instance of the inclusion circuit.
+pub fn execution_size_in_bytes<N: Network>(transitions: &[Transition<N>]) -> Result<u64> {
+    // Compute the size of the execution without a proof.
+    let unproven_size = Execution::from(transitions.iter().cloned(), N::StateRoot::default(), None)?.size_in_bytes()?;
+
+    // Count the number of instances for each function circuit.
+    let mut batch_sizes = IndexMap::<Locator<N>, u64>::new();
+    for transition in transitions {
+        *batch_sizes.entry(Locator::new(*transition.program_id(), *transition.function_name())).or_default() += 1;
+    }
+    // Count the number of instances for the inclusion circuit.
+    let num_inclusions =
+        transitions.iter().flat_map(Transition::inputs).filter(|input| matches!(input, Input::Record(..))).count()
+            as u64;
+
+    // Compute the number of circuits and instances in the batch.
+    let num_circuits = batch_sizes.len() as u64 + u64::from(num_inclusions > 0);
+    let num_instances = batch_sizes.values().sum::<u64>() + num_inclusions;
+
+    // Compute the size of the proof.
+    let proof_size = num_circuits
+        .checked_mul(PROOF_PER_CIRCUIT_SIZE_IN_BYTES)
+        .and_then(|x| x.checked_add(num_instances.checked_mul(PROOF_PER_INSTANCE_SIZE_IN_BYTES)?))
+        .and_then(|x| x.checked_add(PROOF_BASE_SIZE_IN_BYTES))
+        .ok_or(anyhow!("The proof size computation overflowed for an execution"))?;
+
+    // Compute the size of the execution.
+    unproven_size.checked_add(proof_size).ok_or(anyhow!("The size computation overflowed for an execution"))
+}
+
+/// Returns the *estimated* cost in microcredits to publish an execution of the given transitions,
+/// without requiring a proof (total cost, (storage cost, finalize cost), [(transition ID, (storage cost, finalize cost))]).
+///
+/// The per-transition storage cost is the size of the transition, and the per-transition finalize cost
+/// is the cost of the commands in its own finalize scope. The total storage cost additionally includes
+/// the global state root and the proof, and the total finalize cost matches `execution_cost`.
+pub fn estimate_execution_cost<N: Network>(
+    process: &Process<N>,
+    transitions: &[Transition<N>],
+) -> Result<(u64, (u64, u64), Vec<(N::TransitionID, (u64, u64))>)> {
+    // Ensure there are transitions.
+    ensure!(!transitions.is_empty(), "Cannot estimate the cost of an execution without transitions");
+
+    // Compute the storage cost in microcredits.
+    let storage_cost = execution_size_in_bytes(transitions)?;
+
+    // Get the finalize cost for the root transition of each call tree.
+    let finalize_cost = finalize_cost_of_call_trees(process, &transitions.iter().collect::<Vec<_>>())?;
+
+    // Compute the storage and finalize cost of each transition.
+    let breakdown = transitions
+        .iter()
+        .map(|transition| {
+            // Compute the size of the transition.
+            let transition_storage_cost = u64::try_from(transition.to_bytes_le()?.len())?;
+            // Compute the cost of the transition's own finalize scope.
+            let stack = process.get_stack(transition.program_id())?;
+            let transition_finalize_cost = match stack.get_function_ref(transition.function_name())?.finalize_logic() {
+                Some(finalize) => cost_of_commands(stack, finalize)?,
+                None => 0,
+            };
+            Ok((*transition.id(), (transition_storage_cost, transition_finalize_cost)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Compute the total cost in microcredits.
+    let total_cost = storage_cost
+        .checked_add(finalize_cost)
+        .ok_or(anyhow!("The total cost computation overflowed for an execution"))?;
+
+    Ok((total_cost, (storage_cost, finalize_cost), breakdown))
+}
+
+/// Returns the sum of the finalize costs for the root transition of each call tree in the given transitions.
+fn finalize_cost_of_call_trees<N: Network>(process: &Process<N>, transitions: &[&Transition<N>]) -> Result<u64> {
+    process.to_call_trees(transitions)?.into_iter().try_fold(0u64, |total, (root, _)| {
+        // Get the finalize cost for the root transition.
+        let finalize_cost = process.get_stack(root.program_id())?.get_finalize_cost(root.function_name())?;
+        // Accumulate the finalize cost.
+        total.checked_add(finalize_cost).ok_or(anyhow!("The finalize cost computation overflowed for an execution"))
+    })
+}
+
 /// Finalize costs for compute heavy operations, derived as:
 /// `BASE_COST + (PER_BYTE_COST * SIZE_IN_BYTES)`.
 
@@ -374,11 +500,46 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         }
     }
     // Aggregate the cost of all commands in the program.
+    future_cost.checked_add(cost_of_commands(stack, finalize)?).ok_or(anyhow!("Finalize cost overflowed"))
+}
+
+/// Returns the number of microcredits required to run the commands in the given finalize scope, excluding any awaited futures.
+fn cost_of_commands<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>) -> Result<u64> {
     finalize
         .commands()
         .iter()
         .map(|command| cost_per_command(stack, finalize, command))
-        .try_fold(future_cost, |acc, res| {
-            res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed")))
-        })
+        .try_fold(0u64, |acc, res| res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed"))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+    use ledger_block::Block;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_proof_size_constants() {
+        // Ensure the derived proof sizes match the layout of a Varuna proof.
+        assert_eq!(PROOF_BASE_SIZE_IN_BYTES, 469);
+        assert_eq!(PROOF_PER_CIRCUIT_SIZE_IN_BYTES, 344);
+        assert_eq!(PROOF_PER_INSTANCE_SIZE_IN_BYTES, 144);
+    }
+
+    #[test]
+    fn test_execution_size_in_bytes() {
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        for transaction in block.transactions().iter() {
+            // Retrieve the execution.
+            let execution = transaction.execution().unwrap();
+            // Retrieve the transitions.
+            let transitions = execution.transitions().cloned().collect::<Vec<_>>();
+            // Ensure the estimated size matches the size of the proven execution.
+            assert_eq!(execution_size_in_bytes(&transitions).unwrap(), execution.size_in_bytes().unwrap());
+        }
+    }
 }
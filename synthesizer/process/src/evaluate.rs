@@ -36,4 +36,40 @@ impl<N: Network> Process<N> {
 
         response
     }
+
+    /// Returns the *estimated* cost in microcredits to publish an execution of the given authorization,
+    /// without proving it (total cost, (storage cost, finalize cost), [(transition ID, (storage cost, finalize cost))]).
+    ///
+    /// Note: The authorization is evaluated (on a replica) to ensure the call graph succeeds on the given inputs.
+    #[inline]
+    pub fn estimate_execution_cost<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: &Authorization<N>,
+    ) -> Result<(u64, (u64, u64), Vec<(N::TransitionID, (u64, u64))>)> {
+        let timer = timer!("Process::estimate_execution_cost");
+
+        // Evaluate each call tree, without modifying the given authorization.
+        // Note: The replica is shared across evaluations, so each call tree consumes its own requests.
+        let replica = authorization.replicate();
+        while !replica.is_empty() {
+            self.evaluate::<A>(replica.clone())?;
+        }
+        lap!(timer, "Evaluate the function(s)");
+
+        // Retrieve the transitions.
+        let transitions = authorization.transitions().into_values().collect::<Vec<_>>();
+        // Ensure there is a transition for each request.
+        ensure!(
+            transitions.len() == authorization.len(),
+            "Expected {} transitions in the authorization, found {}",
+            authorization.len(),
+            transitions.len()
+        );
+
+        // Estimate the execution cost.
+        let cost = estimate_execution_cost(self, &transitions);
+        finish!(timer, "Estimate the execution cost");
+
+        cost
+    }
 }
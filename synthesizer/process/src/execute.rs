@@ -56,6 +56,48 @@ impl<N: Network> Process<N> {
         finish!(timer);
         Ok((response, trace))
     }
+
+    /// Executes the given (bundled) authorization, returning the response of each call tree in order.
+    /// See `Authorization::bundle` for more details.
+    #[inline]
+    pub fn execute_bundle<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Vec<Response<N>>, Trace<N>)> {
+        let timer = timer!("Process::execute_bundle");
+
+        // Initialize the trace.
+        let trace = Arc::new(RwLock::new(Trace::new()));
+        // Initialize the responses.
+        let mut responses = Vec::new();
+
+        // Execute each call tree in order, until all of the requests have been consumed.
+        while !authorization.is_empty() {
+            // Retrieve the root request of the call tree (without popping it).
+            let request = authorization.peek_next()?;
+
+            #[cfg(feature = "aleo-cli")]
+            println!("{}", format!(" • Executing '{}/{}'...", request.program_id(), request.function_name()).dimmed());
+
+            // Initialize the call stack.
+            // Note: The authorization is shared, so each call tree consumes its own requests.
+            let call_stack = CallStack::execute(authorization.clone(), trace.clone())?;
+            // Retrieve the stack.
+            let stack = self.get_stack(request.program_id())?;
+            // Execute the circuit, as a root request without a caller or root_tvk.
+            responses.push(stack.execute_function::<A, R>(call_stack, None, None, rng)?);
+            lap!(timer, "Execute '{}/{}'", request.program_id(), request.function_name());
+        }
+
+        // Extract the trace.
+        let trace = Arc::try_unwrap(trace).unwrap().into_inner();
+        // Ensure the trace is not empty.
+        ensure!(!trace.transitions().is_empty(), "Execution of the bundle is empty");
+
+        finish!(timer);
+        Ok((responses, trace))
+    }
 }
 
 #[cfg(test)]
@@ -88,17 +88,14 @@ impl<N: Network> Process<N> {
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
-        // Ensure the number of transitions matches the program function.
-        // Retrieve the root transition (without popping it).
-        let transition = execution.peek()?;
-        // Retrieve the stack.
-        let stack = self.get_stack(transition.program_id())?;
-        // Ensure the number of calls matches the number of transitions.
-        let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
+        // Ensure the number of transitions matches the program function(s).
+        // Note: A bundled execution contains multiple call trees, each of which must be complete.
+        let call_trees = self.to_call_trees(&execution.transitions().collect::<Vec<_>>())?;
+        // Ensure the execution contains a single call tree, unless bundled executions are enabled.
         ensure!(
-            number_of_calls == execution.len(),
-            "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-            execution.len()
+            call_trees.len() == 1 || state.block_height() >= N::BUNDLED_EXECUTION_HEIGHT,
+            "Bundled executions are enabled from block {}",
+            N::BUNDLED_EXECUTION_HEIGHT
         );
         lap!(timer, "Verify the number of transitions");
 
@@ -106,10 +103,19 @@ impl<N: Network> Process<N> {
         let call_graph = self.construct_call_graph(execution)?;
 
         atomic_batch_scope!(store, {
-            // Finalize the root transition.
+            // Initialize the finalize operations.
+            let mut finalize_operations = Vec::new();
+
+            // Finalize the root transition of each call tree, in order.
             // Note that this will result in all the remaining transitions being finalized, since the number
-            // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            // of calls matches the number of transitions in each call tree. If any call tree fails to finalize,
+            // then the entire execution (i.e. every call tree in the bundle) is reverted.
+            for (transition, _) in call_trees {
+                // Retrieve the stack.
+                let stack = self.get_stack(transition.program_id())?;
+                // Finalize the call tree.
+                finalize_operations.extend(finalize_transition(state, store, stack, transition, call_graph.clone())?);
+            }
 
             /* Finalize the fee. */
 
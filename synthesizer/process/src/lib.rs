@@ -44,8 +44,20 @@ mod tests;
 
 use console::{
     account::PrivateKey,
-    network::prelude::*,
-    program::{compute_function_id, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
+    network::{prelude::*, CreditsKeyPair},
+    program::{
+        compute_function_id,
+        Identifier,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        Record,
+        Request,
+        Response,
+        SigningPayload,
+        Value,
+    },
     types::{Field, U16, U64},
 };
 use ledger_block::{Deployment, Execution, Fee, Input, Transition};
@@ -134,6 +146,23 @@ impl<N: Network> Process<N> {
         // Add the stack to the process.
         self.stacks.insert(*stack.program_id(), Arc::new(stack));
     }
+
+    /// Removes the stack for the given program ID from the process.
+    /// Note: This method is used to revert a deployment, and errors if another program imports the program.
+    #[inline]
+    pub fn remove_stack(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Cannot remove the 'credits.aleo' program");
+        // Ensure no other program imports the program.
+        if let Some(stack) = self.stacks.values().find(|stack| stack.program().contains_import(program_id)) {
+            bail!("Cannot remove program '{program_id}', as it is imported by '{}'", stack.program_id())
+        }
+        // Remove the stack from the process.
+        match self.stacks.shift_remove(program_id) {
+            Some(_) => Ok(()),
+            None => bail!("Program '{program_id}' does not exist"),
+        }
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -154,14 +183,7 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
         lap!(timer, "Initialize stack");
 
-        // Synthesize the 'credits.aleo' verifying keys.
-        for function_name in program.functions().keys() {
-            // Load the verifying key.
-            let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
-            stack.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone()))?;
-            lap!(timer, "Load verifying key for {function_name}");
-        }
-        lap!(timer, "Load circuit keys");
+        // Note: The 'credits.aleo' proving and verifying keys are loaded by the stack on first use.
 
         // Add the stack to the process.
         process.add_stack(stack);
@@ -283,6 +305,26 @@ impl<N: Network> Process<N> {
     }
 }
 
+impl<N: Network> Process<N> {
+    /// Sets the synthesizer of the 'credits.aleo' keys of the network, which is used if no keys are bundled.
+    #[inline]
+    pub fn set_credits_key_synthesizer<A: circuit::Aleo<Network = N>>() {
+        N::set_credits_key_synthesizer(Self::synthesize_credits_key::<A>)
+    }
+
+    /// Synthesizes the 'credits.aleo' proving and verifying key for the given function name.
+    /// Note: The keys only depend on the circuit of the function, so every node synthesizes the same keys.
+    fn synthesize_credits_key<A: circuit::Aleo<Network = N>>(function_name: &str) -> Result<CreditsKeyPair<N>> {
+        // Initialize a process, without loading the 'credits.aleo' keys.
+        let process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let stack = Stack::new(&process, &Program::credits()?)?;
+        // Synthesize the keys of the function.
+        let function_name = Identifier::from_str(function_name)?;
+        stack.synthesize_key::<A, _>(&function_name, &mut rand::thread_rng())?;
+        Ok(((*stack.get_proving_key(&function_name)?).clone(), (*stack.get_verifying_key(&function_name)?).clone()))
+    }
+}
+
 #[cfg(any(test, feature = "test"))]
 pub mod test_helpers {
     use super::*;
@@ -16,7 +16,11 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, program::Request, types::Field};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, Request, Value},
+    types::Field,
+};
 use ledger_block::{Transaction, Transition};
 
 use indexmap::IndexMap;
@@ -44,6 +48,50 @@ impl<N: Network> Authorization<N> {
             transitions: Arc::new(RwLock::new(self.transitions.read().clone())),
         }
     }
+
+    /// Initializes a new `Authorization` instance, by bundling the given authorizations in order.
+    ///
+    /// A bundle executes each call tree in order, under a single execution ID and fee,
+    /// and its finalize scopes are applied atomically (i.e. all-or-nothing).
+    /// The given authorizations may be for different programs and signed by different signers.
+    /// Note: A bundled execution is only valid in the blocks from `N::BUNDLED_EXECUTION_HEIGHT`.
+    ///
+    /// Warning: The requests are signed independently, and do not commit to the bundle. So, whoever assembles
+    /// the bundle may unbundle, reorder, or execute each authorization on its own. The atomicity only holds for
+    /// the bundle as it is assembled, and a signer is not protected against front-running by bundling.
+    pub fn bundle(authorizations: impl IntoIterator<Item = Authorization<N>>) -> Result<Self> {
+        // Initialize the requests and transitions.
+        let mut requests = VecDeque::new();
+        let mut transitions = IndexMap::new();
+        // Append the requests and transitions of each authorization.
+        for (index, authorization) in authorizations.into_iter().enumerate() {
+            // Ensure the authorization is not empty.
+            ensure!(!authorization.is_empty(), "Authorization {index} in the bundle is empty");
+            // Ensure the authorization is not for a fee.
+            ensure!(
+                !authorization.is_fee_private() && !authorization.is_fee_public(),
+                "Authorization {index} in the bundle is for a fee"
+            );
+            // Ensure the authorization contains a transition for each request.
+            ensure!(
+                authorization.len() == authorization.transitions.read().len(),
+                "Authorization {index} in the bundle is incomplete"
+            );
+            // Append the requests.
+            requests.extend(authorization.to_vec_deque());
+            // Append the transitions.
+            for (transition_id, transition) in authorization.transitions() {
+                ensure!(
+                    transitions.insert(transition_id, transition).is_none(),
+                    "Transition {transition_id} is duplicated in the bundle"
+                );
+            }
+        }
+        // Ensure the bundle is not empty.
+        ensure!(!requests.is_empty(), "Cannot bundle an empty list of authorizations");
+        // Return the bundled authorization.
+        Ok(Self { requests: Arc::new(RwLock::new(requests)), transitions: Arc::new(RwLock::new(transitions)) })
+    }
 }
 
 impl<N: Network> TryFrom<(Vec<Request<N>>, Vec<Transition<N>>)> for Authorization<N> {
@@ -115,6 +163,17 @@ impl<N: Network> Authorization<N> {
             _ => false,
         }
     }
+
+    /// Returns the deployment or execution ID that the fee authorization is bound to.
+    pub fn to_fee_deployment_or_execution_id(&self) -> Result<Field<N>> {
+        // Ensure the authorization is for a fee.
+        ensure!(self.is_fee_private() || self.is_fee_public(), "The authorization is not for a fee");
+        // Retrieve the last input of the fee request, which is the deployment or execution ID.
+        match self.requests.read()[0].inputs().last() {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::Field(id), _))) => Ok(*id),
+            _ => bail!("Failed to retrieve the deployment or execution ID from the fee authorization"),
+        }
+    }
 }
 
 impl<N: Network> Authorization<N> {
@@ -284,4 +343,34 @@ pub(crate) mod test_helpers {
         assert!(authorization.is_fee_public(), "Authorization must be for a call to 'credits.aleo/fee_public'");
         authorization
     }
+
+    #[test]
+    fn test_to_fee_deployment_or_execution_id() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        // Sample an execution ID.
+        let execution_id = Field::rand(rng);
+
+        // Ensure the execution ID is retrieved from a public fee authorization.
+        let authorization =
+            process.authorize_fee_public::<CurrentAleo, _>(&private_key, 100, 0, execution_id, rng).unwrap();
+        assert_eq!(authorization.to_fee_deployment_or_execution_id().unwrap(), execution_id);
+
+        // Ensure a non-fee authorization is rejected.
+        let address = console::account::Address::try_from(&private_key).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "transfer_public",
+                [address.to_string(), "1u64".to_string()].into_iter(),
+                rng,
+            )
+            .unwrap();
+        assert!(authorization.to_fee_deployment_or_execution_id().is_err());
+    }
 }
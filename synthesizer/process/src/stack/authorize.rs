@@ -47,7 +47,79 @@ impl<N: Network> Stack<N> {
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Construct the call stack.
-        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
+        let call_stack = CallStack::Authorize(vec![request], Some(*private_key), authorization.clone());
+        // Construct the authorization from the function.
+        let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
+        finish!(timer, "Construct the authorization from the function");
+
+        // Return the authorization.
+        Ok(authorization)
+    }
+
+    /// Returns the signing payload for a call to the program function for the given inputs.
+    /// The payload can be signed by an external signer, and assembled into a request with `Request::from_signing_payload`.
+    ///
+    /// Note: The payload only covers the root request, so `Stack::authorize_request` fails for functions with calls.
+    #[inline]
+    pub fn signing_payload(
+        &self,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<SigningPayload<N>> {
+        // Get the program ID.
+        let program_id = *self.program.id();
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the input types.
+        let input_types = self.get_function(&function_name)?.input_types();
+        // Prepare the inputs.
+        let inputs = inputs
+            .enumerate()
+            .map(|(index, input)| {
+                input
+                    .try_into()
+                    .map_err(|_| anyhow!("Failed to parse input #{index} for '{program_id}/{function_name}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the signing payload for the root request.
+        SigningPayload::new(program_id, function_name, inputs, input_types, None, true)
+    }
+
+    /// Authorizes a call to the program function for the given signed request.
+    /// This method does not require the private key, and is used to authorize requests that were signed externally.
+    ///
+    /// # Errors
+    /// This method fails if the function makes an external call, as each nested request must be signed
+    /// by the private key. Only functions without calls can be authorized from an external signature.
+    #[inline]
+    pub fn authorize_request<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        request: Request<N>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        let timer = timer!("Stack::authorize_request");
+
+        // Ensure the request is for this program.
+        ensure!(
+            request.program_id() == self.program.id(),
+            "Expected a request for '{}', found a request for '{}'",
+            self.program.id(),
+            request.program_id()
+        );
+        // Retrieve the input types.
+        let input_types = self.get_function(request.function_name())?.input_types();
+        // Ensure the request is a valid root request.
+        ensure!(request.verify(&input_types, true), "Request is invalid");
+        lap!(timer, "Verify the request");
+
+        // This is the root request and does not have a caller.
+        let caller = None;
+        // This is the root request and we do not have a root_tvk to pass on.
+        let root_tvk = None;
+        // Initialize the authorization.
+        let authorization = Authorization::new(request.clone());
+        // Construct the call stack.
+        let call_stack = CallStack::Authorize(vec![request], None, authorization.clone());
         // Construct the authorization from the function.
         let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
         finish!(timer, "Construct the authorization from the function");
@@ -227,7 +227,7 @@ impl<N: Network> CallTrait<N> for Call<N> {
 
                 match registers.call_stack() {
                     // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
-                    CallStack::Authorize(_, private_key, authorization)
+                    CallStack::Authorize(_, Some(private_key), authorization)
                     | CallStack::Synthesize(_, private_key, authorization) => {
                         // Compute the request.
                         let request = Request::sign(
@@ -255,6 +255,12 @@ impl<N: Network> CallTrait<N> for Call<N> {
                         // Return the request and response.
                         (request, response)
                     }
+                    // If the circuit is in authorize mode without a private key, then external calls cannot be signed.
+                    CallStack::Authorize(_, None, _) => bail!(
+                        "Cannot authorize the call to '{}/{}' without a private key",
+                        substack.program_id(),
+                        function.name()
+                    ),
                     CallStack::PackageRun(_, private_key, ..) => {
                         // Compute the request.
                         let request = Request::sign(
@@ -58,6 +58,7 @@ use console::{
         RegisterType,
         Request,
         Response,
+        SigningPayload,
         Value,
         ValueType,
     },
@@ -79,7 +80,7 @@ pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>
 
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
-    Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
+    Authorize(Vec<Request<N>>, Option<PrivateKey<N>>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, Option<u64>),
     Evaluate(Authorization<N>),
@@ -391,6 +392,8 @@ impl<N: Network> Stack<N> {
     /// Returns the verifying key for the given function name.
     #[inline]
     pub fn get_verifying_key(&self, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        // If the program is 'credits.aleo', try to load the verifying key, if it does not exist.
+        self.try_insert_credits_function_verifying_key(function_name)?;
         // Return the verifying key, if it exists.
         match self.verifying_keys.read().get(function_name) {
             Some(verifying_key) => Ok(verifying_key.clone()),
@@ -453,6 +456,20 @@ impl<N: Network> Stack<N> {
         }
         Ok(())
     }
+
+    /// Inserts the verifying key if the program ID is 'credits.aleo'.
+    fn try_insert_credits_function_verifying_key(&self, function_name: &Identifier<N>) -> Result<()> {
+        // If the program is 'credits.aleo' and it does not exist yet, load the verifying key directly.
+        if self.program_id() == &ProgramID::from_str("credits.aleo")?
+            && !self.verifying_keys.read().contains_key(function_name)
+        {
+            // Load the 'credits.aleo' function verifying key.
+            let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
+            // Insert the 'credits.aleo' function verifying key.
+            self.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone()))?;
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> PartialEq for Stack<N> {
@@ -14,6 +14,7 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    Authorization,
     CallStack,
     Process,
     Stack,
@@ -23,7 +24,7 @@ use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Request, Value},
     types::{Field, U64},
 };
 use ledger_block::{Fee, Transaction};
@@ -1291,7 +1292,7 @@ finalize compute:
     let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -1404,7 +1405,7 @@ finalize compute:
     let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -1535,7 +1536,7 @@ finalize mint_public:
     let execution = trace.prove_execution::<CurrentAleo, _>("token", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -1703,7 +1704,7 @@ finalize init:
     let execution = trace.prove_execution::<CurrentAleo, _>("public_wallet", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -1818,7 +1819,7 @@ finalize compute:
     let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -1948,7 +1949,7 @@ function a:
     let execution = trace.prove_execution::<CurrentAleo, _>("two", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 }
 
 #[test]
@@ -2135,7 +2136,7 @@ fn test_complex_execution_order() {
     let execution = trace.prove_execution::<CurrentAleo, _>("four", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 }
 
 #[test]
@@ -2246,7 +2247,7 @@ finalize compute:
     let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -2355,7 +2356,7 @@ function compute:
     let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
 }
 
 #[test]
@@ -2645,3 +2646,386 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_estimate_execution_cost() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            "credits.aleo",
+            "transfer_public",
+            [Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()].iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Estimate the execution cost.
+    let (total_cost, (storage_cost, finalize_cost), breakdown) =
+        process.estimate_execution_cost::<CurrentAleo>(&authorization).unwrap();
+
+    // Ensure the authorization was not modified.
+    assert_eq!(authorization.len(), 1);
+
+    // Ensure the finalize cost matches the finalize cost of the function.
+    let expected_finalize_cost = process
+        .get_stack("credits.aleo")
+        .unwrap()
+        .get_finalize_cost(&Identifier::from_str("transfer_public").unwrap())
+        .unwrap();
+    assert_eq!(finalize_cost, expected_finalize_cost);
+    assert_eq!(total_cost, storage_cost + finalize_cost);
+
+    // Ensure the breakdown covers the transition.
+    assert_eq!(breakdown.len(), 1);
+    let (transition_id, (transition_storage_cost, transition_finalize_cost)) = breakdown[0];
+    let transitions = authorization.transitions();
+    let transition = transitions.get(&transition_id).unwrap();
+    assert_eq!(transition_storage_cost, transition.to_bytes_le().unwrap().len() as u64);
+    assert_eq!(transition_finalize_cost, finalize_cost);
+    assert!(storage_cost > transition_storage_cost);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Execute and prove the authorization.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("credits.aleo/transfer_public", rng).unwrap();
+    process.verify_execution(&execution, 0).unwrap();
+
+    // Ensure the estimate matches the cost of the proven execution.
+    assert_eq!(storage_cost, execution.size_in_bytes().unwrap());
+    assert_eq!((total_cost, (storage_cost, finalize_cost)), crate::execution_cost(&process, &execution).unwrap());
+}
+
+#[test]
+fn test_process_estimate_execution_cost_fails_on_invalid_inputs() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function check:
+    input r0 as u32.private;
+    assert.eq r0 1u32;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Authorize the function call with an input that satisfies the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "check", ["1u32"].into_iter(), rng)
+        .unwrap();
+    assert!(process.estimate_execution_cost::<CurrentAleo>(&authorization).is_ok());
+
+    // Authorize the function call with an input that does not satisfy the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "check", ["2u32"].into_iter(), rng)
+        .unwrap();
+    assert!(process.estimate_execution_cost::<CurrentAleo>(&authorization).is_err());
+}
+
+#[test]
+fn test_process_authorize_signed_fee_request() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Sample the fee amounts and the execution ID.
+    let base_fee_in_microcredits = 1000;
+    let priority_fee_in_microcredits = 100;
+    let execution_id = Field::rand(rng);
+
+    // Construct the signing payload for the fee.
+    let payload = process
+        .fee_public_signing_payload(base_fee_in_microcredits, priority_fee_in_microcredits, execution_id)
+        .unwrap();
+    assert_eq!(payload.program_id().to_string(), "credits.aleo");
+    assert_eq!(payload.function_name().to_string(), "fee_public");
+
+    // Sign the payload (this step happens on the external signer).
+    let seed: u64 = rng.gen();
+    let signature = payload.sign(&caller_private_key, &mut TestRng::fixed(seed)).unwrap();
+
+    // Assemble the request, and authorize it without the private key.
+    let request = Request::from_signing_payload(&payload, &signature).unwrap();
+    let candidate = process.authorize_request::<CurrentAleo, _>(request, rng).unwrap();
+
+    // Authorize the fee in-process, with the same signing randomness.
+    let expected = process
+        .authorize_fee_public::<CurrentAleo, _>(
+            &caller_private_key,
+            base_fee_in_microcredits,
+            priority_fee_in_microcredits,
+            execution_id,
+            &mut TestRng::fixed(seed),
+        )
+        .unwrap();
+
+    // Ensure the authorizations match.
+    assert_eq!(expected, candidate);
+}
+
+#[test]
+fn test_process_authorize_signed_request_fails_on_external_call() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize the programs.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program child.aleo;
+
+function noop:",
+    )
+    .unwrap();
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+function call_noop:
+    call child.aleo/noop;
+
+function noop:",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program0).unwrap();
+    process.add_program(&program1).unwrap();
+
+    // Ensure a signed request without external calls is authorized.
+    let payload =
+        process.signing_payload(program1.id(), "noop", Vec::<Value<CurrentNetwork>>::new().into_iter()).unwrap();
+    let signature = payload.sign(&caller_private_key, rng).unwrap();
+    let request = Request::from_signing_payload(&payload, &signature).unwrap();
+    let authorization = process.authorize_request::<CurrentAleo, _>(request, rng).unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Ensure a signed request with an external call is rejected, as the nested request cannot be signed.
+    let payload =
+        process.signing_payload(program1.id(), "call_noop", Vec::<Value<CurrentNetwork>>::new().into_iter()).unwrap();
+    let signature = payload.sign(&caller_private_key, rng).unwrap();
+    let request = Request::from_signing_payload(&payload, &signature).unwrap();
+    assert!(process.authorize_request::<CurrentAleo, _>(request, rng).is_err());
+}
+
+#[test]
+fn test_process_bundle_authorization() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize two caller accounts.
+    let caller_private_key_0 = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller_0 = Address::try_from(&caller_private_key_0).unwrap();
+    let caller_private_key_1 = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller_1 = Address::try_from(&caller_private_key_1).unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Authorize a function call for each caller.
+    let authorization_0 = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key_0,
+            "credits.aleo",
+            "transfer_public",
+            [caller_1.to_string(), "1u64".to_string()].into_iter(),
+            rng,
+        )
+        .unwrap();
+    let authorization_1 = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key_1,
+            "credits.aleo",
+            "transfer_public",
+            [caller_0.to_string(), "1u64".to_string()].into_iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Bundle the authorizations.
+    let bundle = Authorization::bundle([authorization_0.replicate(), authorization_1.replicate()]).unwrap();
+    assert_eq!(bundle.len(), 2);
+    // Ensure the transitions are in order.
+    let transitions = bundle.transitions().into_values().collect::<Vec<_>>();
+    assert_eq!(transitions[0], authorization_0.transitions()[0]);
+    assert_eq!(transitions[1], authorization_1.transitions()[0]);
+
+    // Ensure the bundle contains 2 call trees.
+    let call_trees = process.to_call_trees(&transitions.iter().collect::<Vec<_>>()).unwrap();
+    assert_eq!(call_trees.len(), 2);
+    assert!(call_trees.iter().all(|(_, number_of_transitions)| *number_of_transitions == 1));
+
+    // Ensure the estimated finalize cost includes both call trees.
+    let (_, (_, finalize_cost), breakdown) = process.estimate_execution_cost::<CurrentAleo>(&bundle).unwrap();
+    let expected_finalize_cost = process
+        .get_stack("credits.aleo")
+        .unwrap()
+        .get_finalize_cost(&Identifier::from_str("transfer_public").unwrap())
+        .unwrap();
+    assert_eq!(finalize_cost, 2 * expected_finalize_cost);
+    assert_eq!(breakdown.len(), 2);
+    // Ensure the bundle was not modified.
+    assert_eq!(bundle.len(), 2);
+
+    // Ensure an empty bundle is rejected.
+    assert!(Authorization::<CurrentNetwork>::bundle([]).is_err());
+    // Ensure a fee authorization cannot be bundled.
+    let fee_authorization =
+        process.authorize_fee_public::<CurrentAleo, _>(&caller_private_key_0, 100, 0, Field::rand(rng), rng).unwrap();
+    assert!(Authorization::bundle([authorization_0.replicate(), fee_authorization]).is_err());
+    // Ensure the same authorization cannot be bundled twice.
+    assert!(Authorization::bundle([authorization_0.replicate(), authorization_0]).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_bundle() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program bundle.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function deposit:
+    input r0 as u64.public;
+    async deposit self.caller r0 into r1;
+    output r1 as bundle.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+
+function withdraw:
+    input r0 as u64.public;
+    async withdraw self.caller r0 into r1;
+    output r1 as bundle.aleo/withdraw.future;
+
+finalize withdraw:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    sub r2 r1 into r3;
+    set r3 into account[r0];",
+    )
+    .unwrap();
+
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize two caller accounts.
+    let caller_private_key_0 = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller_0 = Address::try_from(&caller_private_key_0).unwrap();
+    let caller_private_key_1 = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller_1 = Address::try_from(&caller_private_key_1).unwrap();
+
+    // A helper to prove a bundle of (private key, function name, amount) calls.
+    let mut prove_bundle = |calls: &[(&PrivateKey<CurrentNetwork>, &str, &str)]| {
+        // Authorize each call.
+        let authorizations = calls
+            .iter()
+            .map(|(private_key, function_name, amount)| {
+                process
+                    .authorize::<CurrentAleo, _>(private_key, program.id(), *function_name, [*amount].into_iter(), rng)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        // Bundle the authorizations.
+        let bundle = Authorization::bundle(authorizations).unwrap();
+        // Execute the bundle.
+        let (responses, mut trace) = process.execute_bundle::<CurrentAleo, _>(bundle, rng).unwrap();
+        assert_eq!(responses.len(), calls.len());
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("bundle", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution, CurrentNetwork::BUNDLED_EXECUTION_HEIGHT).unwrap();
+        execution
+    };
+
+    // Retrieve the height from which bundled executions are enabled.
+    let bundle_height = CurrentNetwork::BUNDLED_EXECUTION_HEIGHT;
+
+    // Prove a bundle, where both call trees succeed.
+    let deposits =
+        prove_bundle(&[(&caller_private_key_0, "deposit", "5u64"), (&caller_private_key_1, "deposit", "3u64")]);
+    // Ensure the bundle fails to verify and finalize before bundled executions are enabled.
+    if let Some(height) = bundle_height.checked_sub(1) {
+        assert!(process.verify_execution(&deposits, height).is_err());
+        assert!(process.finalize_execution(sample_finalize_state(height), &finalize_store, &deposits, None).is_err());
+    }
+
+    // Prove a bundle, where the second call tree fails to finalize.
+    let execution =
+        prove_bundle(&[(&caller_private_key_0, "deposit", "5u64"), (&caller_private_key_1, "withdraw", "3u64")]);
+    // Ensure the bundle fails to finalize.
+    assert!(
+        process.finalize_execution(sample_finalize_state(bundle_height), &finalize_store, &execution, None).is_err()
+    );
+    // Ensure the first call tree was not finalized.
+    let key_0 = Plaintext::from(Literal::Address(caller_0));
+    assert!(finalize_store.get_value_speculative(*program.id(), mapping_name, &key_0).unwrap().is_none());
+
+    // Ensure the bundle where both call trees succeed finalizes.
+    process.finalize_execution(sample_finalize_state(bundle_height), &finalize_store, &deposits, None).unwrap();
+    // Ensure both call trees were finalized.
+    let key_1 = Plaintext::from(Literal::Address(caller_1));
+    let candidate = finalize_store.get_value_speculative(*program.id(), mapping_name, &key_0).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("5u64").unwrap());
+    let candidate = finalize_store.get_value_speculative(*program.id(), mapping_name, &key_1).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("3u64").unwrap());
+}
@@ -15,31 +15,30 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Verifies the given execution is valid.
+    /// Verifies the given execution is valid, for a block at the given height.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
-    pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+    pub fn verify_execution(&self, execution: &Execution<N>, block_height: u32) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
-        // Ensure the number of transitions matches the program function.
-        let locator = {
-            // Retrieve the transition (without popping it).
-            let transition = execution.peek()?;
-            // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
-            // Ensure the number of calls matches the number of transitions.
-            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-            ensure!(
-                number_of_calls == execution.len(),
-                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-                execution.len()
-            );
-            // Output the locator of the main function.
-            Locator::new(*transition.program_id(), *transition.function_name()).to_string()
-        };
+        // Ensure the number of transitions matches the program function(s).
+        // Note: A bundled execution contains multiple call trees, each of which must be complete.
+        let call_trees = self.to_call_trees(&execution.transitions().collect::<Vec<_>>())?;
+        // Ensure the execution contains a single call tree, unless bundled executions are enabled.
+        ensure!(
+            call_trees.len() == 1 || block_height >= N::BUNDLED_EXECUTION_HEIGHT,
+            "Bundled executions are enabled from block {}",
+            N::BUNDLED_EXECUTION_HEIGHT
+        );
+        // Output the locator(s) of the main function(s).
+        let locator = call_trees
+            .iter()
+            .map(|(root, _)| Locator::new(*root.program_id(), *root.function_name()).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
         lap!(timer, "Verify the number of transitions");
 
         // Construct the call graph of the execution.
@@ -131,16 +130,19 @@ impl<N: Network> Process<N> {
         let num_instances = verifier_inputs.values().map(|(_, inputs)| inputs.len()).sum::<usize>();
         // Ensure the number of instances matches the number of transitions.
         ensure!(num_instances == execution.transitions().len(), "The number of verifier instances is incorrect");
-        // Ensure the same signer is used for all transitions.
-        execution.transitions().try_fold(None, |signer, transition| {
-            Ok(match signer {
-                None => Some(transition.scm()),
-                Some(signer) => {
-                    ensure!(signer == transition.scm(), "The transitions did not use the same signer");
-                    Some(signer)
-                }
-            })
-        })?;
+        // Ensure the same signer is used for all transitions in each call tree.
+        let mut transitions = execution.transitions();
+        for (_, number_of_transitions) in &call_trees {
+            transitions.by_ref().take(*number_of_transitions).try_fold(None, |signer, transition| {
+                Ok(match signer {
+                    None => Some(transition.scm()),
+                    Some(signer) => {
+                        ensure!(signer == transition.scm(), "The transitions did not use the same signer");
+                        Some(signer)
+                    }
+                })
+            })?;
+        }
 
         // Construct the list of verifier inputs.
         let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
@@ -206,6 +208,41 @@ impl<N: Network> Process<N> {
 }
 
 impl<N: Network> Process<N> {
+    /// Returns the root transition and number of transitions of each call tree in the given transitions, in order.
+    ///
+    /// The transitions of each call tree are in post-order, so the root of the last call tree is the last transition.
+    /// An execution contains a single call tree, unless it is a bundle (see `Authorization::bundle`).
+    pub fn to_call_trees<'a>(&self, transitions: &[&'a Transition<N>]) -> Result<Vec<(&'a Transition<N>, usize)>> {
+        // Ensure there are transitions.
+        ensure!(!transitions.is_empty(), "There are no transitions in the execution");
+
+        // Initialize the call trees.
+        let mut call_trees = Vec::new();
+        // Initialize the number of remaining transitions.
+        let mut remaining = transitions.len();
+        // Iterate over each call tree in reverse order.
+        while remaining > 0 {
+            // Retrieve the root transition of the call tree.
+            let root = transitions[remaining - 1];
+            // Retrieve the number of calls in the call tree.
+            let number_of_calls = self.get_stack(root.program_id())?.get_number_of_calls(root.function_name())?;
+            // Ensure the call tree is complete.
+            ensure!(
+                number_of_calls > 0 && number_of_calls <= remaining,
+                "The number of transitions in the execution is incorrect. Expected {number_of_calls} for '{}/{}', but found {remaining}",
+                root.program_id(),
+                root.function_name()
+            );
+            // Add the call tree.
+            call_trees.push((root, number_of_calls));
+            // Update the number of remaining transitions.
+            remaining -= number_of_calls;
+        }
+        // Return the call trees, in order.
+        call_trees.reverse();
+        Ok(call_trees)
+    }
+
     // A helper function to construct a call graph from an execution.
     //
     // The call graph represents a mapping of parent transition IDs to child transition IDs,
@@ -296,6 +333,7 @@ impl<N: Network> Process<N> {
             // - The stack is either empty, or the top entry is incomplete.
             match traversal_stack.last_mut() {
                 // If the stack is empty, then push the `transition` to the top of the stack.
+                // Note: In a bundled execution, the stack is empty at the root of each call tree.
                 None => {
                     traversal_stack.push(TransitionMetadata::new(
                         &mut counter,
@@ -115,6 +115,84 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Compute the authorization");
         result
     }
+
+    /// Authorizes a sponsored fee, where the sponsor (i.e. a paymaster) pays the fee for the given execution,
+    /// which may be authorized by a different caller.
+    ///
+    /// If a `fee_record` is provided, then a private fee is authorized; otherwise, a public fee is authorized.
+    /// The fee is bound to the execution ID of the given authorization, and the base fee is set to the
+    /// estimated execution cost. This method errors if the total fee exceeds `max_fee_in_microcredits`.
+    #[inline]
+    pub fn authorize_sponsored_fee<R: Rng + CryptoRng>(
+        &self,
+        sponsor_private_key: &PrivateKey<N>,
+        execute_authorization: &Authorization<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        max_fee_in_microcredits: u64,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        let timer = timer!("VM::authorize_sponsored_fee");
+
+        // Ensure the authorization is not for a fee.
+        ensure!(
+            !execute_authorization.is_fee_private() && !execute_authorization.is_fee_public(),
+            "Cannot sponsor the fee of a fee authorization"
+        );
+        // Compute the execution ID.
+        let execution_id = execute_authorization.to_execution_id()?;
+        lap!(timer, "Compute the execution ID");
+
+        // Compute the base fee.
+        // Note: A split does not require a base fee, to match the behavior of `VM::execute`.
+        let base_fee_in_microcredits = match execute_authorization.is_split() {
+            true => 0,
+            false => {
+                macro_rules! logic {
+                    ($process:expr, $network:path, $aleo:path) => {{
+                        // Estimate the execution cost.
+                        let (cost, _, _) = $process.estimate_execution_cost::<$aleo>(cast_ref!(
+                            &execute_authorization as Authorization<$network>
+                        ))?;
+                        Ok(cost)
+                    }};
+                }
+                let result: Result<u64> = process!(self, logic);
+                result?
+            }
+        };
+        lap!(timer, "Estimate the execution cost");
+
+        // Ensure the total fee does not exceed the maximum fee of the sponsor.
+        let total_fee_in_microcredits = base_fee_in_microcredits
+            .checked_add(priority_fee_in_microcredits)
+            .ok_or_else(|| anyhow!("The sponsored fee overflowed"))?;
+        ensure!(
+            total_fee_in_microcredits <= max_fee_in_microcredits,
+            "The sponsored fee ({total_fee_in_microcredits} microcredits) exceeds the maximum fee ({max_fee_in_microcredits} microcredits)"
+        );
+
+        // Authorize the fee.
+        let result = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                sponsor_private_key,
+                record,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            ),
+            None => self.authorize_fee_public(
+                sponsor_private_key,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            ),
+        };
+        finish!(timer, "Authorize the sponsored fee");
+        result
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
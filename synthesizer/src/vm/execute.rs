@@ -76,6 +76,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Returns a new execute transaction for the given authorization.
+    ///
+    /// The execute authorization may bundle multiple call trees (see `Authorization::bundle`),
+    /// in which case they are executed under the same fee, and finalized atomically.
+    /// Note: A bundle is only valid in the blocks from `N::BUNDLED_EXECUTION_HEIGHT`.
+    ///
+    /// The fee authorization may be signed by a different caller than the execute authorization
+    /// (see `VM::authorize_sponsored_fee`), however it must be bound to the execution ID.
     pub fn execute_authorization<R: Rng + CryptoRng>(
         &self,
         execute_authorization: Authorization<N>,
@@ -83,6 +90,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
+        // Ensure the fee authorization is bound to the execution, prior to proving.
+        if let Some(fee_authorization) = &fee_authorization {
+            // Compute the execution ID.
+            let execution_id = execute_authorization.to_execution_id()?;
+            // Ensure the fee authorization is for the execution ID.
+            ensure!(
+                fee_authorization.to_fee_deployment_or_execution_id()? == execution_id,
+                "The fee authorization is not bound to the execution '{execution_id}'"
+            );
+        }
         // Compute the execution.
         let execution = self.execute_authorization_raw(execute_authorization, query.clone(), rng)?;
         // Compute the fee.
@@ -118,10 +135,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Execution<N>> {
         let timer = timer!("VM::execute_authorization_raw");
 
-        // Construct the locator of the main function.
-        let locator = {
+        // Construct the locator of the main function, and determine if the authorization is a bundle.
+        let (locator, is_bundle) = {
             let request = authorization.peek_next()?;
-            Locator::new(*request.program_id(), *request.function_name()).to_string()
+            // Retrieve the number of calls in the call tree of the main function.
+            let number_of_calls =
+                self.process.read().get_stack(request.program_id())?.get_number_of_calls(request.function_name())?;
+            // Note: An authorization contains a single call tree, unless it is a bundle.
+            let is_bundle = number_of_calls != authorization.len();
+            (Locator::new(*request.program_id(), *request.function_name()).to_string(), is_bundle)
         };
         // Prepare the query.
         let query = match query {
@@ -134,8 +156,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the authorization.
                 let authorization = cast_ref!(authorization as Authorization<$network>);
-                // Execute the call.
-                let (_, mut trace) = $process.execute::<$aleo, _>(authorization.clone(), rng)?;
+                // Execute the call(s).
+                let mut trace = match is_bundle {
+                    // If the authorization is a bundle, then execute each call tree in order.
+                    true => $process.execute_bundle::<$aleo, _>(authorization.clone(), rng)?.1,
+                    // Otherwise, execute the call.
+                    false => $process.execute::<$aleo, _>(authorization.clone(), rng)?.1,
+                };
                 lap!(timer, "Execute the call");
 
                 // Prepare the assignments.
@@ -708,4 +735,190 @@ finalize test:
         // Check that the finalize cost is equal to the expected cost.
         assert_eq!(finalize_cost, expected_cost);
     }
+
+    #[test]
+    fn test_sponsored_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the sponsor, who holds the genesis credits.
+        let sponsor_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        // Initialize a new caller, who holds no credits.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Deploy a program.
+        let program = Program::from_str(
+            r"
+program sponsored.aleo;
+function increment:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+        let transaction = vm.deploy(&sponsor_private_key, &program, None, 0, None, rng).unwrap();
+        let next_block =
+            crate::test_helpers::sample_next_block(&vm, &sponsor_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&next_block).unwrap();
+
+        // Authorize the execution by the caller.
+        let execute_authorization =
+            vm.authorize(&caller_private_key, "sponsored.aleo", "increment", ["1u64"], rng).unwrap();
+
+        // Ensure the sponsor rejects a fee that exceeds its maximum fee.
+        assert!(vm.authorize_sponsored_fee(&sponsor_private_key, &execute_authorization, None, 0, 1, rng).is_err());
+        // Ensure a fee authorization cannot be sponsored.
+        let fee_authorization = vm.authorize_fee_public(&sponsor_private_key, 1, 0, Field::rand(rng), rng).unwrap();
+        assert!(vm.authorize_sponsored_fee(&sponsor_private_key, &fee_authorization, None, 0, u64::MAX, rng).is_err());
+
+        // Ensure a fee authorization that is not bound to the execution is rejected.
+        assert!(vm
+            .execute_authorization(execute_authorization.replicate(), Some(fee_authorization), None, rng)
+            .is_err());
+
+        // Authorize the sponsored fee.
+        let fee_authorization =
+            vm.authorize_sponsored_fee(&sponsor_private_key, &execute_authorization, None, 0, u64::MAX, rng).unwrap();
+        // Construct the transaction.
+        let transaction = vm.execute_authorization(execute_authorization, Some(fee_authorization), None, rng).unwrap();
+
+        // Ensure the fee is paid by the sponsor.
+        let sponsor = Address::try_from(&sponsor_private_key).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let fee = transaction.fee_transition().unwrap();
+        assert_eq!(fee.payer(), Some(sponsor));
+        assert_ne!(fee.payer(), Some(caller));
+
+        // Ensure the base fee covers the execution cost.
+        let Transaction::Execute(_, execution, _) = &transaction else {
+            unreachable!("VM::execute_authorization always produces an `Execution`")
+        };
+        let (cost, _) = execution_cost(&vm.process().read(), execution).unwrap();
+        assert_eq!(*fee.base_amount().unwrap(), cost);
+
+        // Ensure the transaction is valid and is accepted.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        let next_block =
+            crate::test_helpers::sample_next_block(&vm, &sponsor_private_key, &[transaction], rng).unwrap();
+        assert!(next_block.transactions().iter().all(|transaction| transaction.is_accepted()));
+        vm.add_next_block(&next_block).unwrap();
+    }
+
+    #[test]
+    fn test_bundled_execution() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the first caller, who holds the genesis credits and pays the fee.
+        let caller_private_key_0 = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_0 = Address::try_from(&caller_private_key_0).unwrap();
+        // Initialize the second caller, who holds no credits.
+        let caller_private_key_1 = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller_1 = Address::try_from(&caller_private_key_1).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Deploy a program.
+        let program = Program::from_str(
+            r"
+program bundle.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function deposit:
+    input r0 as u64.public;
+    async deposit self.caller r0 into r1;
+    output r1 as bundle.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+
+function withdraw:
+    input r0 as u64.public;
+    async withdraw self.caller r0 into r1;
+    output r1 as bundle.aleo/withdraw.future;
+
+finalize withdraw:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    sub r2 r1 into r3;
+    set r3 into account[r0];",
+        )
+        .unwrap();
+        let transaction = vm.deploy(&caller_private_key_0, &program, None, 0, None, rng).unwrap();
+        let next_block =
+            crate::test_helpers::sample_next_block(&vm, &caller_private_key_0, &[transaction], rng).unwrap();
+        vm.add_next_block(&next_block).unwrap();
+
+        // A helper to construct a bundled transaction, with the fee paid by the first caller.
+        let bundle = |calls: [(&PrivateKey<CurrentNetwork>, &str); 2], rng: &mut TestRng| {
+            // Authorize each call.
+            let authorizations = calls
+                .into_iter()
+                .map(|(private_key, function_name)| {
+                    vm.authorize(private_key, "bundle.aleo", function_name, ["5u64"], rng).unwrap()
+                })
+                .collect::<Vec<_>>();
+            // Bundle the authorizations.
+            let authorization = Authorization::bundle(authorizations).unwrap();
+            // Authorize the fee.
+            let fee_authorization =
+                vm.authorize_sponsored_fee(&caller_private_key_0, &authorization, None, 0, u64::MAX, rng).unwrap();
+            // Construct the transaction.
+            vm.execute_authorization(authorization, Some(fee_authorization), None, rng).unwrap()
+        };
+        // A helper to retrieve the account balance of the given address.
+        let balance = |address: Address<CurrentNetwork>| {
+            vm.finalize_store()
+                .get_value_confirmed(
+                    ProgramID::from_str("bundle.aleo").unwrap(),
+                    Identifier::from_str("account").unwrap(),
+                    &Plaintext::from(Literal::Address(address)),
+                )
+                .unwrap()
+        };
+
+        // Construct a bundle, where the second call tree fails to finalize.
+        let transaction = bundle([(&caller_private_key_0, "deposit"), (&caller_private_key_1, "withdraw")], rng);
+        let Transaction::Execute(_, execution, _) = &transaction else {
+            unreachable!("VM::execute_authorization always produces an `Execution`")
+        };
+        assert_eq!(execution.len(), 2);
+
+        // Ensure the bundle is invalid until bundled executions are enabled.
+        while *vm.block_store().heights().max().unwrap() + 1 < CurrentNetwork::BUNDLED_EXECUTION_HEIGHT {
+            assert!(vm.check_transaction(&transaction, None, rng).is_err());
+            let next_block = crate::test_helpers::sample_next_block(&vm, &caller_private_key_0, &[], rng).unwrap();
+            vm.add_next_block(&next_block).unwrap();
+        }
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Ensure the bundle is rejected, and the first call tree is not finalized.
+        let next_block =
+            crate::test_helpers::sample_next_block(&vm, &caller_private_key_0, &[transaction], rng).unwrap();
+        assert!(next_block.transactions().iter().all(|transaction| transaction.is_rejected()));
+        vm.add_next_block(&next_block).unwrap();
+        assert!(balance(caller_0).is_none());
+
+        // Construct a bundle, where both call trees succeed.
+        let transaction = bundle([(&caller_private_key_0, "deposit"), (&caller_private_key_1, "deposit")], rng);
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Ensure the bundle is accepted, and both call trees are finalized.
+        let next_block =
+            crate::test_helpers::sample_next_block(&vm, &caller_private_key_0, &[transaction], rng).unwrap();
+        assert!(next_block.transactions().iter().all(|transaction| transaction.is_accepted()));
+        vm.add_next_block(&next_block).unwrap();
+        assert_eq!(balance(caller_0), Some(Value::from_str("5u64").unwrap()));
+        assert_eq!(balance(caller_1), Some(Value::from_str("5u64").unwrap()));
+    }
 }
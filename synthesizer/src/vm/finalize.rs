@@ -179,8 +179,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::finalize");
 
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: The history height is set and cleared while the lock is held, so that no speculation
+        // records its updates into the history of the block.
+        let atomic_lock = self.atomic_lock.lock();
+
+        // Record the previous values of the keys updated by the block, for historical queries and rollbacks.
+        self.finalize_store().set_history_height(Some(state.block_height()));
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
-        let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
+        let result = self.atomic_finalize(state, ratifications, solutions, transactions);
+        // Stop recording the previous values of updated keys.
+        self.finalize_store().set_history_height(None);
+        drop(atomic_lock);
+        let ratified_finalize_operations = result?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
@@ -206,7 +218,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///   - If `coinbase_reward = Some(coinbase_reward)`, then the method will append a
     ///     `Ratify::BlockReward(block_reward)` and `Ratify::PuzzleReward(puzzle_reward)`
     ///     to the front of the `ratifications` list.
-    fn atomic_speculate<'a>(
+    pub(super) fn atomic_speculate<'a>(
         &self,
         state: FinalizeGlobalState,
         coinbase_reward: Option<u64>,
@@ -566,6 +578,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Performs atomic finalization over a list of transactions.
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
+    /// Note: The caller must hold the atomic lock for the entire scope of this call.
     #[inline]
     fn atomic_finalize(
         &self,
@@ -574,11 +587,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         solutions: &Solutions<N>,
         transactions: &Transactions<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
-        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
-        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
-        // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
-        let _atomic_lock = self.atomic_lock.lock();
-
         let timer = timer!("VM::atomic_finalize");
 
         // Perform the finalize operation on the preset finalize mode.
@@ -790,6 +798,27 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 Err(e) => return Err(format!("Failed to post-ratify - {e}")),
             }
 
+            // Store the ratified finalize operations, which are needed to prove the finalize root of the block.
+            if let Err(e) = store.insert_ratified_operations(state.block_height(), &ratified_finalize_operations) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to store the ratified finalize operations - {e}"));
+            }
+            // Mark the history of the block as recorded.
+            if let Err(e) = store.insert_history_height(state.block_height()) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to record the finalize history - {e}"));
+            }
+            // Discard the history of the blocks beyond the history depth, or beyond the pruning depth if lower,
+            // as the finalize history is retained for at most the same blocks as the block proofs.
+            let history_depth = store.history_depth();
+            let depth = self.block_store().pruning_depth().map_or(history_depth, |depth| depth.min(history_depth));
+            if let Some(height) = state.block_height().checked_sub(depth) {
+                if let Err(e) = store.prune_history(height) {
+                    // Note: This will abort the entire atomic batch.
+                    return Err(format!("Failed to prune the finalize history - {e}"));
+                }
+            }
+
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
@@ -66,6 +66,22 @@ macro_rules! process {
                 // Process the logic.
                 $logic!(process.read(), console::network::MainnetV0, circuit::AleoV0)
             }
+            console::network::TestnetV0::ID => {
+                // Cast the process.
+                let process = (&$self.process as &dyn std::any::Any)
+                    .downcast_ref::<Arc<RwLock<Process<console::network::TestnetV0>>>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast {}", stringify!($self.process)))?;
+                // Process the logic.
+                $logic!(process.read(), console::network::TestnetV0, circuit::AleoTestnetV0)
+            }
+            console::network::DevnetV0::ID => {
+                // Cast the process.
+                let process = (&$self.process as &dyn std::any::Any)
+                    .downcast_ref::<Arc<RwLock<Process<console::network::DevnetV0>>>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast {}", stringify!($self.process)))?;
+                // Process the logic.
+                $logic!(process.read(), console::network::DevnetV0, circuit::AleoDevnetV0)
+            }
             _ => bail!("Unsupported VM configuration for network: {}", N::ID),
         }
     }};
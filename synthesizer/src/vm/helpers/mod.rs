@@ -17,5 +17,8 @@ pub use committee::*;
 
 mod macros;
 
+mod replay;
+pub use replay::*;
+
 mod rewards;
 pub use rewards::*;
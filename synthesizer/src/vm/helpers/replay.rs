@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+use ledger_block::{ConfirmedTransaction, Ratifications};
+
+/// A report of the divergences found when replaying a stored block against its parent state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayReport<N: Network> {
+    /// The height of the replayed block.
+    height: u32,
+    /// The hash of the replayed block.
+    block_hash: N::BlockHash,
+    /// The transactions that failed re-verification, along with the error.
+    invalid_transactions: Vec<(N::TransactionID, String)>,
+    /// The stored transactions whose replayed outcome diverged, along with the replayed outcome (if any).
+    diverged_transactions: Vec<(ConfirmedTransaction<N>, Option<ConfirmedTransaction<N>>)>,
+    /// The transactions that were aborted during the replay, along with the reason.
+    aborted_transactions: Vec<(N::TransactionID, String)>,
+    /// The stored aborted transaction IDs that were confirmed during the replay.
+    diverged_aborted_transaction_ids: Vec<N::TransactionID>,
+    /// The replayed ratifications, if they diverged from the stored ratifications.
+    diverged_ratifications: Option<Ratifications<N>>,
+    /// The stored and replayed finalize roots, if they diverged.
+    diverged_finalize_root: Option<(Field<N>, Field<N>)>,
+}
+
+impl<N: Network> ReplayReport<N> {
+    /// Initializes a new replay report.
+    pub(crate) fn new(
+        height: u32,
+        block_hash: N::BlockHash,
+        invalid_transactions: Vec<(N::TransactionID, String)>,
+        diverged_transactions: Vec<(ConfirmedTransaction<N>, Option<ConfirmedTransaction<N>>)>,
+        aborted_transactions: Vec<(N::TransactionID, String)>,
+        diverged_aborted_transaction_ids: Vec<N::TransactionID>,
+        diverged_ratifications: Option<Ratifications<N>>,
+        diverged_finalize_root: Option<(Field<N>, Field<N>)>,
+    ) -> Self {
+        Self {
+            height,
+            block_hash,
+            invalid_transactions,
+            diverged_transactions,
+            aborted_transactions,
+            diverged_aborted_transaction_ids,
+            diverged_ratifications,
+            diverged_finalize_root,
+        }
+    }
+
+    /// Returns the height of the replayed block.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the hash of the replayed block.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the transactions that failed re-verification, along with the error.
+    pub fn invalid_transactions(&self) -> &[(N::TransactionID, String)] {
+        &self.invalid_transactions
+    }
+
+    /// Returns the stored transactions whose replayed outcome diverged, along with the replayed outcome (if any).
+    pub fn diverged_transactions(&self) -> &[(ConfirmedTransaction<N>, Option<ConfirmedTransaction<N>>)] {
+        &self.diverged_transactions
+    }
+
+    /// Returns the transactions that were aborted during the replay, along with the reason.
+    pub fn aborted_transactions(&self) -> &[(N::TransactionID, String)] {
+        &self.aborted_transactions
+    }
+
+    /// Returns the stored aborted transaction IDs that were confirmed during the replay.
+    pub fn diverged_aborted_transaction_ids(&self) -> &[N::TransactionID] {
+        &self.diverged_aborted_transaction_ids
+    }
+
+    /// Returns the replayed ratifications, if they diverged from the stored ratifications.
+    pub const fn diverged_ratifications(&self) -> Option<&Ratifications<N>> {
+        self.diverged_ratifications.as_ref()
+    }
+
+    /// Returns the stored and replayed finalize roots, if they diverged.
+    pub const fn diverged_finalize_root(&self) -> Option<(Field<N>, Field<N>)> {
+        self.diverged_finalize_root
+    }
+
+    /// Returns `true` if the replay matches the stored block.
+    pub fn is_consistent(&self) -> bool {
+        self.invalid_transactions.is_empty()
+            && self.diverged_transactions.is_empty()
+            && self.aborted_transactions.is_empty()
+            && self.diverged_aborted_transaction_ids.is_empty()
+            && self.diverged_ratifications.is_none()
+            && self.diverged_finalize_root.is_none()
+    }
+}
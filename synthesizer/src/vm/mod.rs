@@ -19,6 +19,7 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod replay;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
@@ -86,6 +87,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Initializes the VM from storage.
     #[inline]
     pub fn from(store: ConsensusStore<N, C>) -> Result<Self> {
+        // Set the synthesizer of the 'credits.aleo' keys, which is used if the network bundles no keys.
+        match N::ID {
+            console::network::MainnetV0::ID => {
+                Process::<console::network::MainnetV0>::set_credits_key_synthesizer::<circuit::AleoV0>()
+            }
+            console::network::TestnetV0::ID => {
+                Process::<console::network::TestnetV0>::set_credits_key_synthesizer::<circuit::AleoTestnetV0>()
+            }
+            console::network::DevnetV0::ID => {
+                Process::<console::network::DevnetV0>::set_credits_key_synthesizer::<circuit::AleoDevnetV0>()
+            }
+            _ => bail!("Unsupported VM configuration for network: {}", N::ID),
+        }
+
         // Initialize a new process.
         let mut process = Process::load()?;
 
@@ -366,7 +381,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         )?;
 
         // Pause the atomic writes, so that both the insertion and finalization belong to a single batch.
-        #[cfg(feature = "rocks")]
+        #[cfg(any(feature = "rocks", feature = "journal"))]
         self.block_store().pause_atomic_writes()?;
 
         // First, insert the block.
@@ -375,12 +390,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
             Ok(_ratified_finalize_operations) => {
                 // Unpause the atomic writes, executing the ones queued from block insertion and finalization.
-                #[cfg(feature = "rocks")]
+                #[cfg(any(feature = "rocks", feature = "journal"))]
                 self.block_store().unpause_atomic_writes::<false>()?;
                 Ok(())
             }
             Err(finalize_error) => {
-                if cfg!(feature = "rocks") {
+                if cfg!(any(feature = "rocks", feature = "journal")) {
                     // Clear all pending atomic operations so that unpausing the atomic writes
                     // doesn't execute any of the queued storage operations.
                     self.block_store().abort_atomic();
@@ -409,6 +424,79 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
     }
+
+    /// Reverts the VM to its state after the block at the given height, removing all later blocks.
+    ///
+    /// The blocks, their transactions, and the mapping state they finalized are reverted within a single atomic batch,
+    /// using the undo logs recorded by the finalize store when each block was added.
+    /// Programs deployed in the removed blocks are also removed from the process, only if the storage is reverted.
+    ///
+    /// Only the blocks within `FinalizeStore::history_range` can be removed; blocks finalized without an undo log
+    /// (e.g. prior to the undo logs being recorded), or whose undo log was pruned, cannot be reverted.
+    pub fn rollback_to(&self, height: u32) -> Result<()> {
+        // Acquire the block lock, which is needed to ensure this function is not called concurrently.
+        // Note: This lock must be held for the entire scope of this function.
+        let _block_lock = self.block_lock.lock();
+        // Acquire the atomic lock, to ensure no speculation is performed on the state being reverted.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(latest_height) => *latest_height,
+            None => bail!("Failed to rollback to block {height}: no blocks in storage"),
+        };
+        // Ensure the given height is not greater than the latest block height.
+        ensure!(height <= latest_height, "Failed to rollback to block {height}: the latest block is {latest_height}");
+        // If the given height is the latest block height, there is nothing to revert.
+        if height == latest_height {
+            return Ok(());
+        }
+
+        // Prepare the process without the programs deployed in the blocks to be removed.
+        // Note: The programs are removed from a copy of the process, so that the process is only updated
+        // once the storage is reverted, and the storage is only reverted if the programs can be removed.
+        let reverted_process = self.process_at_height(height)?;
+
+        // Remove the blocks, and revert the finalize state.
+        self.store.remove_last_n(latest_height - height)?;
+        // Update the process.
+        *self.process.write() = reverted_process;
+        Ok(())
+    }
+
+    /// Returns a copy of the process, without the programs deployed in the blocks after the given height.
+    /// Note: The programs are removed in the reverse order of deployment, so that no remaining program imports them.
+    fn process_at_height(&self, height: u32) -> Result<Process<N>> {
+        // Collect the programs deployed in the blocks after the given height.
+        let mut program_ids = Vec::new();
+        let latest_height = self.block_store().heights().max().map_or(height, |latest_height| *latest_height);
+        for block_height in height + 1..=latest_height {
+            // Retrieve the block hash.
+            let Some(block_hash) = self.block_store().get_block_hash(block_height)? else {
+                bail!("Missing block hash for block {block_height}")
+            };
+            // Retrieve the block transactions.
+            let Some(transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+                bail!("Missing transactions for block {block_height}")
+            };
+            for transaction in transactions.iter() {
+                if let ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) =
+                    transaction
+                {
+                    program_ids.push(*deployment.program_id());
+                }
+            }
+        }
+
+        // Remove the deployed programs from a copy of the process, in the reverse order of deployment.
+        let mut process = self.process.read().clone();
+        for program_id in program_ids.iter().rev() {
+            if process.contains_program(program_id) {
+                process.remove_stack(program_id)?;
+            }
+        }
+        Ok(process)
+    }
 }
 
 #[cfg(test)]
@@ -416,7 +504,7 @@ pub(crate) mod test_helpers {
     use super::*;
     use console::{
         account::{Address, ViewKey},
-        network::MainnetV0,
+        network::{DevnetV0, MainnetV0, TestnetV0},
         program::Value,
         types::Field,
     };
@@ -693,12 +781,12 @@ function compute:
             .clone()
     }
 
-    pub fn sample_next_block<R: Rng + CryptoRng>(
-        vm: &VM<MainnetV0, ConsensusMemory<MainnetV0>>,
-        private_key: &PrivateKey<MainnetV0>,
-        transactions: &[Transaction<MainnetV0>],
+    pub fn sample_next_block<N: Network, R: Rng + CryptoRng>(
+        vm: &VM<N, ConsensusMemory<N>>,
+        private_key: &PrivateKey<N>,
+        transactions: &[Transaction<N>],
         rng: &mut R,
-    ) -> Result<Block<MainnetV0>> {
+    ) -> Result<Block<N>> {
         // Get the most recent block.
         let block_hash =
             vm.block_store().get_block_hash(*vm.block_store().heights().max().unwrap().borrow()).unwrap().unwrap();
@@ -711,16 +799,16 @@ function compute:
 
         // Construct the metadata associated with the block.
         let metadata = Metadata::new(
-            MainnetV0::ID,
+            N::ID,
             previous_block.round() + 1,
             previous_block.height() + 1,
             0,
             0,
-            MainnetV0::GENESIS_COINBASE_TARGET,
-            MainnetV0::GENESIS_PROOF_TARGET,
+            N::GENESIS_COINBASE_TARGET,
+            N::GENESIS_PROOF_TARGET,
             previous_block.last_coinbase_target(),
             previous_block.last_coinbase_timestamp(),
-            MainnetV0::GENESIS_TIMESTAMP + 1,
+            N::GENESIS_TIMESTAMP + 1,
         )?;
 
         let header = Header::from(
@@ -1425,4 +1513,248 @@ finalize do:
         // Verify.
         vm.check_transaction(&transaction, None, rng).unwrap();
     }
+
+    #[test]
+    fn test_replay_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+
+        // Ensure the genesis block replays without divergence.
+        let report = vm.replay_block(0, rng).unwrap();
+        assert_eq!(report.height(), 0);
+        assert!(report.is_consistent(), "{report:?}");
+
+        // Add a block with an execution.
+        let transaction = sample_execution_transaction_with_public_fee(rng);
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction], rng).unwrap()).unwrap();
+
+        // Ensure the block replays without divergence.
+        let report = vm.replay_block(1, rng).unwrap();
+        assert_eq!(report.height(), 1);
+        assert_eq!(report.block_hash(), vm.block_store().get_block_hash(1).unwrap().unwrap());
+        assert!(report.is_consistent(), "{report:?}");
+
+        // Ensure the VM state was not modified by the replay.
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 1);
+
+        // Ensure a block that does not exist cannot be replayed.
+        assert!(vm.replay_block(2, rng).is_err());
+
+        // Add an empty block, and ensure the preceding blocks still replay against their parent state.
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[], rng).unwrap()).unwrap();
+        for height in 0..=2 {
+            let report = vm.replay_block(height, rng).unwrap();
+            assert!(report.is_consistent(), "{report:?}");
+            assert!(report.diverged_aborted_transaction_ids().is_empty());
+        }
+
+        // Ensure a block cannot be replayed once the history needed to reconstruct its parent state is pruned.
+        vm.finalize_store().prune_history(1).unwrap();
+        assert!(vm.replay_block(1, rng).is_err());
+        assert!(vm.replay_block(2, rng).unwrap().is_consistent());
+    }
+
+    #[test]
+    fn test_rollback_to() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Retrieve the finalize state checksum after the genesis block.
+        let checksum = vm.finalize_store().get_checksum_confirmed().unwrap();
+        // Retrieve the current committee after the genesis block.
+        let committee = vm.finalize_store().committee_store().current_committee().unwrap();
+
+        // Add a block with an execution.
+        let transaction = sample_execution_transaction_with_public_fee(rng);
+        let transaction_id = transaction.id();
+        let block = sample_next_block(&vm, &private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert_ne!(vm.finalize_store().get_checksum_confirmed().unwrap(), checksum);
+
+        // Ensure a rollback to a block that does not exist fails.
+        assert!(vm.rollback_to(2).is_err());
+
+        // Rollback to the genesis block.
+        vm.rollback_to(0).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 0);
+        assert!(!vm.transaction_store().contains_transaction_id(&transaction_id).unwrap());
+        assert_eq!(vm.finalize_store().get_checksum_confirmed().unwrap(), checksum);
+        assert_eq!(vm.finalize_store().committee_store().current_committee().unwrap(), committee);
+
+        // Ensure the block can be added again.
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 1);
+
+        // Add a block with pruning enabled, which discards the finalize history of the previous blocks.
+        vm.block_store().set_pruning_depth(Some(1)).unwrap();
+        let block = sample_next_block(&vm, &private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(vm.finalize_store().history_range().unwrap(), Some(2..=2));
+
+        // Ensure a rollback beyond the recorded finalize history fails, and leaves the VM unchanged.
+        let checksum = vm.finalize_store().get_checksum_confirmed().unwrap();
+        assert!(vm.rollback_to(0).is_err());
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 2);
+        assert!(vm.transaction_store().contains_transaction_id(&transaction_id).unwrap());
+        assert_eq!(vm.finalize_store().get_checksum_confirmed().unwrap(), checksum);
+
+        // Ensure a rollback within the recorded finalize history succeeds.
+        vm.rollback_to(1).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_history_depth() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM with the genesis block, and ensure the finalize history is bounded by default.
+        let vm = sample_vm_with_genesis_block(rng);
+        assert_eq!(vm.finalize_store().history_depth(), ledger_store::DEFAULT_HISTORY_DEPTH);
+        assert_eq!(vm.finalize_store().history_range().unwrap(), Some(0..=0));
+        // Ensure the history of the latest block is always retained.
+        assert!(vm.finalize_store().set_history_depth(0).is_err());
+
+        // Retain the history of the 2 latest blocks, and add 3 blocks.
+        vm.finalize_store().set_history_depth(2).unwrap();
+        for _ in 0..3 {
+            let block = sample_next_block(&vm, &private_key, &[], rng).unwrap();
+            vm.add_next_block(&block).unwrap();
+        }
+        assert_eq!(vm.finalize_store().history_range().unwrap(), Some(2..=3));
+        // Ensure no history height remains set once the blocks are finalized.
+        assert_eq!(vm.finalize_store().history_height(), None);
+
+        // Ensure the blocks can only be reverted within the history depth.
+        assert!(vm.rollback_to(0).is_err());
+        vm.rollback_to(1).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_auto_prune() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM with the genesis block, and enable pruning.
+        let vm = sample_vm_with_genesis_block(rng);
+        vm.block_store().set_pruning_depth(Some(1)).unwrap();
+        assert_eq!(vm.block_store().pruning_depth(), Some(1));
+
+        // Add a block with an execution, which prunes the genesis block.
+        let transaction = sample_execution_transaction_with_public_fee(rng);
+        let transaction_id = transaction.id();
+        let block_1 = sample_next_block(&vm, &private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block_1).unwrap();
+        let genesis_hash = vm.block_store().get_block_hash(0).unwrap().unwrap();
+        assert!(vm.block_store().is_pruned(&genesis_hash).unwrap());
+        assert!(!vm.block_store().is_pruned(&block_1.hash()).unwrap());
+        assert_eq!(vm.block_store().get_block(&block_1.hash()).unwrap(), Some(block_1.clone()));
+
+        // Add an empty block, which prunes the block with the execution.
+        let block_2 = sample_next_block(&vm, &private_key, &[], rng).unwrap();
+        vm.add_next_block(&block_2).unwrap();
+        assert!(vm.block_store().is_pruned(&block_1.hash()).unwrap());
+        assert!(!vm.block_store().is_pruned(&block_2.hash()).unwrap());
+
+        // Ensure the pruned block fails clearly, while its transaction is retained without its proof.
+        let error = vm.block_store().get_block(&block_1.hash()).unwrap_err();
+        assert!(error.to_string().contains("has been pruned"), "{error}");
+        let transaction = vm.block_store().get_transaction(&transaction_id).unwrap().unwrap();
+        assert!(transaction.execution().unwrap().proof().is_none());
+        assert_eq!(vm.block_store().get_block(&block_2.hash()).unwrap(), Some(block_2));
+
+        // Ensure disabling pruning stops pruning the subsequent blocks.
+        vm.block_store().set_pruning_depth(None).unwrap();
+        let block_3 = sample_next_block(&vm, &private_key, &[], rng).unwrap();
+        vm.add_next_block(&block_3).unwrap();
+        assert!(!vm.block_store().is_pruned(&vm.block_store().get_block_hash(2).unwrap().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_check_integrity() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM over a consensus store, and add the genesis block.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        let vm = VM::from(store.clone()).unwrap();
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+
+        // Add a block with an execution.
+        let transaction = sample_execution_transaction_with_public_fee(rng);
+        let block = sample_next_block(&vm, &private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the stored finalize operations of every block match the finalize roots of their headers.
+        let report = store.check_integrity(false, true).unwrap();
+        assert!(report.is_ok(), "{report}");
+    }
+
+    #[test]
+    fn test_execute_and_verify_on_each_network() {
+        fn check_execute_and_verify<N: Network>() {
+            let rng = &mut TestRng::default();
+
+            // Initialize a new VM on the network.
+            let vm = VM::<N, ConsensusMemory<N>>::from(ConsensusStore::open(None).unwrap()).unwrap();
+
+            // Initialize the genesis block, and add it to the VM.
+            let private_key = PrivateKey::<N>::new(rng).unwrap();
+            let view_key = ViewKey::try_from(&private_key).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
+            let genesis = vm.genesis_beacon(&private_key, rng).unwrap();
+            vm.add_next_block(&genesis).unwrap();
+
+            // Select a record to spend, so the private transfer proves its inclusion in the genesis block.
+            let record = genesis
+                .transitions()
+                .cloned()
+                .flat_map(Transition::into_records)
+                .find_map(|(_, record)| record.decrypt(&view_key).ok())
+                .unwrap();
+
+            // Execute a public transfer, with a public fee.
+            let inputs = [Value::<N>::from_str(&address.to_string()).unwrap(), Value::<N>::from_str("1u64").unwrap()];
+            let function = ("credits.aleo", "transfer_public");
+            let public_transaction =
+                vm.execute(&private_key, function, inputs.into_iter(), None, 0, None, rng).unwrap();
+            vm.check_transaction(&public_transaction, None, rng).unwrap();
+
+            // Execute a private transfer, with a public fee.
+            let inputs = [
+                Value::<N>::Record(record),
+                Value::<N>::from_str(&address.to_string()).unwrap(),
+                Value::<N>::from_str("1u64").unwrap(),
+            ];
+            let function = ("credits.aleo", "transfer_private");
+            let private_transaction =
+                vm.execute(&private_key, function, inputs.into_iter(), None, 0, None, rng).unwrap();
+            vm.check_transaction(&private_transaction, None, rng).unwrap();
+
+            // Ensure both transactions are accepted in the next block.
+            let block = sample_next_block(&vm, &private_key, &[public_transaction, private_transaction], rng).unwrap();
+            assert_eq!(block.transactions().num_accepted(), 2);
+            vm.add_next_block(&block).unwrap();
+        }
+
+        check_execute_and_verify::<MainnetV0>();
+        check_execute_and_verify::<TestnetV0>();
+        check_execute_and_verify::<DevnetV0>();
+    }
 }
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::helpers::memory::ConsensusMemory;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Replays the stored block at the given height, and returns a report of any divergence from the stored block.
+    ///
+    /// The parent state is reconstructed in a scratch (in-memory) VM, by exporting the finalize state as of the
+    /// parent block (see `FinalizeStore::export_state`), and the process without the programs deployed since.
+    /// Every transaction in the block is then re-verified, and the ratifications, solutions, and transactions
+    /// are re-speculated against the parent state. The VM storage is not modified.
+    ///
+    /// Only the blocks within `FinalizeStore::history_range` (and the block after the latest block in it)
+    /// can be replayed, as the history of the blocks after the parent block is needed to reconstruct its state.
+    ///
+    /// Note: The scratch VM does not hold the preceding blocks. As such, the global state roots referenced by
+    /// the transactions are checked against the preceding blocks in storage, however the uniqueness of the
+    /// transactions against the preceding blocks is not re-checked. The aborted transactions are not stored,
+    /// and are not replayed; rather, the stored aborted transaction IDs are checked against the replay.
+    pub fn replay_block<R: Rng + CryptoRng>(&self, height: u32, rng: &mut R) -> Result<ReplayReport<N>> {
+        let timer = timer!("VM::replay_block");
+
+        // Retrieve the block to replay.
+        let block = match self.block_store().get_block_hash(height)? {
+            Some(block_hash) => match self.block_store().get_block(&block_hash)? {
+                Some(block) => block,
+                None => bail!("Block {height} ('{block_hash}') does not exist in storage"),
+            },
+            None => bail!("Block {height} does not exist in storage"),
+        };
+
+        // Initialize a scratch VM, with the state as of the parent block.
+        let scratch = {
+            // Acquire the block lock, to ensure no block is added while the parent state is reconstructed.
+            let _block_lock = self.block_lock.lock();
+
+            // Initialize a scratch store.
+            let store = ConsensusStore::<N, ConsensusMemory<N>>::open(None)?;
+            // Export the finalize state as of the parent block.
+            // Note: The genesis block is replayed against an empty state.
+            if let Some(parent_height) = height.checked_sub(1) {
+                self.finalize_store()
+                    .export_state(parent_height, store.finalize_store())
+                    .map_err(|e| anyhow!("Failed to reconstruct the parent state of block {height} - {e}"))?;
+            }
+            // Initialize the scratch VM.
+            let scratch = VM::from(store)?;
+            // Set the process as of the parent block.
+            if let Some(parent_height) = height.checked_sub(1) {
+                *scratch.process.write() = self.process_at_height(parent_height)?;
+            }
+            scratch
+        };
+        lap!(timer, "Reconstruct the parent state");
+
+        // Construct the finalize state.
+        let state = FinalizeGlobalState::new::<N>(
+            block.round(),
+            block.height(),
+            block.cumulative_weight(),
+            block.cumulative_proof_target(),
+            block.previous_hash(),
+        )?;
+
+        // Retrieve the stored transactions.
+        let transactions = block.transactions();
+
+        // Prepare a closure to determine if the given state root is the state root of a preceding block.
+        let contains_state_root = |state_root: &N::StateRoot| -> Result<bool> {
+            Ok(matches!(
+                self.block_store().find_block_height_from_state_root(*state_root)?,
+                Some(root_height) if root_height < height
+            ))
+        };
+
+        // Re-verify the transactions.
+        // Note: The genesis block transactions are not verified, to match the behavior of `VM::speculate`.
+        let invalid_transactions = match block.is_genesis() {
+            true => vec![],
+            false => {
+                let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+                cfg_iter!(transactions)
+                    .zip(rngs)
+                    .filter_map(|(transaction, mut rng)| {
+                        transaction
+                            .to_rejected_id()
+                            .and_then(|rejected_id| {
+                                scratch.check_transaction_against(
+                                    transaction,
+                                    rejected_id,
+                                    height,
+                                    contains_state_root,
+                                    &mut rng,
+                                )
+                            })
+                            .err()
+                            .map(|error| (transaction.id(), error.to_string()))
+                    })
+                    .collect::<Vec<_>>()
+            }
+        };
+        lap!(timer, "Re-verify the transactions");
+
+        // Reconstruct the candidate ratifications.
+        let candidate_ratifications = block.ratifications().iter().cloned().collect::<Vec<_>>();
+        // Reconstruct the unconfirmed transactions.
+        let candidate_transactions =
+            transactions.iter().map(|confirmed| confirmed.to_unconfirmed_transaction()).collect::<Result<Vec<_>>>()?;
+
+        // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
+        let (ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations) = scratch
+            .atomic_speculate(state, None, candidate_ratifications, block.solutions(), candidate_transactions.iter())?;
+        lap!(timer, "Re-speculate the block");
+
+        // Determine if the ratifications diverged.
+        let diverged_ratifications = match &ratifications == block.ratifications() {
+            true => None,
+            false => Some(ratifications),
+        };
+
+        // Index the replayed transactions by their unconfirmed transaction ID.
+        let replayed_transactions = confirmed_transactions
+            .iter()
+            .map(|confirmed| Ok((confirmed.to_unconfirmed_transaction_id()?, confirmed)))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        // Determine the stored transactions that diverged, along with their replayed outcome.
+        let mut diverged_transactions = Vec::new();
+        for expected in transactions.iter() {
+            let candidate = replayed_transactions.get(&expected.to_unconfirmed_transaction_id()?).copied();
+            if candidate != Some(expected) {
+                diverged_transactions.push((expected.clone(), candidate.cloned()));
+            }
+        }
+
+        // Collect the transactions that were aborted during the replay.
+        let aborted_transactions =
+            aborted_transactions.into_iter().map(|(transaction, error)| (transaction.id(), error)).collect();
+        // Collect the stored aborted transaction IDs that were confirmed during the replay.
+        let diverged_aborted_transaction_ids = block
+            .aborted_transaction_ids()
+            .iter()
+            .filter(|transaction_id| replayed_transactions.contains_key(*transaction_id))
+            .copied()
+            .collect();
+
+        // Determine if the finalize root diverged.
+        let replayed_transactions: Transactions<N> = confirmed_transactions.into_iter().collect();
+        let finalize_root = replayed_transactions.to_finalize_root(ratified_finalize_operations)?;
+        let diverged_finalize_root = match finalize_root == block.finalize_root() {
+            true => None,
+            false => Some((block.finalize_root(), finalize_root)),
+        };
+        finish!(timer, "Compare the replayed block");
+
+        Ok(ReplayReport::new(
+            height,
+            block.hash(),
+            invalid_transactions,
+            diverged_transactions,
+            aborted_transactions,
+            diverged_aborted_transaction_ids,
+            diverged_ratifications,
+            diverged_finalize_root,
+        ))
+    }
+}
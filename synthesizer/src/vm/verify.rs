@@ -39,6 +39,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         transaction: &Transaction<N>,
         rejected_id: Option<Field<N>>,
         rng: &mut R,
+    ) -> Result<()> {
+        // Determine the height of the next block.
+        let block_height = self.block_store().heights().max().map_or(0, |latest_height| *latest_height + 1);
+        // Verify the transaction for the next block.
+        self.check_transaction_against(
+            transaction,
+            rejected_id,
+            block_height,
+            |state_root| self.block_store().contains_state_root(state_root),
+            rng,
+        )
+    }
+
+    /// Verifies the transaction in the VM, for inclusion in the block at the given `block_height`,
+    /// where the given `contains_state_root` closure determines which global state roots may be referenced
+    /// by the transaction. On failure, returns an error.
+    #[inline]
+    pub(crate) fn check_transaction_against<R: CryptoRng + Rng, F: Fn(&N::StateRoot) -> Result<bool>>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        block_height: u32,
+        contains_state_root: F,
+        rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
@@ -93,7 +117,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         lap!(timer, "Check for duplicate elements");
 
         // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        self.check_fee_against(transaction, rejected_id, &contains_state_root)?;
 
         // Check if the transaction exists in the partially-verified cache.
         let is_partially_verified = self.partially_verified_transactions.read().peek(&transaction.id()).is_some();
@@ -134,7 +158,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
                 // Verify the execution.
-                self.check_execution_internal(execution, is_partially_verified)?;
+                // Note: A bundled execution is only valid from `N::BUNDLED_EXECUTION_HEIGHT`, which is enforced by
+                // `Process::verify_execution`, and by `Process::finalize_execution` (if it was partially verified).
+                self.check_execution_internal(execution, block_height, is_partially_verified, &contains_state_root)?;
             }
             Transaction::Fee(..) => { /* no-op */ }
         }
@@ -150,8 +176,25 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
+    ///
+    /// Note: The fee payer is not required to be the signer of the deployment or execution (i.e. a sponsored fee).
+    /// The fee is bound to the transaction by the deployment or execution ID, which is an input to the fee.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_fee_against(transaction, rejected_id, &|state_root| {
+            self.block_store().contains_state_root(state_root)
+        })
+    }
+
+    /// Verifies the `fee` in the given transaction, where the given `contains_state_root` closure determines
+    /// which global state roots may be referenced by the fee. On failure, returns an error.
+    #[inline]
+    fn check_fee_against<F: Fn(&N::StateRoot) -> Result<bool>>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        contains_state_root: &F,
+    ) -> Result<()> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -167,7 +210,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
                 }
                 // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                self.check_fee_internal(fee, deployment_id, contains_state_root)?;
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -195,7 +238,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
                     // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
+                    // Note: The fee may be signed by a different caller than the execution (i.e. a sponsored fee),
+                    // in which case the fee is bound to the execution solely by the execution ID.
+                    self.check_fee_internal(fee, execution_id, contains_state_root)?;
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
@@ -207,7 +252,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Transaction::Fee(id, fee) => {
                 // Verify the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
+                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id, contains_state_root)?,
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
@@ -244,20 +289,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Note: This is an internal check only. To ensure all components of the execution are checked,
     /// use `VM::check_transaction` instead.
     #[inline]
-    fn check_execution_internal(&self, execution: &Execution<N>, is_partially_verified: bool) -> Result<()> {
+    fn check_execution_internal<F: Fn(&N::StateRoot) -> Result<bool>>(
+        &self,
+        execution: &Execution<N>,
+        block_height: u32,
+        is_partially_verified: bool,
+        contains_state_root: &F,
+    ) -> Result<()> {
         let timer = timer!("VM::check_execution");
 
         // Verify the execution proof, if it has not been partially-verified before.
         let verification = match is_partially_verified {
             true => Ok(()),
-            false => self.process.read().verify_execution(execution),
+            false => self.process.read().verify_execution(execution, block_height),
         };
         lap!(timer, "Verify the execution");
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
+            Ok(()) => match contains_state_root(&execution.global_state_root()) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Execution verification failed - global state root does not exist (yet)"),
                 Err(error) => bail!("Execution verification failed - {error}"),
@@ -273,7 +324,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal<F: Fn(&N::StateRoot) -> Result<bool>>(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        contains_state_root: &F,
+    ) -> Result<()> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
@@ -308,7 +364,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
+            Ok(()) => match contains_state_root(&fee.global_state_root()) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Fee verification failed: global state root not found"),
                 Err(error) => bail!("Fee verification failed: {error}"),
@@ -378,6 +434,8 @@ mod tests {
     fn test_verify_execution() {
         let rng = &mut TestRng::default();
         let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let contains_state_root =
+            |state_root: &<CurrentNetwork as Network>::StateRoot| vm.block_store().contains_state_root(state_root);
 
         // Fetch execution transactions.
         let transactions = [
@@ -391,13 +449,13 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(execution.proof().is_some());
                     // Verify the execution.
-                    vm.check_execution_internal(&execution, false).unwrap();
+                    vm.check_execution_internal(&execution, 0, false, &contains_state_root).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_execution = execution.to_string();
                     let recovered_execution: Execution<CurrentNetwork> =
                         serde_json::from_str(&serialized_execution).unwrap();
-                    vm.check_execution_internal(&recovered_execution, false).unwrap();
+                    vm.check_execution_internal(&recovered_execution, 0, false, &contains_state_root).unwrap();
                 }
                 _ => panic!("Expected an execution transaction"),
             }
@@ -408,6 +466,8 @@ mod tests {
     fn test_verify_fee() {
         let rng = &mut TestRng::default();
         let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let contains_state_root =
+            |state_root: &<CurrentNetwork as Network>::StateRoot| vm.block_store().contains_state_root(state_root);
 
         // Fetch execution transactions.
         let transactions = [
@@ -423,12 +483,12 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    vm.check_fee_internal(&fee, execution_id).unwrap();
+                    vm.check_fee_internal(&fee, execution_id, &contains_state_root).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    vm.check_fee_internal(&recovered_fee, execution_id).unwrap();
+                    vm.check_fee_internal(&recovered_fee, execution_id, &contains_state_root).unwrap();
                 }
                 _ => panic!("Expected an execution with a fee"),
             }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::cli::commands::{Build, Clean, Devnode, Execute, New, Run, Update, VerifyStorage};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -42,6 +42,8 @@ pub enum Command {
     Build(Build),
     #[clap(name = "clean")]
     Clean(Clean),
+    #[clap(name = "devnode")]
+    Devnode(Devnode),
     #[clap(name = "execute")]
     Execute(Execute),
     #[clap(name = "new")]
@@ -50,6 +52,8 @@ pub enum Command {
     Run(Run),
     #[clap(name = "update")]
     Update(Update),
+    #[clap(name = "verify-storage")]
+    VerifyStorage(VerifyStorage),
 }
 
 impl Command {
@@ -58,10 +62,12 @@ impl Command {
         match self {
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
+            Self::Devnode(command) => command.parse(),
             Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Update(command) => command.parse(),
+            Self::VerifyStorage(command) => command.parse(),
         }
     }
 }
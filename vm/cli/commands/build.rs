@@ -28,16 +28,22 @@ pub struct Build {
 impl Build {
     /// Compiles an Aleo program with the specified name.
     pub fn parse(self) -> Result<String> {
+        // Load the network ID, and build the program on its network.
+        with_network!(crate::cli::helpers::dotenv_network_id()?, |N, A| self.build::<N, A>())
+    }
+
+    /// Builds the program on the given network.
+    fn build<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
         // Load the package.
-        let package = Package::open(&path)?;
+        let package = Package::<N>::open(&path)?;
 
         println!("⚠️  Attention - This command is deprecated. Use the {} command.\n", "'run'".to_string().bold());
 
         // Build the package, if the package requires building.
-        package.build::<Aleo>(self.endpoint)?;
+        package.build::<A>(self.endpoint)?;
 
         // package.build::<Aleo>(match self.offline {
         //     true => None,
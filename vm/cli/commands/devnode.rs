@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::account::{Address, PrivateKey},
+    devnode::{BlockProduction, DevNode, DevNodeConfig},
+    ledger::{block::Block, store::helpers::memory::ConsensusMemory},
+};
+
+use aleo_std::StorageMode;
+use std::{net::SocketAddr, time::Duration};
+
+/// Starts a local development chain, which serves the REST API until it is interrupted,
+/// or performs a chain operation on a running one.
+#[derive(Debug, Parser)]
+pub struct Devnode {
+    /// The chain operation to perform on the running devnode, instead of starting one.
+    #[clap(subcommand)]
+    command: Option<DevnodeCommand>,
+    /// The network of the chain (`mainnet`, `testnet`, or `devnet`).
+    #[clap(default_value = "devnet", long)]
+    network: String,
+    /// The private key of the validator that signs the blocks (a new one is sampled, if not given).
+    #[clap(long)]
+    private_key: Option<String>,
+    /// The address of the REST server.
+    #[clap(default_value = "127.0.0.1:3030", long)]
+    rest: SocketAddr,
+    /// The interval between blocks in seconds (if 0, a block is produced for each broadcast transaction).
+    #[clap(default_value = "0", long)]
+    block_time: u64,
+}
+
+/// The chain operations of a running devnode, which are performed over its REST API.
+#[derive(Debug, Parser)]
+pub enum DevnodeCommand {
+    /// Produces empty blocks on the running devnode.
+    Mine {
+        /// The number of empty blocks.
+        #[clap(default_value = "1", long)]
+        blocks: u32,
+    },
+    /// Resets the chain of the running devnode to its state after the block at the given height.
+    Reset {
+        /// The height of the block (the genesis block, if not given).
+        #[clap(default_value = "0", long)]
+        height: u32,
+    },
+}
+
+impl Devnode {
+    /// Starts the development chain.
+    pub fn parse(self) -> Result<String> {
+        match &self.command {
+            Some(command) => with_network!(network_id(&self.network)?, |N, _A| self.request::<N>(command)),
+            None => with_network!(network_id(&self.network)?, |N, _A| self.start::<N>()),
+        }
+    }
+
+    /// Performs the given chain operation on the devnode that serves the REST API at the given address.
+    fn request<N: Network>(&self, command: &DevnodeCommand) -> Result<String> {
+        let url = format!("http://{}/{}/devnode", self.rest, N::SHORT_NAME);
+        match command {
+            DevnodeCommand::Mine { blocks } => {
+                let block: Block<N> = post(&format!("{url}/mine/{blocks}"))?.into_json()?;
+                Ok(format!("⛏  Mined {blocks} empty blocks (the latest height is {})", block.height()))
+            }
+            DevnodeCommand::Reset { height } => {
+                post(&format!("{url}/reset/{height}"))?;
+                Ok(format!("⏪ Reset the devnode to height {height}"))
+            }
+        }
+    }
+
+    /// Starts the development chain on the given network.
+    fn start<N: Network>(&self) -> Result<String> {
+        let rng = &mut rand::thread_rng();
+
+        // Load the private key, or sample a new one.
+        let private_key = match &self.private_key {
+            Some(private_key) => PrivateKey::<N>::from_str(private_key)?,
+            None => PrivateKey::<N>::new(rng)?,
+        };
+        // Prepare the configuration.
+        let block_production = match self.block_time {
+            0 => BlockProduction::OnTransaction,
+            seconds => BlockProduction::Interval(Duration::from_secs(seconds)),
+        };
+        let config = DevNodeConfig { block_production, rest_address: Some(self.rest), ..Default::default() };
+
+        // Start the devnode.
+        let devnode = DevNode::<N, ConsensusMemory<N>>::new(private_key, StorageMode::Production, config, rng)?;
+
+        println!("⛓  Started the {} devnode\n", self.network.bold());
+        println!(" •  REST API:    {}/{}", devnode.rest_url().unwrap_or_default(), N::SHORT_NAME);
+        println!(" •  Validator:   {}", Address::try_from(&private_key)?);
+        println!(" •  Private key: {private_key}\n");
+
+        // Serve the chain until the process is interrupted.
+        loop {
+            std::thread::park();
+        }
+    }
+}
+
+/// Performs a `POST` request without a body to the given URL, and returns the response.
+fn post(url: &str) -> Result<ureq::Response> {
+    match ureq::post(url).call() {
+        Ok(response) => Ok(response),
+        Err(ureq::Error::Status(status, response)) => {
+            anyhow::bail!("The devnode responded with status {status} - {}", response.into_string()?)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_devnode_unknown_network() {
+        let command = Devnode::try_parse_from(["snarkvm", "--network", "unknown"]).unwrap();
+        assert!(command.parse().unwrap_err().to_string().contains("Unsupported network 'unknown'"));
+    }
+
+    #[test]
+    fn test_devnode_invalid_private_key() {
+        let command = Devnode::try_parse_from(["snarkvm", "--private-key", "invalid"]).unwrap();
+        assert!(command.parse().is_err());
+    }
+
+    #[test]
+    fn test_devnode_commands() {
+        let command = Devnode::try_parse_from(["snarkvm", "mine", "--blocks", "3"]).unwrap();
+        assert!(matches!(command.command, Some(DevnodeCommand::Mine { blocks: 3 })));
+        let command = Devnode::try_parse_from(["snarkvm", "reset"]).unwrap();
+        assert!(matches!(command.command, Some(DevnodeCommand::Reset { height: 0 })));
+
+        // Ensure the chain operations fail without a running devnode.
+        let command = Devnode::try_parse_from(["snarkvm", "--rest", "127.0.0.1:1", "reset"]).unwrap();
+        assert!(command.parse().is_err());
+    }
+}
@@ -18,6 +18,9 @@ pub use build::*;
 pub mod clean;
 pub use clean::*;
 
+pub mod devnode;
+pub use devnode::*;
+
 pub mod execute;
 pub use execute::*;
 
@@ -30,8 +33,14 @@ pub use run::*;
 pub mod update;
 pub use update::*;
 
+pub mod verify_storage;
+pub use verify_storage::*;
+
 use crate::{
-    console::program::{Identifier, Locator, ProgramID, Value},
+    console::{
+        network::Network,
+        program::{Identifier, Locator, ProgramID, Value},
+    },
     ledger::block::Transaction,
     package::Package,
 };
@@ -45,4 +54,41 @@ use std::collections::HashMap;
 pub const LOCALE: &num_format::Locale = &num_format::Locale::en;
 
 pub(crate) type CurrentNetwork = crate::prelude::MainnetV0;
-pub(crate) type Aleo = crate::circuit::AleoV0;
+
+/// A helper macro to process the given logic with the network (and its circuit environment) of the given network ID.
+macro_rules! with_network {
+    // Example: with_network!(network_id, |N, A| self.run::<N, A>())
+    ($network_id:expr, |$network:ident, $aleo:ident| $logic:expr) => {{
+        match $network_id {
+            <crate::prelude::MainnetV0 as crate::prelude::Network>::ID => {
+                type $network = crate::prelude::MainnetV0;
+                type $aleo = crate::circuit::AleoV0;
+                $logic
+            }
+            <crate::prelude::TestnetV0 as crate::prelude::Network>::ID => {
+                type $network = crate::prelude::TestnetV0;
+                type $aleo = crate::circuit::AleoTestnetV0;
+                $logic
+            }
+            <crate::prelude::DevnetV0 as crate::prelude::Network>::ID => {
+                type $network = crate::prelude::DevnetV0;
+                type $aleo = crate::circuit::AleoDevnetV0;
+                $logic
+            }
+            network_id => anyhow::bail!("Unsupported network ID {network_id}"),
+        }
+    }};
+}
+pub(crate) use with_network;
+
+/// Returns the network ID for the given short network name (e.g. `mainnet`).
+pub(crate) fn network_id(name: &str) -> Result<u16> {
+    use crate::prelude::{DevnetV0, MainnetV0, Network, TestnetV0};
+
+    match name {
+        MainnetV0::SHORT_NAME => Ok(MainnetV0::ID),
+        TestnetV0::SHORT_NAME => Ok(TestnetV0::ID),
+        DevnetV0::SHORT_NAME => Ok(DevnetV0::ID),
+        _ => anyhow::bail!("Unsupported network '{name}' (expected 'mainnet', 'testnet', or 'devnet')"),
+    }
+}
@@ -25,21 +25,31 @@ pub struct Run {
 
 impl Run {
     /// Compiles an Aleo program function with the specified name.
-    #[allow(clippy::format_in_format_args)]
     pub fn parse(self) -> Result<String> {
+        // Load the network ID, and run the function on its network.
+        with_network!(crate::cli::helpers::dotenv_network_id()?, |N, A| self.run::<N, A>())
+    }
+
+    /// Runs the function on the given network.
+    #[allow(clippy::format_in_format_args)]
+    fn run<N: Network, A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(self) -> Result<String> {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
         // Load the package.
-        let package = Package::open(&path)?;
+        let package = Package::<N>::open(&path)?;
         // Load the private key.
-        let private_key = crate::cli::helpers::dotenv_private_key()?;
+        let private_key = crate::cli::helpers::dotenv_private_key::<N>()?;
+        // Prepare the function name and inputs for the network.
+        let function = Identifier::<N>::from_str(&self.function.to_string())?;
+        let inputs =
+            self.inputs.iter().map(|input| Value::<N>::from_str(&input.to_string())).collect::<Result<Vec<_>>>()?;
 
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
 
         // Execute the request.
-        let (response, metrics) = package.run::<Aleo, _>(&private_key, self.function, &self.inputs, rng)?;
+        let (response, metrics) = package.run::<A, _>(&private_key, function, &inputs, rng)?;
 
         // Count the number of times a function is called.
         let mut program_frequency = HashMap::<String, usize>::new();
@@ -88,7 +98,7 @@ impl Run {
         println!();
 
         // Prepare the locator.
-        let locator = Locator::<CurrentNetwork>::from_str(&format!("{}/{}", package.program_id(), self.function))?;
+        let locator = Locator::<N>::from_str(&format!("{}/{}", package.program_id(), self.function))?;
         // Prepare the path string.
         let path_string = format!("(in \"{}\")", path.display());
 
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(any(feature = "rocks", feature = "journal"))]
+use crate::ledger::store::{helpers::SCHEMA_VERSION, ConsensusStorage, ConsensusStore};
+
+use anyhow::ensure;
+use std::path::PathBuf;
+
+/// Verifies the integrity of a ledger storage, and optionally repairs it.
+#[derive(Debug, Parser)]
+pub struct VerifyStorage {
+    /// The path to the ledger storage directory.
+    #[clap(long)]
+    path: PathBuf,
+    /// Repairs the dangling and unmirrored entries that are found.
+    #[clap(long)]
+    repair: bool,
+    /// Checks the stored finalize operations of every block against the finalize root in its header.
+    #[clap(long)]
+    deep: bool,
+    /// The network of the ledger storage (`mainnet`, `testnet`, or `devnet`).
+    #[clap(default_value = "mainnet", long)]
+    network: String,
+}
+
+impl VerifyStorage {
+    /// Verifies the integrity of the ledger storage.
+    pub fn parse(self) -> Result<String> {
+        // Ensure the storage directory exists.
+        ensure!(self.path.is_dir(), "The ledger storage directory '{}' does not exist", self.path.display());
+
+        // Verify the ledger storage on its network.
+        with_network!(network_id(&self.network)?, |N, _A| self.verify_network::<N>())
+    }
+
+    /// Verifies the integrity of the ledger storage on the given network.
+    #[cfg_attr(not(any(feature = "rocks", feature = "journal")), allow(clippy::extra_unused_type_parameters))]
+    fn verify_network<N: Network>(&self) -> Result<String> {
+        #[cfg(feature = "rocks")]
+        {
+            use crate::ledger::store::helpers::rocksdb::{ConsensusDB, RocksDB};
+            let schema_version = RocksDB::read_schema_version(N::ID, self.path.clone())?;
+            return self.verify::<N, ConsensusDB<N>>(schema_version);
+        }
+        #[cfg(all(feature = "journal", not(feature = "rocks")))]
+        {
+            use crate::ledger::store::helpers::journal::{ConsensusJournal, JournalDB};
+            let schema_version = JournalDB::read_schema_version(N::ID, self.path.clone())?;
+            return self.verify::<N, ConsensusJournal<N>>(schema_version);
+        }
+        #[cfg(not(any(feature = "rocks", feature = "journal")))]
+        anyhow::bail!("Verifying a ledger storage requires the 'rocks' or 'journal' feature")
+    }
+
+    /// Verifies the integrity of the ledger storage, with the given schema version, using the given storage backend.
+    #[cfg(any(feature = "rocks", feature = "journal"))]
+    fn verify<N: Network, C: ConsensusStorage<N>>(&self, schema_version: Option<u32>) -> Result<String> {
+        // Ensure the storage uses the current schema version, as opening the store would otherwise migrate it.
+        match schema_version {
+            Some(version) if version == SCHEMA_VERSION => (),
+            Some(version) => anyhow::bail!(
+                "The ledger storage uses schema version {version}, not the supported schema version {SCHEMA_VERSION} \
+                 - it must be migrated (or opened by a matching release) before it is verified"
+            ),
+            None => anyhow::bail!(
+                "The ledger storage has no schema version, i.e. it is empty or predates schema versioning \
+                 - it must be migrated before it is verified"
+            ),
+        }
+
+        // Open the consensus store.
+        let store = ConsensusStore::<N, C>::open(self.path.clone())?;
+
+        // Check the integrity of the storage.
+        let report = store.check_integrity(self.repair, self.deep)?;
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", self.path.display());
+
+        match report.is_ok() {
+            true => Ok(format!("✅ Verified the ledger storage {}", path_string.dimmed())),
+            // Succeed if every issue was repaired.
+            false if report.num_repaired() == report.issues().len() => {
+                Ok(format!("🔧 {report} {}", path_string.dimmed()))
+            }
+            false => anyhow::bail!("{report} {}", path_string.dimmed()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_storage_missing_directory() {
+        let command = VerifyStorage {
+            path: PathBuf::from("/nonexistent/ledger"),
+            repair: false,
+            deep: false,
+            network: "mainnet".to_string(),
+        };
+        assert!(command.parse().is_err());
+    }
+
+    #[test]
+    fn test_verify_storage_unknown_network() {
+        let command =
+            VerifyStorage { path: std::env::temp_dir(), repair: false, deep: false, network: "unknown".to_string() };
+        assert!(command.parse().unwrap_err().to_string().contains("Unsupported network 'unknown'"));
+    }
+}
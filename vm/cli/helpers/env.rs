@@ -12,7 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{cli::CurrentNetwork, console::account::PrivateKey};
+use crate::{
+    cli::network_id,
+    console::{
+        account::PrivateKey,
+        network::{MainnetV0, Network},
+    },
+};
 
 use anyhow::{anyhow, Result};
 
@@ -37,17 +43,32 @@ fn dotenv_load() -> Result<()> {
     Ok(())
 }
 
+/// Returns the network ID from the environment, which defaults to mainnet.
+pub fn dotenv_network_id() -> Result<u16> {
+    if cfg!(test) {
+        Ok(MainnetV0::ID)
+    } else {
+        // Load the '.env' file, if it exists.
+        let _ = dotenvy::dotenv();
+        // Load the network from the environment, and parse its network ID.
+        match dotenvy::var("NETWORK") {
+            Ok(network) => network_id(&network),
+            Err(_) => Ok(MainnetV0::ID),
+        }
+    }
+}
+
 /// Returns the private key from the environment.
-pub fn dotenv_private_key() -> Result<PrivateKey<CurrentNetwork>> {
+pub fn dotenv_private_key<N: Network>() -> Result<PrivateKey<N>> {
     if cfg!(test) {
         let rng = &mut crate::utilities::TestRng::fixed(123456789);
-        PrivateKey::<CurrentNetwork>::new(rng)
+        PrivateKey::<N>::new(rng)
     } else {
         use std::str::FromStr;
         dotenv_load()?;
         // Load the private key from the environment.
         let private_key = dotenvy::var("PRIVATE_KEY").map_err(|e| anyhow!("Missing PRIVATE_KEY - {e}"))?;
         // Parse the private key.
-        PrivateKey::<CurrentNetwork>::from_str(&private_key)
+        PrivateKey::<N>::from_str(&private_key)
     }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm::cli::{Updater, CLI};
+use snarkvm::cli::{Command, Updater, CLI};
 
 use clap::Parser;
 
@@ -21,10 +21,18 @@ fn main() -> anyhow::Result<()> {
     let cli = CLI::parse();
     // Run the updater.
     println!("{}", Updater::print_cli());
+    // Determine whether the command verifies a ledger storage.
+    let is_verify_storage = matches!(cli.command, Command::VerifyStorage(..));
     // Run the CLI.
     match cli.command.parse() {
         Ok(output) => println!("{output}\n"),
-        Err(error) => println!("⚠️  {error}\n"),
+        Err(error) => {
+            println!("⚠️  {error}\n");
+            // Exit with a non-zero status if the storage verification failed, so that scripts can detect it.
+            if is_verify_storage {
+                std::process::exit(1);
+            }
+        }
     }
     Ok(())
 }
@@ -0,0 +1,455 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A single-process development chain ("devnode") built on a `Ledger`, for application developers.
+//!
+//! The devnode produces beacon blocks on demand, on a timer, or for each submitted transaction,
+//! keeps the submitted transactions in a local mempool, and serves the `Query` endpoints over REST.
+//! It can also mine empty blocks to advance the chain, and reset the chain to an earlier height,
+//! which are served over REST as well.
+
+use crate::{
+    console::{
+        account::{Address, PrivateKey},
+        network::prelude::*,
+    },
+    ledger::{
+        block::{Block, Transaction},
+        mempool::{Mempool, MempoolConfig},
+        store::{ConsensusStorage, ConsensusStore},
+        Ledger,
+    },
+    rest::{DevNodeChain, RestServer},
+    synthesizer::VM,
+};
+
+use aleo_std::StorageMode;
+use parking_lot::Mutex;
+use rand::rngs::OsRng;
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+/// The interval at which the block producer checks whether it is shut down.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The mode in which the devnode produces blocks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlockProduction {
+    /// Blocks are only produced when `DevNode::mine_block` is called.
+    OnDemand,
+    /// A block is produced for each transaction, as soon as it is submitted.
+    OnTransaction,
+    /// A block is produced at the given interval, including the pending transactions (if any).
+    Interval(Duration),
+}
+
+/// The configuration of a devnode.
+#[derive(Clone, Debug)]
+pub struct DevNodeConfig {
+    /// The mode in which blocks are produced.
+    pub block_production: BlockProduction,
+    /// The address of the REST server, if it should be started.
+    pub rest_address: Option<SocketAddr>,
+    /// The configuration of the mempool.
+    pub mempool: MempoolConfig,
+}
+
+impl Default for DevNodeConfig {
+    /// Returns a configuration that produces blocks on demand, without a REST server.
+    fn default() -> Self {
+        Self { block_production: BlockProduction::OnDemand, rest_address: None, mempool: Default::default() }
+    }
+}
+
+/// A single-process development chain, which produces beacon blocks signed by its private key.
+///
+/// The block producer and the REST server are stopped when the devnode is shut down or dropped.
+pub struct DevNode<N: Network, C: 'static + ConsensusStorage<N>> {
+    /// The chain state of the devnode.
+    chain: Arc<Chain<N, C>>,
+    /// The REST server, if it was started.
+    rest_server: Option<RestServer>,
+    /// The flag to stop the block producer.
+    is_shutdown: Arc<AtomicBool>,
+    /// The handle of the block producer thread, if blocks are produced at an interval.
+    producer: Option<JoinHandle<()>>,
+}
+
+impl<N: Network, C: 'static + ConsensusStorage<N>> DevNode<N, C> {
+    /// Starts a devnode on the chain in the given storage, or on a new chain if the storage is empty,
+    /// whose genesis block is created with the given private key as a validator.
+    pub fn new<R: Rng + CryptoRng>(
+        private_key: PrivateKey<N>,
+        storage_mode: StorageMode,
+        config: DevNodeConfig,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Load the genesis block from the storage, or create a new one.
+        let vm = VM::from(ConsensusStore::<N, C>::open(storage_mode.clone())?)?;
+        let genesis = match vm.block_store().get_block_hash(0)? {
+            Some(hash) => vm.block_store().get_block(&hash)?.ok_or_else(|| anyhow!("Missing the genesis block"))?,
+            None => vm.genesis_beacon(&private_key, rng)?,
+        };
+        drop(vm);
+        // Load the ledger with the genesis block.
+        let ledger = Ledger::load(genesis, storage_mode)?;
+        // Start the devnode.
+        Self::start(ledger, private_key, config)
+    }
+
+    /// Starts a devnode on the given ledger, which produces blocks signed by the given private key.
+    pub fn start(ledger: Ledger<N, C>, private_key: PrivateKey<N>, config: DevNodeConfig) -> Result<Self> {
+        // Ensure the private key belongs to a member of the latest committee, as it signs the beacon blocks.
+        let address = Address::try_from(&private_key)?;
+        ensure!(
+            ledger.latest_committee()?.is_committee_member(address),
+            "The devnode private key ('{address}') is not a member of the latest committee"
+        );
+
+        // Initialize the chain state.
+        let chain = Arc::new(Chain {
+            ledger: ledger.clone(),
+            private_key,
+            automine: config.block_production == BlockProduction::OnTransaction,
+            mempool: Mutex::new(Mempool::new(config.mempool)),
+            block_lock: Default::default(),
+        });
+
+        // Start the REST server, which passes the broadcast transactions to the mempool.
+        let rest_server = match config.rest_address {
+            Some(address) => Some(RestServer::start_with_devnode(ledger, address, chain.clone())?),
+            None => None,
+        };
+
+        // Start the block producer, if blocks are produced at an interval.
+        let is_shutdown = Arc::new(AtomicBool::new(false));
+        let producer = match config.block_production {
+            BlockProduction::Interval(interval) => {
+                let (chain, shutdown) = (chain.clone(), is_shutdown.clone());
+                Some(std::thread::Builder::new().name("devnode-producer".to_string()).spawn(move || {
+                    let mut next_block = Instant::now() + interval;
+                    while !shutdown.load(Ordering::SeqCst) {
+                        // Wait until the next block is due, while checking if the devnode is shut down.
+                        if Instant::now() < next_block {
+                            std::thread::sleep(SHUTDOWN_POLL_INTERVAL.min(next_block - Instant::now()));
+                            continue;
+                        }
+                        if let Err(error) = chain.mine_block() {
+                            warn!("Failed to produce a devnode block - {error}");
+                        }
+                        next_block = Instant::now() + interval;
+                    }
+                })?)
+            }
+            BlockProduction::OnDemand | BlockProduction::OnTransaction => None,
+        };
+
+        Ok(Self { chain, rest_server, is_shutdown, producer })
+    }
+
+    /// Returns the ledger of the devnode.
+    pub fn ledger(&self) -> &Ledger<N, C> {
+        &self.chain.ledger
+    }
+
+    /// Returns the base URL of the REST server, if it was started.
+    pub fn rest_url(&self) -> Option<String> {
+        self.rest_server.as_ref().map(RestServer::url)
+    }
+
+    /// Returns the transactions in the mempool, from the highest to the lowest priority.
+    pub fn pending_transactions(&self) -> Vec<Transaction<N>> {
+        self.chain.mempool.lock().transactions().cloned().collect()
+    }
+
+    /// Checks the given transaction and adds it to the mempool, returning its transaction ID.
+    /// If a block is produced for each transaction, the transaction is included in a new block.
+    pub fn submit_transaction(&self, transaction: Transaction<N>) -> Result<N::TransactionID> {
+        self.chain.submit_transaction(transaction)
+    }
+
+    /// Produces a block with the highest priority transactions in the mempool, and adds it to the ledger.
+    pub fn mine_block(&self) -> Result<Block<N>> {
+        self.chain.mine_block()
+    }
+
+    /// Produces the given number of empty blocks, and returns the latest block.
+    /// The pending transactions remain in the mempool, unless their anchor expires.
+    pub fn mine_empty_blocks(&self, num_blocks: u32) -> Result<Block<N>> {
+        self.chain.mine_empty_blocks(num_blocks)
+    }
+
+    /// Resets the chain to its state after the block at the given height, and clears the mempool.
+    pub fn reset_to(&self, height: u32) -> Result<()> {
+        self.chain.reset_to(height)
+    }
+
+    /// Resets the chain to its genesis state, and clears the mempool.
+    pub fn reset(&self) -> Result<()> {
+        self.reset_to(0)
+    }
+
+    /// Shuts down the block producer and the REST server.
+    pub fn shutdown(&mut self) {
+        self.is_shutdown.store(true, Ordering::SeqCst);
+        if let Some(producer) = self.producer.take() {
+            let _ = producer.join();
+        }
+        if let Some(mut rest_server) = self.rest_server.take() {
+            rest_server.shutdown();
+        }
+    }
+}
+
+impl<N: Network, C: 'static + ConsensusStorage<N>> Drop for DevNode<N, C> {
+    /// Shuts down the devnode.
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// The chain state of a devnode, which is shared with the block producer and the REST server.
+struct Chain<N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: Ledger<N, C>,
+    /// The private key that signs the beacon blocks.
+    private_key: PrivateKey<N>,
+    /// Whether a block is produced for each submitted transaction.
+    automine: bool,
+    /// The mempool of pending transactions.
+    mempool: Mutex<Mempool<N>>,
+    /// The lock to ensure blocks are produced one at a time.
+    block_lock: Mutex<()>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> DevNodeChain<N> for Chain<N, C> {
+    /// Checks the given transaction and adds it to the mempool, returning its transaction ID.
+    fn submit_transaction(&self, transaction: Transaction<N>) -> Result<N::TransactionID> {
+        let transaction_id = transaction.id();
+        // Add the transaction to the mempool.
+        self.mempool.lock().insert(&self.ledger, transaction, &mut OsRng)?;
+        // Produce a block with the transaction, if blocks are produced for each transaction.
+        if self.automine {
+            self.mine_block()?;
+        }
+        Ok(transaction_id)
+    }
+
+    /// Produces the given number of empty blocks, and returns the latest block.
+    fn mine_empty_blocks(&self, num_blocks: u32) -> Result<Block<N>> {
+        // Hold the block lock, so that the blocks are produced consecutively.
+        let _block_lock = self.block_lock.lock();
+        for _ in 0..num_blocks {
+            self.mine_empty_block()?;
+        }
+        Ok(self.ledger.latest_block())
+    }
+
+    /// Resets the chain to its state after the block at the given height, and clears the mempool.
+    fn reset_to(&self, height: u32) -> Result<()> {
+        // Hold the block lock, so that no block is produced during the reset.
+        let _block_lock = self.block_lock.lock();
+        self.ledger.rollback_to(height)?;
+        self.mempool.lock().clear();
+        Ok(())
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Chain<N, C> {
+    /// Produces a block with the highest priority transactions in the mempool, and adds it to the ledger.
+    fn mine_block(&self) -> Result<Block<N>> {
+        // Hold the block lock, so that the blocks are produced consecutively.
+        let _block_lock = self.block_lock.lock();
+        // Prepare the next block.
+        let block =
+            self.mempool.lock().prepare_advance_to_next_beacon_block(&self.ledger, &self.private_key, &mut OsRng)?;
+        self.advance(&block)?;
+        Ok(block)
+    }
+
+    /// Produces an empty block, and adds it to the ledger.
+    /// Note: The caller must hold the block lock.
+    fn mine_empty_block(&self) -> Result<Block<N>> {
+        let block =
+            self.ledger.prepare_advance_to_next_beacon_block(&self.private_key, vec![], vec![], vec![], &mut OsRng)?;
+        self.advance(&block)?;
+        Ok(block)
+    }
+
+    /// Checks the given block, adds it to the ledger, and evicts its transactions from the mempool.
+    /// Note: The caller must hold the block lock.
+    fn advance(&self, block: &Block<N>) -> Result<()> {
+        // Check the next block.
+        self.ledger.check_next_block(block, &mut OsRng)?;
+        // Add the block to the ledger.
+        self.ledger.advance_to_next_block(block)?;
+        // Evict the included and expired transactions from the mempool.
+        self.mempool.lock().advance_to_next_block(&self.ledger, block);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        console::{
+            network::{DevnetV0, MainnetV0},
+            program::Value,
+        },
+        ledger::{
+            query::{Query, QueryTrait},
+            store::helpers::memory::{BlockMemory, ConsensusMemory},
+        },
+    };
+
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+    };
+
+    type CurrentNetwork = DevnetV0;
+    type CurrentDevNode = DevNode<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
+
+    /// Returns a `transfer_public` transaction from the given private key.
+    fn sample_transfer(
+        devnode: &CurrentDevNode,
+        private_key: &PrivateKey<CurrentNetwork>,
+    ) -> Transaction<CurrentNetwork> {
+        let rng = &mut TestRng::default();
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let inputs = [Value::from_str(&recipient.to_string()).unwrap(), Value::from_str("1000000u64").unwrap()];
+        devnode
+            .ledger()
+            .vm()
+            .execute(private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap()
+    }
+
+    /// Performs a `POST` request to the REST server of the given devnode, and returns the body of the response.
+    fn post(devnode: &CurrentDevNode, path: &str) -> String {
+        let mut stream = TcpStream::connect(devnode.rest_server.as_ref().unwrap().local_addr()).unwrap();
+        write!(stream, "POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200"), "{response}");
+        body.to_string()
+    }
+
+    #[test]
+    fn test_devnode_rejects_non_committee_key() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger with the genesis block, without checks.
+        let genesis = Block::<MainnetV0>::from_bytes_le(MainnetV0::genesis_bytes()).unwrap();
+        let ledger =
+            Ledger::<MainnetV0, ConsensusMemory<MainnetV0>>::load_unchecked(genesis, StorageMode::Production).unwrap();
+
+        // Ensure a devnode cannot sign blocks with a private key outside of the committee.
+        let private_key = PrivateKey::new(rng).unwrap();
+        assert!(DevNode::start(ledger, private_key, DevNodeConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_devnode() {
+        let rng = &mut TestRng::default();
+
+        // Start a devnode on a new chain, with a REST server.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let config = DevNodeConfig { rest_address: Some("127.0.0.1:0".parse().unwrap()), ..Default::default() };
+        let devnode = CurrentDevNode::new(private_key, StorageMode::Production, config, rng).unwrap();
+        assert_eq!(devnode.ledger().latest_height(), 0);
+
+        // Mine empty blocks.
+        let block = devnode.mine_empty_blocks(3).unwrap();
+        assert_eq!(block.height(), 3);
+        assert_eq!(devnode.ledger().latest_height(), 3);
+        assert_eq!(block.transactions().len(), 0);
+
+        // Submit a transaction, which remains pending until a block is mined.
+        let transaction = sample_transfer(&devnode, &private_key);
+        let transaction_id = devnode.submit_transaction(transaction.clone()).unwrap();
+        assert_eq!(devnode.pending_transactions(), vec![transaction.clone()]);
+        assert!(devnode.submit_transaction(transaction.clone()).is_err());
+
+        // Mine a block with the pending transaction.
+        let block = devnode.mine_block().unwrap();
+        assert_eq!(block.height(), 4);
+        assert!(block.transactions().get(&transaction_id).is_some());
+        assert!(devnode.pending_transactions().is_empty());
+        assert!(devnode.ledger().contains_transaction_id(&transaction_id).unwrap());
+        assert!(devnode.submit_transaction(transaction).is_err());
+
+        // Ensure the REST server serves the chain.
+        let query = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from(devnode.rest_url().unwrap());
+        assert_eq!(query.current_state_root().unwrap(), devnode.ledger().latest_state_root());
+
+        // Ensure the REST server mines empty blocks, and resets the chain.
+        let block = serde_json::from_str::<Block<CurrentNetwork>>(&post(&devnode, "/devnet/devnode/mine/2")).unwrap();
+        assert_eq!(block.height(), 6);
+        assert_eq!(devnode.ledger().latest_height(), 6);
+        assert_eq!(post(&devnode, "/devnet/devnode/reset/4"), "4");
+        assert_eq!(devnode.ledger().latest_height(), 4);
+        assert!(devnode.ledger().contains_transaction_id(&transaction_id).unwrap());
+
+        // Reset the chain to an earlier height.
+        devnode.reset_to(3).unwrap();
+        assert_eq!(devnode.ledger().latest_height(), 3);
+        assert!(!devnode.ledger().contains_transaction_id(&transaction_id).unwrap());
+
+        // Reset the chain to its genesis state, and ensure blocks can be mined again.
+        devnode.submit_transaction(sample_transfer(&devnode, &private_key)).unwrap();
+        devnode.reset().unwrap();
+        assert_eq!(devnode.ledger().latest_height(), 0);
+        assert!(devnode.pending_transactions().is_empty());
+        assert_eq!(devnode.mine_block().unwrap().height(), 1);
+    }
+
+    #[test]
+    fn test_devnode_block_production() {
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Ensure a block is produced for each submitted transaction.
+        let config = DevNodeConfig { block_production: BlockProduction::OnTransaction, ..Default::default() };
+        let devnode = CurrentDevNode::new(private_key, StorageMode::Production, config, rng).unwrap();
+        let transaction_id = devnode.submit_transaction(sample_transfer(&devnode, &private_key)).unwrap();
+        assert_eq!(devnode.ledger().latest_height(), 1);
+        assert!(devnode.ledger().contains_transaction_id(&transaction_id).unwrap());
+        assert!(devnode.pending_transactions().is_empty());
+
+        // Ensure blocks can be produced manually when they are produced at an interval,
+        // and the block producer stops when the devnode is shut down.
+        let config = DevNodeConfig {
+            block_production: BlockProduction::Interval(Duration::from_secs(3600)),
+            ..Default::default()
+        };
+        let mut devnode = CurrentDevNode::new(private_key, StorageMode::Production, config, rng).unwrap();
+        let transaction_id = devnode.submit_transaction(sample_transfer(&devnode, &private_key)).unwrap();
+        assert_eq!(devnode.ledger().latest_height(), 0);
+        assert_eq!(devnode.mine_block().unwrap().height(), 1);
+        assert!(devnode.ledger().contains_transaction_id(&transaction_id).unwrap());
+        devnode.shutdown();
+        assert!(devnode.producer.is_none());
+        assert_eq!(devnode.ledger().latest_height(), 1);
+    }
+}
@@ -19,11 +19,18 @@
 #[cfg(feature = "cli")]
 #[macro_use]
 extern crate thiserror;
+#[cfg(feature = "ledger")]
+#[macro_use]
+extern crate tracing;
 
 #[cfg(feature = "cli")]
 pub mod cli;
+#[cfg(all(feature = "ledger", feature = "synthesizer"))]
+pub mod devnode;
 pub mod file;
 pub mod package;
+#[cfg(feature = "ledger")]
+pub mod rest;
 
 #[cfg(feature = "algorithms")]
 pub use snarkvm_algorithms as algorithms;
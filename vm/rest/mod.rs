@@ -0,0 +1,635 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A local REST server that serves the `Query` endpoints (along with block, transaction, program,
+//! and mapping lookups) from a `Ledger`, for local devnets and integration tests.
+//! Optionally, the server accepts transactions that are broadcast to it, and serves the chain operations of a devnode.
+
+use crate::{
+    console::{
+        network::prelude::*,
+        program::{Identifier, Plaintext, ProgramID},
+        types::Field,
+    },
+    ledger::{
+        block::{Block, Transaction},
+        store::ConsensusStorage,
+        Ledger,
+    },
+};
+
+use parking_lot::Mutex;
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{sync_channel, TrySendError},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+/// The number of worker threads that handle the connections.
+const NUM_WORKERS: usize = 8;
+/// The maximum number of accepted connections that wait for a worker, beyond which connections are rejected.
+const MAX_PENDING_CONNECTIONS: usize = 64;
+/// The maximum number of bytes in the head of a request.
+const MAX_REQUEST_HEAD_SIZE: usize = 8 * 1024;
+/// The maximum number of bytes in the body of a request.
+const MAX_REQUEST_BODY_SIZE: usize = 1024 * 1024;
+/// The timeout for reading a request from a connection.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A response of the REST server, as a status code and a JSON body.
+type Response = (u16, String);
+/// A handler for the transactions broadcast to the REST server, which returns the transaction ID.
+type Broadcast<N> = Arc<dyn Fn(Transaction<N>) -> Result<<N as Network>::TransactionID> + Send + Sync>;
+
+/// The chain of a devnode, which the REST server modifies on `POST` requests.
+pub trait DevNodeChain<N: Network>: Send + Sync {
+    /// Checks the given transaction and adds it to the mempool, returning its transaction ID.
+    fn submit_transaction(&self, transaction: Transaction<N>) -> Result<N::TransactionID>;
+
+    /// Produces the given number of empty blocks, and returns the latest block.
+    fn mine_empty_blocks(&self, num_blocks: u32) -> Result<Block<N>>;
+
+    /// Resets the chain to its state after the block at the given height.
+    fn reset_to(&self, height: u32) -> Result<()>;
+}
+
+/// The handlers of the `POST` requests to the REST server.
+#[derive(Clone)]
+struct Handlers<N: Network> {
+    /// The handler for the broadcast transactions, if transactions are accepted.
+    broadcast: Option<Broadcast<N>>,
+    /// The devnode chain, if its chain operations are served.
+    devnode: Option<Arc<dyn DevNodeChain<N>>>,
+}
+
+/// A REST server that serves the ledger, under the path prefix of its network (e.g. `/mainnet`).
+///
+/// The server handles the connections on a fixed pool of worker threads, and rejects new connections with
+/// `503 Service Unavailable` while too many are waiting. The server stops when it is shut down or dropped.
+pub struct RestServer {
+    /// The address the server is listening on.
+    local_addr: SocketAddr,
+    /// The flag to stop the server.
+    is_shutdown: Arc<AtomicBool>,
+    /// The handle of the listener thread.
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RestServer {
+    /// Starts a REST server for the given ledger, listening on the given address.
+    pub fn start<N: Network, C: 'static + ConsensusStorage<N>>(
+        ledger: Ledger<N, C>,
+        address: impl ToSocketAddrs,
+    ) -> Result<Self> {
+        Self::spawn(ledger, address, Handlers { broadcast: None, devnode: None })
+    }
+
+    /// Starts a REST server for the given ledger, listening on the given address,
+    /// which passes the transactions broadcast to `POST /{network}/transaction/broadcast` to the given handler.
+    pub fn start_with_broadcast<N: Network, C: 'static + ConsensusStorage<N>>(
+        ledger: Ledger<N, C>,
+        address: impl ToSocketAddrs,
+        broadcast: impl Fn(Transaction<N>) -> Result<N::TransactionID> + Send + Sync + 'static,
+    ) -> Result<Self> {
+        Self::spawn(ledger, address, Handlers { broadcast: Some(Arc::new(broadcast)), devnode: None })
+    }
+
+    /// Starts a REST server for the given ledger of a devnode, listening on the given address,
+    /// which passes the transactions broadcast to `POST /{network}/transaction/broadcast` to the devnode chain,
+    /// and serves `POST /{network}/devnode/mine/{num_blocks}` and `POST /{network}/devnode/reset[/{height}]`.
+    pub fn start_with_devnode<N: Network, C: 'static + ConsensusStorage<N>>(
+        ledger: Ledger<N, C>,
+        address: impl ToSocketAddrs,
+        devnode: Arc<dyn DevNodeChain<N>>,
+    ) -> Result<Self> {
+        let chain = devnode.clone();
+        let broadcast: Broadcast<N> = Arc::new(move |transaction| chain.submit_transaction(transaction));
+        Self::spawn(ledger, address, Handlers { broadcast: Some(broadcast), devnode: Some(devnode) })
+    }
+
+    /// Spawns the listener thread of the REST server.
+    fn spawn<N: Network, C: 'static + ConsensusStorage<N>>(
+        ledger: Ledger<N, C>,
+        address: impl ToSocketAddrs,
+        handlers: Handlers<N>,
+    ) -> Result<Self> {
+        // Retrieve the path prefix of the network.
+        let prefix = N::SHORT_NAME;
+        // Bind the listener.
+        let listener = TcpListener::bind(address)?;
+        let local_addr = listener.local_addr()?;
+        let is_shutdown = Arc::new(AtomicBool::new(false));
+
+        // Spawn the worker threads, which handle the accepted connections until the listener thread stops.
+        let (sender, receiver) = sync_channel::<TcpStream>(MAX_PENDING_CONNECTIONS);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let mut workers = Vec::with_capacity(NUM_WORKERS);
+        for index in 0..NUM_WORKERS {
+            let (ledger, handlers, receiver) = (ledger.clone(), handlers.clone(), receiver.clone());
+            workers.push(std::thread::Builder::new().name(format!("rest-worker-{index}")).spawn(move || {
+                loop {
+                    let Ok(stream) = receiver.lock().recv() else {
+                        break;
+                    };
+                    if let Err(error) = handle_connection(&ledger, prefix, &handlers, stream) {
+                        warn!("Failed to handle a REST request - {error}");
+                    }
+                }
+            })?);
+        }
+
+        // Spawn the listener thread.
+        let shutdown = is_shutdown.clone();
+        let handle = std::thread::Builder::new().name("rest-server".to_string()).spawn(move || {
+            for stream in listener.incoming() {
+                // Stop, if the server is shut down.
+                if shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => match sender.try_send(stream) {
+                        Ok(()) => (),
+                        // Reject the connection, if too many connections are waiting for a worker.
+                        Err(TrySendError::Full(mut stream)) => {
+                            if let Err(error) = write_response(&mut stream, error(503, "The server is busy")) {
+                                debug!("Failed to reject a REST connection - {error}");
+                            }
+                        }
+                        Err(TrySendError::Disconnected(_)) => break,
+                    },
+                    Err(error) => warn!("Failed to accept a REST connection - {error}"),
+                }
+            }
+            // Stop the worker threads, once they handle the pending connections.
+            drop(sender);
+            for worker in workers {
+                let _ = worker.join();
+            }
+        })?;
+
+        Ok(Self { local_addr, is_shutdown, handle: Some(handle) })
+    }
+
+    /// Returns the address the server is listening on.
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Returns the base URL of the server, to be used with `Query::REST`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.local_addr)
+    }
+
+    /// Shuts down the server, and waits for the listener and worker threads to stop.
+    pub fn shutdown(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.is_shutdown.store(true, Ordering::SeqCst);
+            // Wake up the listener thread, so that it observes the flag.
+            let _ = TcpStream::connect(self.local_addr);
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for RestServer {
+    /// Shuts down the server.
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Reads a request from the given connection, and writes the response.
+fn handle_connection<N: Network, C: ConsensusStorage<N>>(
+    ledger: &Ledger<N, C>,
+    prefix: &str,
+    handlers: &Handlers<N>,
+    mut stream: TcpStream,
+) -> Result<()> {
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+
+    // Read the head of the request.
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut head = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line == "\r\n" || line == "\n" {
+            break;
+        }
+        head.push(line);
+        ensure!(head.iter().map(String::len).sum::<usize>() <= MAX_REQUEST_HEAD_SIZE, "The request is too large");
+    }
+
+    // Route the request.
+    let response = match head.first().map(|line| line.split_whitespace().collect::<Vec<_>>()) {
+        Some(parts) if parts.len() == 3 && parts[0] == "GET" => {
+            // Remove the query string, if any.
+            let path = parts[1].split('?').next().unwrap_or_default();
+            route(ledger, prefix, path)
+        }
+        Some(parts) if parts.len() == 3 && parts[0] == "POST" && handlers.broadcast.is_some() => {
+            // Read the body of the request, which is either chunked or of the given length.
+            let is_chunked = header(&head, "transfer-encoding").is_some_and(|value| value.contains("chunked"));
+            let body = match is_chunked {
+                true => read_chunked_body(&mut reader)?,
+                false => {
+                    let length = header(&head, "content-length").and_then(|value| value.parse::<usize>().ok());
+                    let length = length.unwrap_or_default();
+                    ensure!(length <= MAX_REQUEST_BODY_SIZE, "The request body is too large");
+                    let mut body = vec![0u8; length];
+                    reader.read_exact(&mut body)?;
+                    body
+                }
+            };
+            route_post(handlers, prefix, parts[1], &body)
+        }
+        Some(parts) if parts.len() == 3 => error(405, "Method not allowed"),
+        _ => error(400, "Malformed request"),
+    };
+
+    // Write the response.
+    write_response(&mut stream, response)
+}
+
+/// Writes the given response to the given connection.
+fn write_response(stream: &mut TcpStream, (status, body): Response) -> Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    Ok(stream.flush()?)
+}
+
+/// Returns the trimmed value of the given header in the given request head, if it is present.
+fn header<'a>(head: &'a [String], name: &str) -> Option<&'a str> {
+    head.iter()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.trim())
+}
+
+/// Reads a request body with the `chunked` transfer encoding from the given reader.
+fn read_chunked_body(reader: &mut impl BufRead) -> Result<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        // Read the size of the chunk, ignoring any chunk extensions.
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let size = line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| anyhow!("Invalid chunk size '{size}'"))?;
+        // Read the trailer, once the last chunk is reached.
+        if size == 0 {
+            loop {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 || line == "\r\n" || line == "\n" {
+                    return Ok(body);
+                }
+            }
+        }
+        // Read the chunk, followed by its line break.
+        ensure!(body.len().saturating_add(size) <= MAX_REQUEST_BODY_SIZE, "The request body is too large");
+        let start = body.len();
+        body.resize(start + size, 0);
+        reader.read_exact(&mut body[start..])?;
+        line.clear();
+        reader.read_line(&mut line)?;
+        ensure!(line.trim().is_empty(), "Malformed chunk");
+    }
+}
+
+/// Returns the response for the given path.
+fn route<N: Network, C: ConsensusStorage<N>>(ledger: &Ledger<N, C>, prefix: &str, path: &str) -> Response {
+    // Split the path into its segments.
+    let segments = path.trim_matches('/').split('/').map(percent_decode).collect::<Vec<_>>();
+    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+    match segments.as_slice() {
+        [network, rest @ ..] if *network == prefix => match rest {
+            ["latest", "height"] => json(&ledger.latest_height()),
+            ["latest", "hash"] => json(&ledger.latest_hash()),
+            ["latest", "block"] => json(&ledger.latest_block()),
+            ["latest", "stateRoot"] => json(&ledger.latest_state_root()),
+            ["block", id] => match id.parse::<u32>() {
+                Ok(height) => lookup(path, ledger.contains_block_height(height), || ledger.get_block(height)),
+                Err(_) => match parse::<N::BlockHash>(id) {
+                    Ok(hash) => lookup(path, ledger.contains_block_hash(&hash), || ledger.get_block_by_hash(&hash)),
+                    Err(response) => response,
+                },
+            },
+            ["transaction", id] => match parse(id) {
+                Ok(id) => lookup(path, ledger.contains_transaction_id(&id), || ledger.get_transaction(id)),
+                Err(response) => response,
+            },
+            ["program", id] => match parse::<ProgramID<N>>(id) {
+                Ok(id) => lookup(path, ledger.contains_program_id(&id), || ledger.get_program(id)),
+                Err(response) => response,
+            },
+            ["program", id, "mapping", name, key] => {
+                match (parse::<ProgramID<N>>(id), parse::<Identifier<N>>(name), parse::<Plaintext<N>>(key)) {
+                    (Ok(id), Ok(name), Ok(key)) => {
+                        match ledger.vm().finalize_store().get_value_confirmed(id, name, &key) {
+                            Ok(value) => json(&value),
+                            Err(e) => error(500, &e.to_string()),
+                        }
+                    }
+                    (Err(response), ..) | (_, Err(response), _) | (.., Err(response)) => response,
+                }
+            }
+            ["statePath", commitment] => match parse::<Field<N>>(commitment) {
+                Ok(commitment) => lookup(path, ledger.contains_commitment(&commitment), || {
+                    ledger.get_state_path_for_commitment(&commitment)
+                }),
+                Err(response) => response,
+            },
+            _ => error(404, &format!("Unknown path '{path}'")),
+        },
+        _ => error(404, &format!("Unknown path '{path}'")),
+    }
+}
+
+/// Returns the response for the given `POST` path and request body.
+fn route_post<N: Network>(handlers: &Handlers<N>, prefix: &str, path: &str, body: &[u8]) -> Response {
+    // Resets the devnode chain to the given height, and returns the height.
+    let reset_to = |devnode: &Arc<dyn DevNodeChain<N>>, height: u32| match devnode.reset_to(height) {
+        Ok(()) => json(&height),
+        Err(e) => error(400, &e.to_string()),
+    };
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    match (segments.as_slice(), &handlers.broadcast, &handlers.devnode) {
+        ([network, "transaction", "broadcast"], Some(broadcast), _) if *network == prefix => {
+            match serde_json::from_slice::<Transaction<N>>(body) {
+                Ok(transaction) => match broadcast(transaction) {
+                    Ok(transaction_id) => json(&transaction_id),
+                    Err(e) => error(400, &e.to_string()),
+                },
+                Err(e) => error(400, &format!("Invalid transaction - {e}")),
+            }
+        }
+        ([network, "devnode", "mine", num_blocks], _, Some(devnode)) if *network == prefix => {
+            match parse::<u32>(num_blocks) {
+                Ok(num_blocks) => match devnode.mine_empty_blocks(num_blocks) {
+                    Ok(block) => json(&block),
+                    Err(e) => error(500, &e.to_string()),
+                },
+                Err(response) => response,
+            }
+        }
+        ([network, "devnode", "reset"], _, Some(devnode)) if *network == prefix => reset_to(devnode, 0),
+        ([network, "devnode", "reset", height], _, Some(devnode)) if *network == prefix => match parse(height) {
+            Ok(height) => reset_to(devnode, height),
+            Err(response) => response,
+        },
+        _ => error(405, "Method not allowed"),
+    }
+}
+
+/// Parses the given path segment, or returns a response for the parse error.
+fn parse<T: FromStr>(segment: &str) -> Result<T, Response> {
+    segment.parse().map_err(|_| error(400, &format!("Invalid path segment '{segment}'")))
+}
+
+/// Returns the response for a lookup of the given path, which is not found if it does not exist,
+/// and is an internal error if the existence check or the lookup fails.
+fn lookup<T: Serialize>(path: &str, exists: Result<bool>, get: impl FnOnce() -> Result<T>) -> Response {
+    match exists.and_then(|exists| exists.then(get).transpose()) {
+        Ok(Some(value)) => json(&value),
+        Ok(None) => error(404, &format!("'{path}' was not found")),
+        Err(e) => error(500, &e.to_string()),
+    }
+}
+
+/// Returns the response for the given value, as JSON.
+fn json<T: Serialize>(value: &T) -> Response {
+    match serde_json::to_string(value) {
+        Ok(body) => (200, body),
+        Err(e) => error(500, &e.to_string()),
+    }
+}
+
+/// Returns the response for the given error.
+fn error(status: u16, message: &str) -> Response {
+    (status, serde_json::json!({ "error": message }).to_string())
+}
+
+/// Decodes the percent-encoded characters in the given path segment.
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes.get(index + 1..index + 3).and_then(|hex| std::str::from_utf8(hex).ok());
+        match (bytes[index], hex.and_then(|hex| u8::from_str_radix(hex, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        console::{
+            account::{Address, PrivateKey},
+            network::MainnetV0,
+            program::Value,
+        },
+        ledger::{
+            query::{Query, QueryTrait, RestClient, RestClientConfig},
+            store::helpers::memory::{BlockMemory, ConsensusMemory},
+        },
+    };
+    use aleo_std::StorageMode;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Performs a request to the given server, and returns the status code and body of the response.
+    fn request(server: &RestServer, request: &str) -> (u16, String) {
+        send(server, &format!("{request}\r\nHost: localhost\r\n\r\n"))
+    }
+
+    /// Performs a `POST` request with the given body to the given server, and returns the status code and body of the response.
+    fn post(server: &RestServer, path: &str, body: &str) -> (u16, String) {
+        let length = body.len();
+        send(server, &format!("POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {length}\r\n\r\n{body}"))
+    }
+
+    /// Sends the given raw request to the given server, and returns the status code and body of the response.
+    fn send(server: &RestServer, raw: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.split_whitespace().nth(1).unwrap().parse().unwrap(), body.to_string())
+    }
+
+    #[test]
+    fn test_rest_server() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger with the genesis block, without checks.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let ledger = Ledger::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::load_unchecked(
+            genesis.clone(),
+            StorageMode::Production,
+        )
+        .unwrap();
+
+        // Start the server.
+        let mut server = RestServer::start(ledger.clone(), "127.0.0.1:0").unwrap();
+
+        // Ensure the `Query` endpoints are served.
+        let query = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from(server.url());
+        assert_eq!(query.current_state_root().unwrap(), ledger.latest_state_root());
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        assert_eq!(query.get_program(&credits).unwrap(), ledger.get_program(credits).unwrap());
+
+        // Ensure the block lookups are served.
+        assert_eq!(request(&server, "GET /mainnet/latest/height HTTP/1.1"), (200, "0".to_string()));
+        let (status, body) = request(&server, "GET /mainnet/block/0 HTTP/1.1");
+        assert_eq!((status, serde_json::from_str::<Block<CurrentNetwork>>(&body).unwrap()), (200, genesis.clone()));
+        let (status, body) = request(&server, &format!("GET /mainnet/block/{} HTTP/1.1", genesis.hash()));
+        assert_eq!((status, serde_json::from_str::<Block<CurrentNetwork>>(&body).unwrap()), (200, genesis.clone()));
+        assert_eq!(request(&server, "GET /mainnet/block/1 HTTP/1.1").0, 404);
+
+        // Ensure the transaction lookups are served.
+        let transaction_id = genesis.transaction_ids().next().unwrap();
+        let (status, body) = request(&server, &format!("GET /mainnet/transaction/{transaction_id} HTTP/1.1"));
+        assert_eq!(status, 200);
+        assert_eq!(
+            &serde_json::from_str::<crate::ledger::Transaction<CurrentNetwork>>(&body).unwrap().id(),
+            transaction_id
+        );
+
+        // Ensure the mapping lookups are served.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let path = format!("GET /mainnet/program/credits.aleo/mapping/account/{address} HTTP/1.1");
+        assert_eq!(request(&server, &path), (200, "null".to_string()));
+        let committee = ledger.latest_committee().unwrap();
+        let (validator, _) = committee.members().iter().next().unwrap();
+        let (status, body) =
+            request(&server, &format!("GET /mainnet/program/credits.aleo/mapping/account/{validator} HTTP/1.1"));
+        assert_eq!(status, 200);
+        let balance = serde_json::from_str::<Option<Value<CurrentNetwork>>>(&body).unwrap();
+        let key = Plaintext::from_str(&validator.to_string()).unwrap();
+        let expected = ledger
+            .vm()
+            .finalize_store()
+            .get_value_confirmed(credits, Identifier::from_str("account").unwrap(), &key)
+            .unwrap();
+        assert_eq!(balance, expected);
+
+        // Ensure the mapping lookups are served to the REST client.
+        let client = RestClient::<CurrentNetwork>::new([server.url()], RestClientConfig::default()).unwrap();
+        let account = Identifier::from_str("account").unwrap();
+        assert_eq!(client.get_mapping_value(&credits, &account, &key).unwrap(), expected);
+        let key = Plaintext::from_str(&address.to_string()).unwrap();
+        assert_eq!(client.get_mapping_value(&credits, &account, &key).unwrap(), None);
+
+        // Ensure invalid requests are rejected.
+        assert_eq!(request(&server, "GET /mainnet/block/invalid HTTP/1.1").0, 400);
+        assert_eq!(request(&server, "GET /testnet/latest/height HTTP/1.1").0, 404);
+        assert_eq!(request(&server, "POST /mainnet/latest/height HTTP/1.1").0, 405);
+
+        // Ensure the server stops when it is shut down.
+        server.shutdown();
+        assert!(query.current_state_root().is_err());
+    }
+
+    #[test]
+    fn test_rest_server_broadcast() {
+        // Initialize the ledger with the genesis block, without checks.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let ledger = Ledger::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::load_unchecked(
+            genesis.clone(),
+            StorageMode::Production,
+        )
+        .unwrap();
+
+        // Start the server, with a handler that records the broadcast transactions.
+        let broadcasts = Arc::new(Mutex::new(Vec::new()));
+        let recorded = broadcasts.clone();
+        let server = RestServer::start_with_broadcast(ledger, "127.0.0.1:0", move |transaction| {
+            ensure!(transaction.is_execute(), "Only executions are accepted");
+            let transaction_id = transaction.id();
+            recorded.lock().push(transaction);
+            Ok(transaction_id)
+        })
+        .unwrap();
+
+        // Broadcast a transaction.
+        let transaction = genesis.transactions().iter().next().unwrap().transaction().clone();
+        let body = serde_json::to_string(&transaction).unwrap();
+        let (status, response) = post(&server, "/mainnet/transaction/broadcast", &body);
+        assert_eq!(status, 200);
+        assert_eq!(
+            serde_json::from_str::<<CurrentNetwork as Network>::TransactionID>(&response).unwrap(),
+            transaction.id()
+        );
+        assert_eq!(*broadcasts.lock(), vec![transaction.clone()]);
+
+        // Broadcast the transaction in chunks.
+        let (first, second) = body.split_at(body.len() / 2);
+        let (first_size, second_size) = (first.len(), second.len());
+        let (status, response) = send(
+            &server,
+            &format!(
+                "POST /mainnet/transaction/broadcast HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n\
+                 {first_size:x}\r\n{first}\r\n{second_size:x};extension=value\r\n{second}\r\n0\r\n\r\n"
+            ),
+        );
+        assert_eq!(status, 200);
+        assert_eq!(
+            serde_json::from_str::<<CurrentNetwork as Network>::TransactionID>(&response).unwrap(),
+            transaction.id()
+        );
+        assert_eq!(*broadcasts.lock(), vec![transaction.clone(), transaction]);
+
+        // Ensure invalid broadcasts are rejected.
+        assert_eq!(post(&server, "/mainnet/transaction/broadcast", "{}").0, 400);
+        assert_eq!(post(&server, "/mainnet/latest/height", "").0, 405);
+        assert_eq!(post(&server, "/mainnet/devnode/mine/1", "").0, 405);
+        assert_eq!(post(&server, "/testnet/transaction/broadcast", &body).0, 405);
+        assert_eq!(broadcasts.lock().len(), 2);
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("aleo1abc"), "aleo1abc");
+        assert_eq!(percent_decode("%7B%20a%3A%201u8%20%7D"), "{ a: 1u8 }");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+}